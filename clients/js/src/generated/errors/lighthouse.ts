@@ -248,6 +248,32 @@ nameToErrorMap.set(
   CrossProgramInvokeViolationError
 );
 
+/** ArithmeticOverflow: ArithmeticOverflow */
+export class ArithmeticOverflowError extends ProgramError {
+  override readonly name: string = 'ArithmeticOverflow';
+
+  readonly code: number = 0x1781; // 6017
+
+  constructor(program: Program, cause?: Error) {
+    super('ArithmeticOverflow', program, cause);
+  }
+}
+codeToErrorMap.set(0x1781, ArithmeticOverflowError);
+nameToErrorMap.set('ArithmeticOverflow', ArithmeticOverflowError);
+
+/** ReturnDataMismatch: ReturnDataMismatch */
+export class ReturnDataMismatchError extends ProgramError {
+  override readonly name: string = 'ReturnDataMismatch';
+
+  readonly code: number = 0x1782; // 6018
+
+  constructor(program: Program, cause?: Error) {
+    super('ReturnDataMismatch', program, cause);
+  }
+}
+codeToErrorMap.set(0x1782, ReturnDataMismatchError);
+nameToErrorMap.set('ReturnDataMismatch', ReturnDataMismatchError);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
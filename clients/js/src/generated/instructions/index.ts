@@ -8,19 +8,29 @@
 
 export * from './assertAccountData';
 export * from './assertAccountDataMulti';
+export * from './assertAccountDataPath';
+export * from './assertAccountDataPathMulti';
 export * from './assertAccountDelta';
 export * from './assertAccountInfo';
 export * from './assertAccountInfoMulti';
 export * from './assertBubblegumTreeConfigAccount';
+export * from './assertMemoryDeltaAndClose';
 export * from './assertMerkleTreeAccount';
 export * from './assertMintAccount';
+export * from './assertMintAccountDelta';
 export * from './assertMintAccountMulti';
+export * from './assertReturnData';
 export * from './assertStakeAccount';
 export * from './assertStakeAccountMulti';
 export * from './assertSysvarClock';
 export * from './assertTokenAccount';
+export * from './assertTokenAccountDelta';
 export * from './assertTokenAccountMulti';
 export * from './assertUpgradeableLoaderAccount';
 export * from './assertUpgradeableLoaderAccountMulti';
 export * from './memoryClose';
+export * from './memoryCloseKeyed';
 export * from './memoryWrite';
+export * from './memoryWriteKeyed';
+export * from './memoryWriteMulti';
+export * from './memoryWriteMultiKeyed';
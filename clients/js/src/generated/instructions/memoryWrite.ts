@@ -40,7 +40,7 @@ export type MemoryWriteInstructionAccounts = {
   systemProgram?: PublicKey | Pda;
   /** Payer account */
   payer?: Signer;
-  /** Memory account */
+  /** Memory account, grows by at most 10 KiB per instruction */
   memory: PublicKey | Pda;
   /** Account to be written to memory */
   sourceAccount: PublicKey | Pda;
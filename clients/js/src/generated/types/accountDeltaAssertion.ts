@@ -18,8 +18,11 @@ import {
   AccountInfoDeltaAssertionArgs,
   DataValueDeltaAssertion,
   DataValueDeltaAssertionArgs,
+  MemoryGuard,
+  MemoryGuardArgs,
   getAccountInfoDeltaAssertionSerializer,
   getDataValueDeltaAssertionSerializer,
+  getMemoryGuardSerializer,
 } from '.';
 import {
   CompactU64,
@@ -38,6 +41,19 @@ export type AccountDeltaAssertion =
       aOffset: CompactU64;
       bOffset: CompactU64;
       assertion: DataValueDeltaAssertion;
+    }
+  | {
+      __kind: 'GuardedData';
+      guard: MemoryGuard;
+      aOffset: CompactU64;
+      bOffset: CompactU64;
+      assertion: DataValueDeltaAssertion;
+    }
+  | {
+      __kind: 'GuardedAccountInfo';
+      guard: MemoryGuard;
+      aOffset: CompactU64;
+      assertion: AccountInfoDeltaAssertion;
     };
 
 export type AccountDeltaAssertionArgs =
@@ -51,6 +67,19 @@ export type AccountDeltaAssertionArgs =
       aOffset: CompactU64Args;
       bOffset: CompactU64Args;
       assertion: DataValueDeltaAssertionArgs;
+    }
+  | {
+      __kind: 'GuardedData';
+      guard: MemoryGuardArgs;
+      aOffset: CompactU64Args;
+      bOffset: CompactU64Args;
+      assertion: DataValueDeltaAssertionArgs;
+    }
+  | {
+      __kind: 'GuardedAccountInfo';
+      guard: MemoryGuardArgs;
+      aOffset: CompactU64Args;
+      assertion: AccountInfoDeltaAssertionArgs;
     };
 
 export function getAccountDeltaAssertionSerializer(): Serializer<
@@ -74,6 +103,25 @@ export function getAccountDeltaAssertionSerializer(): Serializer<
           ['assertion', getDataValueDeltaAssertionSerializer()],
         ]),
       ],
+      [
+        'GuardedData',
+        struct<GetDataEnumKindContent<AccountDeltaAssertion, 'GuardedData'>>([
+          ['guard', getMemoryGuardSerializer()],
+          ['aOffset', getCompactU64Serializer()],
+          ['bOffset', getCompactU64Serializer()],
+          ['assertion', getDataValueDeltaAssertionSerializer()],
+        ]),
+      ],
+      [
+        'GuardedAccountInfo',
+        struct<
+          GetDataEnumKindContent<AccountDeltaAssertion, 'GuardedAccountInfo'>
+        >([
+          ['guard', getMemoryGuardSerializer()],
+          ['aOffset', getCompactU64Serializer()],
+          ['assertion', getAccountInfoDeltaAssertionSerializer()],
+        ]),
+      ],
     ],
     { description: 'AccountDeltaAssertion' }
   ) as Serializer<AccountDeltaAssertionArgs, AccountDeltaAssertion>;
@@ -88,6 +136,14 @@ export function accountDeltaAssertion(
   kind: 'Data',
   data: GetDataEnumKindContent<AccountDeltaAssertionArgs, 'Data'>
 ): GetDataEnumKind<AccountDeltaAssertionArgs, 'Data'>;
+export function accountDeltaAssertion(
+  kind: 'GuardedData',
+  data: GetDataEnumKindContent<AccountDeltaAssertionArgs, 'GuardedData'>
+): GetDataEnumKind<AccountDeltaAssertionArgs, 'GuardedData'>;
+export function accountDeltaAssertion(
+  kind: 'GuardedAccountInfo',
+  data: GetDataEnumKindContent<AccountDeltaAssertionArgs, 'GuardedAccountInfo'>
+): GetDataEnumKind<AccountDeltaAssertionArgs, 'GuardedAccountInfo'>;
 export function accountDeltaAssertion<
   K extends AccountDeltaAssertionArgs['__kind'],
 >(kind: K, data?: any): Extract<AccountDeltaAssertionArgs, { __kind: K }> {
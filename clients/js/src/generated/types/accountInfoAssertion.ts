@@ -6,7 +6,7 @@
  * @see https://github.com/codama-idl/codama
  */
 
-import { PublicKey } from '@metaplex-foundation/umi';
+import { Option, OptionOrNullable, PublicKey } from '@metaplex-foundation/umi';
 import {
   GetDataEnumKind,
   GetDataEnumKindContent,
@@ -14,9 +14,12 @@ import {
   bool,
   bytes,
   dataEnum,
+  i128,
+  option,
   publicKey as publicKeySerializer,
   struct,
   u64,
+  u8,
 } from '@metaplex-foundation/umi/serializers';
 import {
   EquatableOperator,
@@ -25,13 +28,25 @@ import {
   IntegerOperatorArgs,
   KnownProgram,
   KnownProgramArgs,
+  SetOperator,
+  SetOperatorArgs,
   getEquatableOperatorSerializer,
   getIntegerOperatorSerializer,
   getKnownProgramSerializer,
+  getSetOperatorSerializer,
 } from '.';
 import {
+  CompactKnownPrograms,
+  CompactKnownProgramsArgs,
+  CompactPdaSeeds,
+  CompactPdaSeedsArgs,
+  CompactPubkeys,
+  CompactPubkeysArgs,
   CompactU64,
   CompactU64Args,
+  getCompactKnownProgramsSerializer,
+  getCompactPdaSeedsSerializer,
+  getCompactPubkeysSerializer,
   getCompactU64Serializer,
 } from '../../hooked';
 
@@ -49,6 +64,32 @@ export type AccountInfoAssertion =
       expectedHash: Uint8Array;
       start: CompactU64;
       length: CompactU64;
+    }
+  | { __kind: 'OwnerIn'; value: CompactPubkeys; operator: SetOperator }
+  | {
+      __kind: 'KnownOwnerIn';
+      value: CompactKnownPrograms;
+      operator: SetOperator;
+    }
+  | {
+      __kind: 'OwnerAccountRef';
+      accountIndex: number;
+      operator: EquatableOperator;
+    }
+  | { __kind: 'IsRentExempt'; value: boolean; operator: EquatableOperator }
+  | { __kind: 'Exists'; value: boolean; operator: EquatableOperator }
+  | { __kind: 'IsWallet'; value: boolean; operator: EquatableOperator }
+  | {
+      __kind: 'LamportsAboveRentMinimum';
+      value: bigint;
+      operator: IntegerOperator;
+    }
+  | {
+      __kind: 'KeyIsDerived';
+      seeds: CompactPdaSeeds;
+      programId: PublicKey;
+      bump: Option<number>;
+      operator: EquatableOperator;
     };
 
 export type AccountInfoAssertionArgs =
@@ -81,6 +122,32 @@ export type AccountInfoAssertionArgs =
       expectedHash: Uint8Array;
       start: CompactU64Args;
       length: CompactU64Args;
+    }
+  | { __kind: 'OwnerIn'; value: CompactPubkeysArgs; operator: SetOperatorArgs }
+  | {
+      __kind: 'KnownOwnerIn';
+      value: CompactKnownProgramsArgs;
+      operator: SetOperatorArgs;
+    }
+  | {
+      __kind: 'OwnerAccountRef';
+      accountIndex: number;
+      operator: EquatableOperatorArgs;
+    }
+  | { __kind: 'IsRentExempt'; value: boolean; operator: EquatableOperatorArgs }
+  | { __kind: 'Exists'; value: boolean; operator: EquatableOperatorArgs }
+  | { __kind: 'IsWallet'; value: boolean; operator: EquatableOperatorArgs }
+  | {
+      __kind: 'LamportsAboveRentMinimum';
+      value: number | bigint;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'KeyIsDerived';
+      seeds: CompactPdaSeedsArgs;
+      programId: PublicKey;
+      bump: OptionOrNullable<number>;
+      operator: EquatableOperatorArgs;
     };
 
 export function getAccountInfoAssertionSerializer(): Serializer<
@@ -153,6 +220,71 @@ export function getAccountInfoAssertionSerializer(): Serializer<
           ['length', getCompactU64Serializer()],
         ]),
       ],
+      [
+        'OwnerIn',
+        struct<GetDataEnumKindContent<AccountInfoAssertion, 'OwnerIn'>>([
+          ['value', getCompactPubkeysSerializer()],
+          ['operator', getSetOperatorSerializer()],
+        ]),
+      ],
+      [
+        'KnownOwnerIn',
+        struct<GetDataEnumKindContent<AccountInfoAssertion, 'KnownOwnerIn'>>([
+          ['value', getCompactKnownProgramsSerializer()],
+          ['operator', getSetOperatorSerializer()],
+        ]),
+      ],
+      [
+        'OwnerAccountRef',
+        struct<GetDataEnumKindContent<AccountInfoAssertion, 'OwnerAccountRef'>>(
+          [
+            ['accountIndex', u8()],
+            ['operator', getEquatableOperatorSerializer()],
+          ]
+        ),
+      ],
+      [
+        'IsRentExempt',
+        struct<GetDataEnumKindContent<AccountInfoAssertion, 'IsRentExempt'>>([
+          ['value', bool()],
+          ['operator', getEquatableOperatorSerializer()],
+        ]),
+      ],
+      [
+        'Exists',
+        struct<GetDataEnumKindContent<AccountInfoAssertion, 'Exists'>>([
+          ['value', bool()],
+          ['operator', getEquatableOperatorSerializer()],
+        ]),
+      ],
+      [
+        'IsWallet',
+        struct<GetDataEnumKindContent<AccountInfoAssertion, 'IsWallet'>>([
+          ['value', bool()],
+          ['operator', getEquatableOperatorSerializer()],
+        ]),
+      ],
+      [
+        'LamportsAboveRentMinimum',
+        struct<
+          GetDataEnumKindContent<
+            AccountInfoAssertion,
+            'LamportsAboveRentMinimum'
+          >
+        >([
+          ['value', i128()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'KeyIsDerived',
+        struct<GetDataEnumKindContent<AccountInfoAssertion, 'KeyIsDerived'>>([
+          ['seeds', getCompactPdaSeedsSerializer()],
+          ['programId', publicKeySerializer()],
+          ['bump', option(u8())],
+          ['operator', getEquatableOperatorSerializer()],
+        ]),
+      ],
     ],
     { description: 'AccountInfoAssertion' }
   ) as Serializer<AccountInfoAssertionArgs, AccountInfoAssertion>;
@@ -195,6 +327,41 @@ export function accountInfoAssertion(
   kind: 'VerifyDatahash',
   data: GetDataEnumKindContent<AccountInfoAssertionArgs, 'VerifyDatahash'>
 ): GetDataEnumKind<AccountInfoAssertionArgs, 'VerifyDatahash'>;
+export function accountInfoAssertion(
+  kind: 'OwnerIn',
+  data: GetDataEnumKindContent<AccountInfoAssertionArgs, 'OwnerIn'>
+): GetDataEnumKind<AccountInfoAssertionArgs, 'OwnerIn'>;
+export function accountInfoAssertion(
+  kind: 'KnownOwnerIn',
+  data: GetDataEnumKindContent<AccountInfoAssertionArgs, 'KnownOwnerIn'>
+): GetDataEnumKind<AccountInfoAssertionArgs, 'KnownOwnerIn'>;
+export function accountInfoAssertion(
+  kind: 'OwnerAccountRef',
+  data: GetDataEnumKindContent<AccountInfoAssertionArgs, 'OwnerAccountRef'>
+): GetDataEnumKind<AccountInfoAssertionArgs, 'OwnerAccountRef'>;
+export function accountInfoAssertion(
+  kind: 'IsRentExempt',
+  data: GetDataEnumKindContent<AccountInfoAssertionArgs, 'IsRentExempt'>
+): GetDataEnumKind<AccountInfoAssertionArgs, 'IsRentExempt'>;
+export function accountInfoAssertion(
+  kind: 'Exists',
+  data: GetDataEnumKindContent<AccountInfoAssertionArgs, 'Exists'>
+): GetDataEnumKind<AccountInfoAssertionArgs, 'Exists'>;
+export function accountInfoAssertion(
+  kind: 'IsWallet',
+  data: GetDataEnumKindContent<AccountInfoAssertionArgs, 'IsWallet'>
+): GetDataEnumKind<AccountInfoAssertionArgs, 'IsWallet'>;
+export function accountInfoAssertion(
+  kind: 'LamportsAboveRentMinimum',
+  data: GetDataEnumKindContent<
+    AccountInfoAssertionArgs,
+    'LamportsAboveRentMinimum'
+  >
+): GetDataEnumKind<AccountInfoAssertionArgs, 'LamportsAboveRentMinimum'>;
+export function accountInfoAssertion(
+  kind: 'KeyIsDerived',
+  data: GetDataEnumKindContent<AccountInfoAssertionArgs, 'KeyIsDerived'>
+): GetDataEnumKind<AccountInfoAssertionArgs, 'KeyIsDerived'>;
 export function accountInfoAssertion<
   K extends AccountInfoAssertionArgs['__kind'],
 >(kind: K, data?: any): Extract<AccountInfoAssertionArgs, { __kind: K }> {
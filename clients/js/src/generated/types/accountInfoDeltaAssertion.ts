@@ -10,16 +10,24 @@ import {
   GetDataEnumKind,
   GetDataEnumKindContent,
   Serializer,
+  bool,
   dataEnum,
   i128,
+  i32,
   struct,
 } from '@metaplex-foundation/umi/serializers';
 import {
+  AccountHashScope,
+  AccountHashScopeArgs,
   EquatableOperator,
   EquatableOperatorArgs,
+  HashAlgorithm,
+  HashAlgorithmArgs,
   IntegerOperator,
   IntegerOperatorArgs,
+  getAccountHashScopeSerializer,
   getEquatableOperatorSerializer,
+  getHashAlgorithmSerializer,
   getIntegerOperatorSerializer,
 } from '.';
 
@@ -27,7 +35,21 @@ export type AccountInfoDeltaAssertion =
   | { __kind: 'Lamports'; value: bigint; operator: IntegerOperator }
   | { __kind: 'DataLength'; value: bigint; operator: IntegerOperator }
   | { __kind: 'Owner'; operator: EquatableOperator }
-  | { __kind: 'RentEpoch'; value: bigint; operator: IntegerOperator };
+  | { __kind: 'RentEpoch'; value: bigint; operator: IntegerOperator }
+  | {
+      __kind: 'LamportsBps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperator;
+    }
+  | { __kind: 'Executable'; operator: EquatableOperator }
+  | { __kind: 'Key'; operator: EquatableOperator }
+  | {
+      __kind: 'Hash';
+      algorithm: HashAlgorithm;
+      scope: AccountHashScope;
+      operator: EquatableOperator;
+    };
 
 export type AccountInfoDeltaAssertionArgs =
   | {
@@ -45,6 +67,20 @@ export type AccountInfoDeltaAssertionArgs =
       __kind: 'RentEpoch';
       value: number | bigint;
       operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'LamportsBps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperatorArgs;
+    }
+  | { __kind: 'Executable'; operator: EquatableOperatorArgs }
+  | { __kind: 'Key'; operator: EquatableOperatorArgs }
+  | {
+      __kind: 'Hash';
+      algorithm: HashAlgorithmArgs;
+      scope: AccountHashScopeArgs;
+      operator: EquatableOperatorArgs;
     };
 
 export function getAccountInfoDeltaAssertionSerializer(): Serializer<
@@ -82,6 +118,36 @@ export function getAccountInfoDeltaAssertionSerializer(): Serializer<
           ['operator', getIntegerOperatorSerializer()],
         ]),
       ],
+      [
+        'LamportsBps',
+        struct<
+          GetDataEnumKindContent<AccountInfoDeltaAssertion, 'LamportsBps'>
+        >([
+          ['value', i32()],
+          ['absolute', bool()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'Executable',
+        struct<GetDataEnumKindContent<AccountInfoDeltaAssertion, 'Executable'>>(
+          [['operator', getEquatableOperatorSerializer()]]
+        ),
+      ],
+      [
+        'Key',
+        struct<GetDataEnumKindContent<AccountInfoDeltaAssertion, 'Key'>>([
+          ['operator', getEquatableOperatorSerializer()],
+        ]),
+      ],
+      [
+        'Hash',
+        struct<GetDataEnumKindContent<AccountInfoDeltaAssertion, 'Hash'>>([
+          ['algorithm', getHashAlgorithmSerializer()],
+          ['scope', getAccountHashScopeSerializer()],
+          ['operator', getEquatableOperatorSerializer()],
+        ]),
+      ],
     ],
     { description: 'AccountInfoDeltaAssertion' }
   ) as Serializer<AccountInfoDeltaAssertionArgs, AccountInfoDeltaAssertion>;
@@ -104,6 +170,22 @@ export function accountInfoDeltaAssertion(
   kind: 'RentEpoch',
   data: GetDataEnumKindContent<AccountInfoDeltaAssertionArgs, 'RentEpoch'>
 ): GetDataEnumKind<AccountInfoDeltaAssertionArgs, 'RentEpoch'>;
+export function accountInfoDeltaAssertion(
+  kind: 'LamportsBps',
+  data: GetDataEnumKindContent<AccountInfoDeltaAssertionArgs, 'LamportsBps'>
+): GetDataEnumKind<AccountInfoDeltaAssertionArgs, 'LamportsBps'>;
+export function accountInfoDeltaAssertion(
+  kind: 'Executable',
+  data: GetDataEnumKindContent<AccountInfoDeltaAssertionArgs, 'Executable'>
+): GetDataEnumKind<AccountInfoDeltaAssertionArgs, 'Executable'>;
+export function accountInfoDeltaAssertion(
+  kind: 'Key',
+  data: GetDataEnumKindContent<AccountInfoDeltaAssertionArgs, 'Key'>
+): GetDataEnumKind<AccountInfoDeltaAssertionArgs, 'Key'>;
+export function accountInfoDeltaAssertion(
+  kind: 'Hash',
+  data: GetDataEnumKindContent<AccountInfoDeltaAssertionArgs, 'Hash'>
+): GetDataEnumKind<AccountInfoDeltaAssertionArgs, 'Hash'>;
 export function accountInfoDeltaAssertion<
   K extends AccountInfoDeltaAssertionArgs['__kind'],
 >(kind: K, data?: any): Extract<AccountInfoDeltaAssertionArgs, { __kind: K }> {
@@ -11,6 +11,7 @@ import {
   GetDataEnumKind,
   GetDataEnumKindContent,
   Serializer,
+  array,
   bool,
   bytes,
   dataEnum,
@@ -55,7 +56,32 @@ export type AssertionResult =
   | {
       __kind: 'Bool';
       fields: [Option<boolean>, Option<boolean>, number, boolean];
-    };
+    }
+  | {
+      __kind: 'Range';
+      fields: [Option<bigint>, bigint, bigint, number, boolean];
+    }
+  | {
+      __kind: 'PubkeySet';
+      fields: [Option<PublicKey>, Array<Option<PublicKey>>, number, boolean];
+    }
+  | {
+      __kind: 'BytesPattern';
+      fields: [Option<number>, number, Uint8Array, number, boolean];
+    }
+  | {
+      __kind: 'I80F48';
+      fields: [Option<bigint>, Option<bigint>, number, boolean];
+    }
+  | {
+      __kind: 'U64F64';
+      fields: [Option<bigint>, Option<bigint>, number, boolean];
+    }
+  | { __kind: 'Wad'; fields: [Option<bigint>, Option<bigint>, number, boolean] }
+  | { __kind: 'Ray'; fields: [Option<bigint>, Option<bigint>, number, boolean] }
+  | { __kind: 'F32'; fields: [Option<number>, Option<number>, number, boolean] }
+  | { __kind: 'F64'; fields: [Option<bigint>, Option<bigint>, number, boolean] }
+  | { __kind: 'String'; fields: [Uint8Array, Uint8Array, number, boolean] };
 
 export type AssertionResultArgs =
   | {
@@ -166,7 +192,85 @@ export type AssertionResultArgs =
         number,
         boolean,
       ];
-    };
+    }
+  | {
+      __kind: 'Range';
+      fields: [
+        OptionOrNullable<number | bigint>,
+        number | bigint,
+        number | bigint,
+        number,
+        boolean,
+      ];
+    }
+  | {
+      __kind: 'PubkeySet';
+      fields: [
+        OptionOrNullable<PublicKey>,
+        Array<OptionOrNullable<PublicKey>>,
+        number,
+        boolean,
+      ];
+    }
+  | {
+      __kind: 'BytesPattern';
+      fields: [OptionOrNullable<number>, number, Uint8Array, number, boolean];
+    }
+  | {
+      __kind: 'I80F48';
+      fields: [
+        OptionOrNullable<number | bigint>,
+        OptionOrNullable<number | bigint>,
+        number,
+        boolean,
+      ];
+    }
+  | {
+      __kind: 'U64F64';
+      fields: [
+        OptionOrNullable<number | bigint>,
+        OptionOrNullable<number | bigint>,
+        number,
+        boolean,
+      ];
+    }
+  | {
+      __kind: 'Wad';
+      fields: [
+        OptionOrNullable<number | bigint>,
+        OptionOrNullable<number | bigint>,
+        number,
+        boolean,
+      ];
+    }
+  | {
+      __kind: 'Ray';
+      fields: [
+        OptionOrNullable<number | bigint>,
+        OptionOrNullable<number | bigint>,
+        number,
+        boolean,
+      ];
+    }
+  | {
+      __kind: 'F32';
+      fields: [
+        OptionOrNullable<number>,
+        OptionOrNullable<number>,
+        number,
+        boolean,
+      ];
+    }
+  | {
+      __kind: 'F64';
+      fields: [
+        OptionOrNullable<number | bigint>,
+        OptionOrNullable<number | bigint>,
+        number,
+        boolean,
+      ];
+    }
+  | { __kind: 'String'; fields: [Uint8Array, Uint8Array, number, boolean] };
 
 export function getAssertionResultSerializer(): Serializer<
   AssertionResultArgs,
@@ -268,6 +372,85 @@ export function getAssertionResultSerializer(): Serializer<
           ['fields', tuple([option(bool()), option(bool()), u8(), bool()])],
         ]),
       ],
+      [
+        'Range',
+        struct<GetDataEnumKindContent<AssertionResult, 'Range'>>([
+          ['fields', tuple([option(i128()), i128(), i128(), u8(), bool()])],
+        ]),
+      ],
+      [
+        'PubkeySet',
+        struct<GetDataEnumKindContent<AssertionResult, 'PubkeySet'>>([
+          [
+            'fields',
+            tuple([
+              option(publicKeySerializer()),
+              array(option(publicKeySerializer())),
+              u8(),
+              bool(),
+            ]),
+          ],
+        ]),
+      ],
+      [
+        'BytesPattern',
+        struct<GetDataEnumKindContent<AssertionResult, 'BytesPattern'>>([
+          [
+            'fields',
+            tuple([option(u32()), u32(), bytes({ size: u32() }), u8(), bool()]),
+          ],
+        ]),
+      ],
+      [
+        'I80F48',
+        struct<GetDataEnumKindContent<AssertionResult, 'I80F48'>>([
+          ['fields', tuple([option(i128()), option(i128()), u8(), bool()])],
+        ]),
+      ],
+      [
+        'U64F64',
+        struct<GetDataEnumKindContent<AssertionResult, 'U64F64'>>([
+          ['fields', tuple([option(u128()), option(u128()), u8(), bool()])],
+        ]),
+      ],
+      [
+        'Wad',
+        struct<GetDataEnumKindContent<AssertionResult, 'Wad'>>([
+          ['fields', tuple([option(u128()), option(u128()), u8(), bool()])],
+        ]),
+      ],
+      [
+        'Ray',
+        struct<GetDataEnumKindContent<AssertionResult, 'Ray'>>([
+          ['fields', tuple([option(u128()), option(u128()), u8(), bool()])],
+        ]),
+      ],
+      [
+        'F32',
+        struct<GetDataEnumKindContent<AssertionResult, 'F32'>>([
+          ['fields', tuple([option(u32()), option(u32()), u8(), bool()])],
+        ]),
+      ],
+      [
+        'F64',
+        struct<GetDataEnumKindContent<AssertionResult, 'F64'>>([
+          ['fields', tuple([option(u64()), option(u64()), u8(), bool()])],
+        ]),
+      ],
+      [
+        'String',
+        struct<GetDataEnumKindContent<AssertionResult, 'String'>>([
+          [
+            'fields',
+            tuple([
+              bytes({ size: u32() }),
+              bytes({ size: u32() }),
+              u8(),
+              bool(),
+            ]),
+          ],
+        ]),
+      ],
     ],
     { description: 'AssertionResult' }
   ) as Serializer<AssertionResultArgs, AssertionResult>;
@@ -326,6 +509,46 @@ export function assertionResult(
   kind: 'Bool',
   data: GetDataEnumKindContent<AssertionResultArgs, 'Bool'>['fields']
 ): GetDataEnumKind<AssertionResultArgs, 'Bool'>;
+export function assertionResult(
+  kind: 'Range',
+  data: GetDataEnumKindContent<AssertionResultArgs, 'Range'>['fields']
+): GetDataEnumKind<AssertionResultArgs, 'Range'>;
+export function assertionResult(
+  kind: 'PubkeySet',
+  data: GetDataEnumKindContent<AssertionResultArgs, 'PubkeySet'>['fields']
+): GetDataEnumKind<AssertionResultArgs, 'PubkeySet'>;
+export function assertionResult(
+  kind: 'BytesPattern',
+  data: GetDataEnumKindContent<AssertionResultArgs, 'BytesPattern'>['fields']
+): GetDataEnumKind<AssertionResultArgs, 'BytesPattern'>;
+export function assertionResult(
+  kind: 'I80F48',
+  data: GetDataEnumKindContent<AssertionResultArgs, 'I80F48'>['fields']
+): GetDataEnumKind<AssertionResultArgs, 'I80F48'>;
+export function assertionResult(
+  kind: 'U64F64',
+  data: GetDataEnumKindContent<AssertionResultArgs, 'U64F64'>['fields']
+): GetDataEnumKind<AssertionResultArgs, 'U64F64'>;
+export function assertionResult(
+  kind: 'Wad',
+  data: GetDataEnumKindContent<AssertionResultArgs, 'Wad'>['fields']
+): GetDataEnumKind<AssertionResultArgs, 'Wad'>;
+export function assertionResult(
+  kind: 'Ray',
+  data: GetDataEnumKindContent<AssertionResultArgs, 'Ray'>['fields']
+): GetDataEnumKind<AssertionResultArgs, 'Ray'>;
+export function assertionResult(
+  kind: 'F32',
+  data: GetDataEnumKindContent<AssertionResultArgs, 'F32'>['fields']
+): GetDataEnumKind<AssertionResultArgs, 'F32'>;
+export function assertionResult(
+  kind: 'F64',
+  data: GetDataEnumKindContent<AssertionResultArgs, 'F64'>['fields']
+): GetDataEnumKind<AssertionResultArgs, 'F64'>;
+export function assertionResult(
+  kind: 'String',
+  data: GetDataEnumKindContent<AssertionResultArgs, 'String'>['fields']
+): GetDataEnumKind<AssertionResultArgs, 'String'>;
 export function assertionResult<K extends AssertionResultArgs['__kind']>(
   kind: K,
   data?: any
@@ -6,18 +6,21 @@
  * @see https://github.com/codama-idl/codama
  */
 
-import { PublicKey } from '@metaplex-foundation/umi';
+import { Option, OptionOrNullable, PublicKey } from '@metaplex-foundation/umi';
 import {
   GetDataEnumKind,
   GetDataEnumKindContent,
   Serializer,
   bool,
   dataEnum,
+  f32,
+  f64,
   i128,
   i16,
   i32,
   i64,
   i8,
+  option,
   publicKey as publicKeySerializer,
   struct,
   u128,
@@ -27,17 +30,38 @@ import {
   u8,
 } from '@metaplex-foundation/umi/serializers';
 import {
+  BytesOperator,
+  BytesOperatorArgs,
   EquatableOperator,
   EquatableOperatorArgs,
   IntegerOperator,
   IntegerOperatorArgs,
+  RangeOperator,
+  RangeOperatorArgs,
+  SetOperator,
+  SetOperatorArgs,
+  StringLayout,
+  StringLayoutArgs,
+  StringOperator,
+  StringOperatorArgs,
+  getBytesOperatorSerializer,
   getEquatableOperatorSerializer,
   getIntegerOperatorSerializer,
+  getRangeOperatorSerializer,
+  getSetOperatorSerializer,
+  getStringLayoutSerializer,
+  getStringOperatorSerializer,
 } from '.';
 import {
   CompactBytes,
   CompactBytesArgs,
+  CompactPubkeys,
+  CompactPubkeysArgs,
+  CompactU64,
+  CompactU64Args,
   getCompactBytesSerializer,
+  getCompactPubkeysSerializer,
+  getCompactU64Serializer,
 } from '../../hooked';
 
 export type DataValueAssertion =
@@ -53,7 +77,76 @@ export type DataValueAssertion =
   | { __kind: 'U128'; value: bigint; operator: IntegerOperator }
   | { __kind: 'I128'; value: bigint; operator: IntegerOperator }
   | { __kind: 'Bytes'; value: CompactBytes; operator: EquatableOperator }
-  | { __kind: 'Pubkey'; value: PublicKey; operator: EquatableOperator };
+  | { __kind: 'Pubkey'; value: PublicKey; operator: EquatableOperator }
+  | { __kind: 'PubkeyIn'; value: CompactPubkeys; operator: SetOperator }
+  | {
+      __kind: 'BytesMasked';
+      value: CompactBytes;
+      mask: CompactBytes;
+      operator: EquatableOperator;
+    }
+  | {
+      __kind: 'BytesPattern';
+      value: CompactBytes;
+      length: Option<CompactU64>;
+      operator: BytesOperator;
+    }
+  | { __kind: 'I80F48'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'U64F64'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'Wad'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'Ray'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'F32'; value: number; operator: IntegerOperator }
+  | { __kind: 'F64'; value: number; operator: IntegerOperator }
+  | { __kind: 'U16BE'; value: number; operator: IntegerOperator }
+  | { __kind: 'I16BE'; value: number; operator: IntegerOperator }
+  | { __kind: 'U32BE'; value: number; operator: IntegerOperator }
+  | { __kind: 'I32BE'; value: number; operator: IntegerOperator }
+  | { __kind: 'U64BE'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'I64BE'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'U128BE'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'I128BE'; value: bigint; operator: IntegerOperator }
+  | {
+      __kind: 'UnsignedInt';
+      value: bigint;
+      byteWidth: number;
+      bigEndian: boolean;
+      operator: IntegerOperator;
+    }
+  | {
+      __kind: 'BitField';
+      value: bigint;
+      byteWidth: number;
+      bigEndian: boolean;
+      shift: number;
+      bitWidth: number;
+      operator: IntegerOperator;
+    }
+  | {
+      __kind: 'String';
+      value: CompactBytes;
+      layout: StringLayout;
+      operator: StringOperator;
+    }
+  | {
+      __kind: 'StringLength';
+      value: number;
+      layout: StringLayout;
+      operator: IntegerOperator;
+    }
+  | {
+      __kind: 'PubkeyAccountRef';
+      accountIndex: number;
+      operator: EquatableOperator;
+    }
+  | {
+      __kind: 'IntegerRange';
+      lower: bigint;
+      upper: bigint;
+      byteWidth: number;
+      signed: boolean;
+      bigEndian: boolean;
+      operator: RangeOperator;
+    };
 
 export type DataValueAssertionArgs =
   | { __kind: 'Bool'; value: boolean; operator: EquatableOperatorArgs }
@@ -72,7 +165,76 @@ export type DataValueAssertionArgs =
       value: CompactBytesArgs;
       operator: EquatableOperatorArgs;
     }
-  | { __kind: 'Pubkey'; value: PublicKey; operator: EquatableOperatorArgs };
+  | { __kind: 'Pubkey'; value: PublicKey; operator: EquatableOperatorArgs }
+  | { __kind: 'PubkeyIn'; value: CompactPubkeysArgs; operator: SetOperatorArgs }
+  | {
+      __kind: 'BytesMasked';
+      value: CompactBytesArgs;
+      mask: CompactBytesArgs;
+      operator: EquatableOperatorArgs;
+    }
+  | {
+      __kind: 'BytesPattern';
+      value: CompactBytesArgs;
+      length: OptionOrNullable<CompactU64Args>;
+      operator: BytesOperatorArgs;
+    }
+  | { __kind: 'I80F48'; value: number | bigint; operator: IntegerOperatorArgs }
+  | { __kind: 'U64F64'; value: number | bigint; operator: IntegerOperatorArgs }
+  | { __kind: 'Wad'; value: number | bigint; operator: IntegerOperatorArgs }
+  | { __kind: 'Ray'; value: number | bigint; operator: IntegerOperatorArgs }
+  | { __kind: 'F32'; value: number; operator: IntegerOperatorArgs }
+  | { __kind: 'F64'; value: number; operator: IntegerOperatorArgs }
+  | { __kind: 'U16BE'; value: number; operator: IntegerOperatorArgs }
+  | { __kind: 'I16BE'; value: number; operator: IntegerOperatorArgs }
+  | { __kind: 'U32BE'; value: number; operator: IntegerOperatorArgs }
+  | { __kind: 'I32BE'; value: number; operator: IntegerOperatorArgs }
+  | { __kind: 'U64BE'; value: number | bigint; operator: IntegerOperatorArgs }
+  | { __kind: 'I64BE'; value: number | bigint; operator: IntegerOperatorArgs }
+  | { __kind: 'U128BE'; value: number | bigint; operator: IntegerOperatorArgs }
+  | { __kind: 'I128BE'; value: number | bigint; operator: IntegerOperatorArgs }
+  | {
+      __kind: 'UnsignedInt';
+      value: number | bigint;
+      byteWidth: number;
+      bigEndian: boolean;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'BitField';
+      value: number | bigint;
+      byteWidth: number;
+      bigEndian: boolean;
+      shift: number;
+      bitWidth: number;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'String';
+      value: CompactBytesArgs;
+      layout: StringLayoutArgs;
+      operator: StringOperatorArgs;
+    }
+  | {
+      __kind: 'StringLength';
+      value: number;
+      layout: StringLayoutArgs;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'PubkeyAccountRef';
+      accountIndex: number;
+      operator: EquatableOperatorArgs;
+    }
+  | {
+      __kind: 'IntegerRange';
+      lower: number | bigint;
+      upper: number | bigint;
+      byteWidth: number;
+      signed: boolean;
+      bigEndian: boolean;
+      operator: RangeOperatorArgs;
+    };
 
 export function getDataValueAssertionSerializer(): Serializer<
   DataValueAssertionArgs,
@@ -171,6 +333,181 @@ export function getDataValueAssertionSerializer(): Serializer<
           ['operator', getEquatableOperatorSerializer()],
         ]),
       ],
+      [
+        'PubkeyIn',
+        struct<GetDataEnumKindContent<DataValueAssertion, 'PubkeyIn'>>([
+          ['value', getCompactPubkeysSerializer()],
+          ['operator', getSetOperatorSerializer()],
+        ]),
+      ],
+      [
+        'BytesMasked',
+        struct<GetDataEnumKindContent<DataValueAssertion, 'BytesMasked'>>([
+          ['value', getCompactBytesSerializer()],
+          ['mask', getCompactBytesSerializer()],
+          ['operator', getEquatableOperatorSerializer()],
+        ]),
+      ],
+      [
+        'BytesPattern',
+        struct<GetDataEnumKindContent<DataValueAssertion, 'BytesPattern'>>([
+          ['value', getCompactBytesSerializer()],
+          ['length', option(getCompactU64Serializer())],
+          ['operator', getBytesOperatorSerializer()],
+        ]),
+      ],
+      [
+        'I80F48',
+        struct<GetDataEnumKindContent<DataValueAssertion, 'I80F48'>>([
+          ['value', i128()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'U64F64',
+        struct<GetDataEnumKindContent<DataValueAssertion, 'U64F64'>>([
+          ['value', u128()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'Wad',
+        struct<GetDataEnumKindContent<DataValueAssertion, 'Wad'>>([
+          ['value', u128()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'Ray',
+        struct<GetDataEnumKindContent<DataValueAssertion, 'Ray'>>([
+          ['value', u128()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'F32',
+        struct<GetDataEnumKindContent<DataValueAssertion, 'F32'>>([
+          ['value', f32()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'F64',
+        struct<GetDataEnumKindContent<DataValueAssertion, 'F64'>>([
+          ['value', f64()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'U16BE',
+        struct<GetDataEnumKindContent<DataValueAssertion, 'U16BE'>>([
+          ['value', u16()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'I16BE',
+        struct<GetDataEnumKindContent<DataValueAssertion, 'I16BE'>>([
+          ['value', i16()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'U32BE',
+        struct<GetDataEnumKindContent<DataValueAssertion, 'U32BE'>>([
+          ['value', u32()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'I32BE',
+        struct<GetDataEnumKindContent<DataValueAssertion, 'I32BE'>>([
+          ['value', i32()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'U64BE',
+        struct<GetDataEnumKindContent<DataValueAssertion, 'U64BE'>>([
+          ['value', u64()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'I64BE',
+        struct<GetDataEnumKindContent<DataValueAssertion, 'I64BE'>>([
+          ['value', i64()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'U128BE',
+        struct<GetDataEnumKindContent<DataValueAssertion, 'U128BE'>>([
+          ['value', u128()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'I128BE',
+        struct<GetDataEnumKindContent<DataValueAssertion, 'I128BE'>>([
+          ['value', i128()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'UnsignedInt',
+        struct<GetDataEnumKindContent<DataValueAssertion, 'UnsignedInt'>>([
+          ['value', u128()],
+          ['byteWidth', u8()],
+          ['bigEndian', bool()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'BitField',
+        struct<GetDataEnumKindContent<DataValueAssertion, 'BitField'>>([
+          ['value', u128()],
+          ['byteWidth', u8()],
+          ['bigEndian', bool()],
+          ['shift', u8()],
+          ['bitWidth', u8()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'String',
+        struct<GetDataEnumKindContent<DataValueAssertion, 'String'>>([
+          ['value', getCompactBytesSerializer()],
+          ['layout', getStringLayoutSerializer()],
+          ['operator', getStringOperatorSerializer()],
+        ]),
+      ],
+      [
+        'StringLength',
+        struct<GetDataEnumKindContent<DataValueAssertion, 'StringLength'>>([
+          ['value', u32()],
+          ['layout', getStringLayoutSerializer()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'PubkeyAccountRef',
+        struct<GetDataEnumKindContent<DataValueAssertion, 'PubkeyAccountRef'>>([
+          ['accountIndex', u8()],
+          ['operator', getEquatableOperatorSerializer()],
+        ]),
+      ],
+      [
+        'IntegerRange',
+        struct<GetDataEnumKindContent<DataValueAssertion, 'IntegerRange'>>([
+          ['lower', i128()],
+          ['upper', i128()],
+          ['byteWidth', u8()],
+          ['signed', bool()],
+          ['bigEndian', bool()],
+          ['operator', getRangeOperatorSerializer()],
+        ]),
+      ],
     ],
     { description: 'DataValueAssertion' }
   ) as Serializer<DataValueAssertionArgs, DataValueAssertion>;
@@ -229,6 +566,98 @@ export function dataValueAssertion(
   kind: 'Pubkey',
   data: GetDataEnumKindContent<DataValueAssertionArgs, 'Pubkey'>
 ): GetDataEnumKind<DataValueAssertionArgs, 'Pubkey'>;
+export function dataValueAssertion(
+  kind: 'PubkeyIn',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'PubkeyIn'>
+): GetDataEnumKind<DataValueAssertionArgs, 'PubkeyIn'>;
+export function dataValueAssertion(
+  kind: 'BytesMasked',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'BytesMasked'>
+): GetDataEnumKind<DataValueAssertionArgs, 'BytesMasked'>;
+export function dataValueAssertion(
+  kind: 'BytesPattern',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'BytesPattern'>
+): GetDataEnumKind<DataValueAssertionArgs, 'BytesPattern'>;
+export function dataValueAssertion(
+  kind: 'I80F48',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'I80F48'>
+): GetDataEnumKind<DataValueAssertionArgs, 'I80F48'>;
+export function dataValueAssertion(
+  kind: 'U64F64',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'U64F64'>
+): GetDataEnumKind<DataValueAssertionArgs, 'U64F64'>;
+export function dataValueAssertion(
+  kind: 'Wad',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'Wad'>
+): GetDataEnumKind<DataValueAssertionArgs, 'Wad'>;
+export function dataValueAssertion(
+  kind: 'Ray',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'Ray'>
+): GetDataEnumKind<DataValueAssertionArgs, 'Ray'>;
+export function dataValueAssertion(
+  kind: 'F32',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'F32'>
+): GetDataEnumKind<DataValueAssertionArgs, 'F32'>;
+export function dataValueAssertion(
+  kind: 'F64',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'F64'>
+): GetDataEnumKind<DataValueAssertionArgs, 'F64'>;
+export function dataValueAssertion(
+  kind: 'U16BE',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'U16BE'>
+): GetDataEnumKind<DataValueAssertionArgs, 'U16BE'>;
+export function dataValueAssertion(
+  kind: 'I16BE',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'I16BE'>
+): GetDataEnumKind<DataValueAssertionArgs, 'I16BE'>;
+export function dataValueAssertion(
+  kind: 'U32BE',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'U32BE'>
+): GetDataEnumKind<DataValueAssertionArgs, 'U32BE'>;
+export function dataValueAssertion(
+  kind: 'I32BE',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'I32BE'>
+): GetDataEnumKind<DataValueAssertionArgs, 'I32BE'>;
+export function dataValueAssertion(
+  kind: 'U64BE',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'U64BE'>
+): GetDataEnumKind<DataValueAssertionArgs, 'U64BE'>;
+export function dataValueAssertion(
+  kind: 'I64BE',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'I64BE'>
+): GetDataEnumKind<DataValueAssertionArgs, 'I64BE'>;
+export function dataValueAssertion(
+  kind: 'U128BE',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'U128BE'>
+): GetDataEnumKind<DataValueAssertionArgs, 'U128BE'>;
+export function dataValueAssertion(
+  kind: 'I128BE',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'I128BE'>
+): GetDataEnumKind<DataValueAssertionArgs, 'I128BE'>;
+export function dataValueAssertion(
+  kind: 'UnsignedInt',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'UnsignedInt'>
+): GetDataEnumKind<DataValueAssertionArgs, 'UnsignedInt'>;
+export function dataValueAssertion(
+  kind: 'BitField',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'BitField'>
+): GetDataEnumKind<DataValueAssertionArgs, 'BitField'>;
+export function dataValueAssertion(
+  kind: 'String',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'String'>
+): GetDataEnumKind<DataValueAssertionArgs, 'String'>;
+export function dataValueAssertion(
+  kind: 'StringLength',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'StringLength'>
+): GetDataEnumKind<DataValueAssertionArgs, 'StringLength'>;
+export function dataValueAssertion(
+  kind: 'PubkeyAccountRef',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'PubkeyAccountRef'>
+): GetDataEnumKind<DataValueAssertionArgs, 'PubkeyAccountRef'>;
+export function dataValueAssertion(
+  kind: 'IntegerRange',
+  data: GetDataEnumKindContent<DataValueAssertionArgs, 'IntegerRange'>
+): GetDataEnumKind<DataValueAssertionArgs, 'IntegerRange'>;
 export function dataValueAssertion<K extends DataValueAssertionArgs['__kind']>(
   kind: K,
   data?: any
@@ -10,6 +10,7 @@ import {
   GetDataEnumKind,
   GetDataEnumKindContent,
   Serializer,
+  bool,
   dataEnum,
   i128,
   i16,
@@ -36,7 +37,58 @@ export type DataValueDeltaAssertion =
   | { __kind: 'I32'; value: bigint; operator: IntegerOperator }
   | { __kind: 'U64'; value: bigint; operator: IntegerOperator }
   | { __kind: 'I64'; value: bigint; operator: IntegerOperator }
-  | { __kind: 'Bytes'; length: number; operator: EquatableOperator };
+  | { __kind: 'Bytes'; length: number; operator: EquatableOperator }
+  | {
+      __kind: 'U8Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperator;
+    }
+  | {
+      __kind: 'I8Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperator;
+    }
+  | {
+      __kind: 'U16Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperator;
+    }
+  | {
+      __kind: 'I16Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperator;
+    }
+  | {
+      __kind: 'U32Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperator;
+    }
+  | {
+      __kind: 'I32Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperator;
+    }
+  | {
+      __kind: 'U64Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperator;
+    }
+  | {
+      __kind: 'I64Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperator;
+    }
+  | { __kind: 'U128'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'I128'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'Pubkey'; operator: EquatableOperator };
 
 export type DataValueDeltaAssertionArgs =
   | { __kind: 'U8'; value: number; operator: IntegerOperatorArgs }
@@ -47,7 +99,58 @@ export type DataValueDeltaAssertionArgs =
   | { __kind: 'I32'; value: number | bigint; operator: IntegerOperatorArgs }
   | { __kind: 'U64'; value: number | bigint; operator: IntegerOperatorArgs }
   | { __kind: 'I64'; value: number | bigint; operator: IntegerOperatorArgs }
-  | { __kind: 'Bytes'; length: number; operator: EquatableOperatorArgs };
+  | { __kind: 'Bytes'; length: number; operator: EquatableOperatorArgs }
+  | {
+      __kind: 'U8Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'I8Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'U16Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'I16Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'U32Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'I32Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'U64Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'I64Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperatorArgs;
+    }
+  | { __kind: 'U128'; value: number | bigint; operator: IntegerOperatorArgs }
+  | { __kind: 'I128'; value: number | bigint; operator: IntegerOperatorArgs }
+  | { __kind: 'Pubkey'; operator: EquatableOperatorArgs };
 
 export function getDataValueDeltaAssertionSerializer(): Serializer<
   DataValueDeltaAssertionArgs,
@@ -118,6 +221,90 @@ export function getDataValueDeltaAssertionSerializer(): Serializer<
           ['operator', getEquatableOperatorSerializer()],
         ]),
       ],
+      [
+        'U8Bps',
+        struct<GetDataEnumKindContent<DataValueDeltaAssertion, 'U8Bps'>>([
+          ['value', i32()],
+          ['absolute', bool()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'I8Bps',
+        struct<GetDataEnumKindContent<DataValueDeltaAssertion, 'I8Bps'>>([
+          ['value', i32()],
+          ['absolute', bool()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'U16Bps',
+        struct<GetDataEnumKindContent<DataValueDeltaAssertion, 'U16Bps'>>([
+          ['value', i32()],
+          ['absolute', bool()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'I16Bps',
+        struct<GetDataEnumKindContent<DataValueDeltaAssertion, 'I16Bps'>>([
+          ['value', i32()],
+          ['absolute', bool()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'U32Bps',
+        struct<GetDataEnumKindContent<DataValueDeltaAssertion, 'U32Bps'>>([
+          ['value', i32()],
+          ['absolute', bool()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'I32Bps',
+        struct<GetDataEnumKindContent<DataValueDeltaAssertion, 'I32Bps'>>([
+          ['value', i32()],
+          ['absolute', bool()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'U64Bps',
+        struct<GetDataEnumKindContent<DataValueDeltaAssertion, 'U64Bps'>>([
+          ['value', i32()],
+          ['absolute', bool()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'I64Bps',
+        struct<GetDataEnumKindContent<DataValueDeltaAssertion, 'I64Bps'>>([
+          ['value', i32()],
+          ['absolute', bool()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'U128',
+        struct<GetDataEnumKindContent<DataValueDeltaAssertion, 'U128'>>([
+          ['value', i128()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'I128',
+        struct<GetDataEnumKindContent<DataValueDeltaAssertion, 'I128'>>([
+          ['value', i128()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
+      [
+        'Pubkey',
+        struct<GetDataEnumKindContent<DataValueDeltaAssertion, 'Pubkey'>>([
+          ['operator', getEquatableOperatorSerializer()],
+        ]),
+      ],
     ],
     { description: 'DataValueDeltaAssertion' }
   ) as Serializer<DataValueDeltaAssertionArgs, DataValueDeltaAssertion>;
@@ -160,6 +347,50 @@ export function dataValueDeltaAssertion(
   kind: 'Bytes',
   data: GetDataEnumKindContent<DataValueDeltaAssertionArgs, 'Bytes'>
 ): GetDataEnumKind<DataValueDeltaAssertionArgs, 'Bytes'>;
+export function dataValueDeltaAssertion(
+  kind: 'U8Bps',
+  data: GetDataEnumKindContent<DataValueDeltaAssertionArgs, 'U8Bps'>
+): GetDataEnumKind<DataValueDeltaAssertionArgs, 'U8Bps'>;
+export function dataValueDeltaAssertion(
+  kind: 'I8Bps',
+  data: GetDataEnumKindContent<DataValueDeltaAssertionArgs, 'I8Bps'>
+): GetDataEnumKind<DataValueDeltaAssertionArgs, 'I8Bps'>;
+export function dataValueDeltaAssertion(
+  kind: 'U16Bps',
+  data: GetDataEnumKindContent<DataValueDeltaAssertionArgs, 'U16Bps'>
+): GetDataEnumKind<DataValueDeltaAssertionArgs, 'U16Bps'>;
+export function dataValueDeltaAssertion(
+  kind: 'I16Bps',
+  data: GetDataEnumKindContent<DataValueDeltaAssertionArgs, 'I16Bps'>
+): GetDataEnumKind<DataValueDeltaAssertionArgs, 'I16Bps'>;
+export function dataValueDeltaAssertion(
+  kind: 'U32Bps',
+  data: GetDataEnumKindContent<DataValueDeltaAssertionArgs, 'U32Bps'>
+): GetDataEnumKind<DataValueDeltaAssertionArgs, 'U32Bps'>;
+export function dataValueDeltaAssertion(
+  kind: 'I32Bps',
+  data: GetDataEnumKindContent<DataValueDeltaAssertionArgs, 'I32Bps'>
+): GetDataEnumKind<DataValueDeltaAssertionArgs, 'I32Bps'>;
+export function dataValueDeltaAssertion(
+  kind: 'U64Bps',
+  data: GetDataEnumKindContent<DataValueDeltaAssertionArgs, 'U64Bps'>
+): GetDataEnumKind<DataValueDeltaAssertionArgs, 'U64Bps'>;
+export function dataValueDeltaAssertion(
+  kind: 'I64Bps',
+  data: GetDataEnumKindContent<DataValueDeltaAssertionArgs, 'I64Bps'>
+): GetDataEnumKind<DataValueDeltaAssertionArgs, 'I64Bps'>;
+export function dataValueDeltaAssertion(
+  kind: 'U128',
+  data: GetDataEnumKindContent<DataValueDeltaAssertionArgs, 'U128'>
+): GetDataEnumKind<DataValueDeltaAssertionArgs, 'U128'>;
+export function dataValueDeltaAssertion(
+  kind: 'I128',
+  data: GetDataEnumKindContent<DataValueDeltaAssertionArgs, 'I128'>
+): GetDataEnumKind<DataValueDeltaAssertionArgs, 'I128'>;
+export function dataValueDeltaAssertion(
+  kind: 'Pubkey',
+  data: GetDataEnumKindContent<DataValueDeltaAssertionArgs, 'Pubkey'>
+): GetDataEnumKind<DataValueDeltaAssertionArgs, 'Pubkey'>;
 export function dataValueDeltaAssertion<
   K extends DataValueDeltaAssertionArgs['__kind'],
 >(kind: K, data?: any): Extract<DataValueDeltaAssertionArgs, { __kind: K }> {
@@ -6,26 +6,151 @@
  * @see https://github.com/codama-idl/codama
  */
 
-import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Serializer,
+  dataEnum,
+  i128,
+  struct,
+  unit,
+} from '@metaplex-foundation/umi/serializers';
 
-export enum IntegerOperator {
-  Equal,
-  NotEqual,
-  GreaterThan,
-  LessThan,
-  GreaterThanOrEqual,
-  LessThanOrEqual,
-  Contains,
-  DoesNotContain,
-}
+export type IntegerOperator =
+  | { __kind: 'Equal' }
+  | { __kind: 'NotEqual' }
+  | { __kind: 'GreaterThan' }
+  | { __kind: 'LessThan' }
+  | { __kind: 'GreaterThanOrEqual' }
+  | { __kind: 'LessThanOrEqual' }
+  | { __kind: 'Contains' }
+  | { __kind: 'DoesNotContain' }
+  | { __kind: 'Between'; lower: bigint; upper: bigint }
+  | { __kind: 'NotBetween'; lower: bigint; upper: bigint }
+  | { __kind: 'BetweenExclusive'; lower: bigint; upper: bigint }
+  | { __kind: 'NotBetweenExclusive'; lower: bigint; upper: bigint };
 
-export type IntegerOperatorArgs = IntegerOperator;
+export type IntegerOperatorArgs =
+  | { __kind: 'Equal' }
+  | { __kind: 'NotEqual' }
+  | { __kind: 'GreaterThan' }
+  | { __kind: 'LessThan' }
+  | { __kind: 'GreaterThanOrEqual' }
+  | { __kind: 'LessThanOrEqual' }
+  | { __kind: 'Contains' }
+  | { __kind: 'DoesNotContain' }
+  | { __kind: 'Between'; lower: number | bigint; upper: number | bigint }
+  | { __kind: 'NotBetween'; lower: number | bigint; upper: number | bigint }
+  | {
+      __kind: 'BetweenExclusive';
+      lower: number | bigint;
+      upper: number | bigint;
+    }
+  | {
+      __kind: 'NotBetweenExclusive';
+      lower: number | bigint;
+      upper: number | bigint;
+    };
 
 export function getIntegerOperatorSerializer(): Serializer<
   IntegerOperatorArgs,
   IntegerOperator
 > {
-  return scalarEnum<IntegerOperator>(IntegerOperator, {
-    description: 'IntegerOperator',
-  }) as Serializer<IntegerOperatorArgs, IntegerOperator>;
+  return dataEnum<IntegerOperator>(
+    [
+      ['Equal', unit()],
+      ['NotEqual', unit()],
+      ['GreaterThan', unit()],
+      ['LessThan', unit()],
+      ['GreaterThanOrEqual', unit()],
+      ['LessThanOrEqual', unit()],
+      ['Contains', unit()],
+      ['DoesNotContain', unit()],
+      [
+        'Between',
+        struct<GetDataEnumKindContent<IntegerOperator, 'Between'>>([
+          ['lower', i128()],
+          ['upper', i128()],
+        ]),
+      ],
+      [
+        'NotBetween',
+        struct<GetDataEnumKindContent<IntegerOperator, 'NotBetween'>>([
+          ['lower', i128()],
+          ['upper', i128()],
+        ]),
+      ],
+      [
+        'BetweenExclusive',
+        struct<GetDataEnumKindContent<IntegerOperator, 'BetweenExclusive'>>([
+          ['lower', i128()],
+          ['upper', i128()],
+        ]),
+      ],
+      [
+        'NotBetweenExclusive',
+        struct<GetDataEnumKindContent<IntegerOperator, 'NotBetweenExclusive'>>([
+          ['lower', i128()],
+          ['upper', i128()],
+        ]),
+      ],
+    ],
+    { description: 'IntegerOperator' }
+  ) as Serializer<IntegerOperatorArgs, IntegerOperator>;
+}
+
+// Data Enum Helpers.
+export function integerOperator(
+  kind: 'Equal'
+): GetDataEnumKind<IntegerOperatorArgs, 'Equal'>;
+export function integerOperator(
+  kind: 'NotEqual'
+): GetDataEnumKind<IntegerOperatorArgs, 'NotEqual'>;
+export function integerOperator(
+  kind: 'GreaterThan'
+): GetDataEnumKind<IntegerOperatorArgs, 'GreaterThan'>;
+export function integerOperator(
+  kind: 'LessThan'
+): GetDataEnumKind<IntegerOperatorArgs, 'LessThan'>;
+export function integerOperator(
+  kind: 'GreaterThanOrEqual'
+): GetDataEnumKind<IntegerOperatorArgs, 'GreaterThanOrEqual'>;
+export function integerOperator(
+  kind: 'LessThanOrEqual'
+): GetDataEnumKind<IntegerOperatorArgs, 'LessThanOrEqual'>;
+export function integerOperator(
+  kind: 'Contains'
+): GetDataEnumKind<IntegerOperatorArgs, 'Contains'>;
+export function integerOperator(
+  kind: 'DoesNotContain'
+): GetDataEnumKind<IntegerOperatorArgs, 'DoesNotContain'>;
+export function integerOperator(
+  kind: 'Between',
+  data: GetDataEnumKindContent<IntegerOperatorArgs, 'Between'>
+): GetDataEnumKind<IntegerOperatorArgs, 'Between'>;
+export function integerOperator(
+  kind: 'NotBetween',
+  data: GetDataEnumKindContent<IntegerOperatorArgs, 'NotBetween'>
+): GetDataEnumKind<IntegerOperatorArgs, 'NotBetween'>;
+export function integerOperator(
+  kind: 'BetweenExclusive',
+  data: GetDataEnumKindContent<IntegerOperatorArgs, 'BetweenExclusive'>
+): GetDataEnumKind<IntegerOperatorArgs, 'BetweenExclusive'>;
+export function integerOperator(
+  kind: 'NotBetweenExclusive',
+  data: GetDataEnumKindContent<IntegerOperatorArgs, 'NotBetweenExclusive'>
+): GetDataEnumKind<IntegerOperatorArgs, 'NotBetweenExclusive'>;
+export function integerOperator<K extends IntegerOperatorArgs['__kind']>(
+  kind: K,
+  data?: any
+): Extract<IntegerOperatorArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isIntegerOperator<K extends IntegerOperator['__kind']>(
+  kind: K,
+  value: IntegerOperator
+): value is IntegerOperator & { __kind: K } {
+  return value.__kind === kind;
 }
@@ -24,9 +24,17 @@ import {
   EquatableOperatorArgs,
   IntegerOperator,
   IntegerOperatorArgs,
+  SetOperator,
+  SetOperatorArgs,
   getEquatableOperatorSerializer,
   getIntegerOperatorSerializer,
+  getSetOperatorSerializer,
 } from '.';
+import {
+  CompactOptionPubkeys,
+  CompactOptionPubkeysArgs,
+  getCompactOptionPubkeysSerializer,
+} from '../../hooked';
 
 export type MintAccountAssertion =
   | {
@@ -41,6 +49,21 @@ export type MintAccountAssertion =
       __kind: 'FreezeAuthority';
       value: Option<PublicKey>;
       operator: EquatableOperator;
+    }
+  | {
+      __kind: 'MintAuthorityIn';
+      value: CompactOptionPubkeys;
+      operator: SetOperator;
+    }
+  | {
+      __kind: 'MintAuthorityAccountRef';
+      accountIndex: number;
+      operator: EquatableOperator;
+    }
+  | {
+      __kind: 'FreezeAuthorityAccountRef';
+      accountIndex: number;
+      operator: EquatableOperator;
     };
 
 export type MintAccountAssertionArgs =
@@ -56,6 +79,21 @@ export type MintAccountAssertionArgs =
       __kind: 'FreezeAuthority';
       value: OptionOrNullable<PublicKey>;
       operator: EquatableOperatorArgs;
+    }
+  | {
+      __kind: 'MintAuthorityIn';
+      value: CompactOptionPubkeysArgs;
+      operator: SetOperatorArgs;
+    }
+  | {
+      __kind: 'MintAuthorityAccountRef';
+      accountIndex: number;
+      operator: EquatableOperatorArgs;
+    }
+  | {
+      __kind: 'FreezeAuthorityAccountRef';
+      accountIndex: number;
+      operator: EquatableOperatorArgs;
     };
 
 export function getMintAccountAssertionSerializer(): Serializer<
@@ -101,6 +139,39 @@ export function getMintAccountAssertionSerializer(): Serializer<
           ]
         ),
       ],
+      [
+        'MintAuthorityIn',
+        struct<GetDataEnumKindContent<MintAccountAssertion, 'MintAuthorityIn'>>(
+          [
+            ['value', getCompactOptionPubkeysSerializer()],
+            ['operator', getSetOperatorSerializer()],
+          ]
+        ),
+      ],
+      [
+        'MintAuthorityAccountRef',
+        struct<
+          GetDataEnumKindContent<
+            MintAccountAssertion,
+            'MintAuthorityAccountRef'
+          >
+        >([
+          ['accountIndex', u8()],
+          ['operator', getEquatableOperatorSerializer()],
+        ]),
+      ],
+      [
+        'FreezeAuthorityAccountRef',
+        struct<
+          GetDataEnumKindContent<
+            MintAccountAssertion,
+            'FreezeAuthorityAccountRef'
+          >
+        >([
+          ['accountIndex', u8()],
+          ['operator', getEquatableOperatorSerializer()],
+        ]),
+      ],
     ],
     { description: 'MintAccountAssertion' }
   ) as Serializer<MintAccountAssertionArgs, MintAccountAssertion>;
@@ -127,6 +198,24 @@ export function mintAccountAssertion(
   kind: 'FreezeAuthority',
   data: GetDataEnumKindContent<MintAccountAssertionArgs, 'FreezeAuthority'>
 ): GetDataEnumKind<MintAccountAssertionArgs, 'FreezeAuthority'>;
+export function mintAccountAssertion(
+  kind: 'MintAuthorityIn',
+  data: GetDataEnumKindContent<MintAccountAssertionArgs, 'MintAuthorityIn'>
+): GetDataEnumKind<MintAccountAssertionArgs, 'MintAuthorityIn'>;
+export function mintAccountAssertion(
+  kind: 'MintAuthorityAccountRef',
+  data: GetDataEnumKindContent<
+    MintAccountAssertionArgs,
+    'MintAuthorityAccountRef'
+  >
+): GetDataEnumKind<MintAccountAssertionArgs, 'MintAuthorityAccountRef'>;
+export function mintAccountAssertion(
+  kind: 'FreezeAuthorityAccountRef',
+  data: GetDataEnumKindContent<
+    MintAccountAssertionArgs,
+    'FreezeAuthorityAccountRef'
+  >
+): GetDataEnumKind<MintAccountAssertionArgs, 'FreezeAuthorityAccountRef'>;
 export function mintAccountAssertion<
   K extends MintAccountAssertionArgs['__kind'],
 >(kind: K, data?: any): Extract<MintAccountAssertionArgs, { __kind: K }> {
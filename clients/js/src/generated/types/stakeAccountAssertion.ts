@@ -22,6 +22,8 @@ import {
   IntegerOperatorArgs,
   MetaAssertion,
   MetaAssertionArgs,
+  StakeActivationAssertion,
+  StakeActivationAssertionArgs,
   StakeAssertion,
   StakeAssertionArgs,
   StakeStateType,
@@ -29,6 +31,7 @@ import {
   getEquatableOperatorSerializer,
   getIntegerOperatorSerializer,
   getMetaAssertionSerializer,
+  getStakeActivationAssertionSerializer,
   getStakeAssertionSerializer,
   getStakeStateTypeSerializer,
 } from '.';
@@ -37,7 +40,8 @@ export type StakeAccountAssertion =
   | { __kind: 'State'; value: StakeStateType; operator: EquatableOperator }
   | { __kind: 'MetaAssertion'; fields: [MetaAssertion] }
   | { __kind: 'StakeAssertion'; fields: [StakeAssertion] }
-  | { __kind: 'StakeFlags'; value: number; operator: IntegerOperator };
+  | { __kind: 'StakeFlags'; value: number; operator: IntegerOperator }
+  | { __kind: 'StakeActivationAssertion'; fields: [StakeActivationAssertion] };
 
 export type StakeAccountAssertionArgs =
   | {
@@ -47,7 +51,11 @@ export type StakeAccountAssertionArgs =
     }
   | { __kind: 'MetaAssertion'; fields: [MetaAssertionArgs] }
   | { __kind: 'StakeAssertion'; fields: [StakeAssertionArgs] }
-  | { __kind: 'StakeFlags'; value: number; operator: IntegerOperatorArgs };
+  | { __kind: 'StakeFlags'; value: number; operator: IntegerOperatorArgs }
+  | {
+      __kind: 'StakeActivationAssertion';
+      fields: [StakeActivationAssertionArgs];
+    };
 
 export function getStakeAccountAssertionSerializer(): Serializer<
   StakeAccountAssertionArgs,
@@ -81,6 +89,15 @@ export function getStakeAccountAssertionSerializer(): Serializer<
           ['operator', getIntegerOperatorSerializer()],
         ]),
       ],
+      [
+        'StakeActivationAssertion',
+        struct<
+          GetDataEnumKindContent<
+            StakeAccountAssertion,
+            'StakeActivationAssertion'
+          >
+        >([['fields', tuple([getStakeActivationAssertionSerializer()])]]),
+      ],
     ],
     { description: 'StakeAccountAssertion' }
   ) as Serializer<StakeAccountAssertionArgs, StakeAccountAssertion>;
@@ -109,6 +126,13 @@ export function stakeAccountAssertion(
   kind: 'StakeFlags',
   data: GetDataEnumKindContent<StakeAccountAssertionArgs, 'StakeFlags'>
 ): GetDataEnumKind<StakeAccountAssertionArgs, 'StakeFlags'>;
+export function stakeAccountAssertion(
+  kind: 'StakeActivationAssertion',
+  data: GetDataEnumKindContent<
+    StakeAccountAssertionArgs,
+    'StakeActivationAssertion'
+  >['fields']
+): GetDataEnumKind<StakeAccountAssertionArgs, 'StakeActivationAssertion'>;
 export function stakeAccountAssertion<
   K extends StakeAccountAssertionArgs['__kind'],
 >(kind: K, data?: any): Extract<StakeAccountAssertionArgs, { __kind: K }> {
@@ -12,6 +12,7 @@ import {
   GetDataEnumKindContent,
   Serializer,
   dataEnum,
+  i8,
   option,
   publicKey as publicKeySerializer,
   struct,
@@ -24,9 +25,17 @@ import {
   EquatableOperatorArgs,
   IntegerOperator,
   IntegerOperatorArgs,
+  SetOperator,
+  SetOperatorArgs,
   getEquatableOperatorSerializer,
   getIntegerOperatorSerializer,
+  getSetOperatorSerializer,
 } from '.';
+import {
+  CompactOptionPubkeys,
+  CompactOptionPubkeysArgs,
+  getCompactOptionPubkeysSerializer,
+} from '../../hooked';
 
 export type TokenAccountAssertion =
   | { __kind: 'Mint'; value: PublicKey; operator: EquatableOperator }
@@ -45,7 +54,34 @@ export type TokenAccountAssertion =
       value: Option<PublicKey>;
       operator: EquatableOperator;
     }
-  | { __kind: 'TokenAccountOwnerIsDerived' };
+  | { __kind: 'TokenAccountOwnerIsDerived' }
+  | { __kind: 'DelegateIn'; value: CompactOptionPubkeys; operator: SetOperator }
+  | {
+      __kind: 'MintAccountRef';
+      accountIndex: number;
+      operator: EquatableOperator;
+    }
+  | {
+      __kind: 'OwnerAccountRef';
+      accountIndex: number;
+      operator: EquatableOperator;
+    }
+  | {
+      __kind: 'DelegateAccountRef';
+      accountIndex: number;
+      operator: EquatableOperator;
+    }
+  | {
+      __kind: 'CloseAuthorityAccountRef';
+      accountIndex: number;
+      operator: EquatableOperator;
+    }
+  | {
+      __kind: 'UiAmount';
+      mantissa: bigint;
+      exponent: number;
+      operator: IntegerOperator;
+    };
 
 export type TokenAccountAssertionArgs =
   | { __kind: 'Mint'; value: PublicKey; operator: EquatableOperatorArgs }
@@ -72,7 +108,38 @@ export type TokenAccountAssertionArgs =
       value: OptionOrNullable<PublicKey>;
       operator: EquatableOperatorArgs;
     }
-  | { __kind: 'TokenAccountOwnerIsDerived' };
+  | { __kind: 'TokenAccountOwnerIsDerived' }
+  | {
+      __kind: 'DelegateIn';
+      value: CompactOptionPubkeysArgs;
+      operator: SetOperatorArgs;
+    }
+  | {
+      __kind: 'MintAccountRef';
+      accountIndex: number;
+      operator: EquatableOperatorArgs;
+    }
+  | {
+      __kind: 'OwnerAccountRef';
+      accountIndex: number;
+      operator: EquatableOperatorArgs;
+    }
+  | {
+      __kind: 'DelegateAccountRef';
+      accountIndex: number;
+      operator: EquatableOperatorArgs;
+    }
+  | {
+      __kind: 'CloseAuthorityAccountRef';
+      accountIndex: number;
+      operator: EquatableOperatorArgs;
+    }
+  | {
+      __kind: 'UiAmount';
+      mantissa: number | bigint;
+      exponent: number;
+      operator: IntegerOperatorArgs;
+    };
 
 export function getTokenAccountAssertionSerializer(): Serializer<
   TokenAccountAssertionArgs,
@@ -141,6 +208,60 @@ export function getTokenAccountAssertionSerializer(): Serializer<
         ),
       ],
       ['TokenAccountOwnerIsDerived', unit()],
+      [
+        'DelegateIn',
+        struct<GetDataEnumKindContent<TokenAccountAssertion, 'DelegateIn'>>([
+          ['value', getCompactOptionPubkeysSerializer()],
+          ['operator', getSetOperatorSerializer()],
+        ]),
+      ],
+      [
+        'MintAccountRef',
+        struct<GetDataEnumKindContent<TokenAccountAssertion, 'MintAccountRef'>>(
+          [
+            ['accountIndex', u8()],
+            ['operator', getEquatableOperatorSerializer()],
+          ]
+        ),
+      ],
+      [
+        'OwnerAccountRef',
+        struct<
+          GetDataEnumKindContent<TokenAccountAssertion, 'OwnerAccountRef'>
+        >([
+          ['accountIndex', u8()],
+          ['operator', getEquatableOperatorSerializer()],
+        ]),
+      ],
+      [
+        'DelegateAccountRef',
+        struct<
+          GetDataEnumKindContent<TokenAccountAssertion, 'DelegateAccountRef'>
+        >([
+          ['accountIndex', u8()],
+          ['operator', getEquatableOperatorSerializer()],
+        ]),
+      ],
+      [
+        'CloseAuthorityAccountRef',
+        struct<
+          GetDataEnumKindContent<
+            TokenAccountAssertion,
+            'CloseAuthorityAccountRef'
+          >
+        >([
+          ['accountIndex', u8()],
+          ['operator', getEquatableOperatorSerializer()],
+        ]),
+      ],
+      [
+        'UiAmount',
+        struct<GetDataEnumKindContent<TokenAccountAssertion, 'UiAmount'>>([
+          ['mantissa', u64()],
+          ['exponent', i8()],
+          ['operator', getIntegerOperatorSerializer()],
+        ]),
+      ],
     ],
     { description: 'TokenAccountAssertion' }
   ) as Serializer<TokenAccountAssertionArgs, TokenAccountAssertion>;
@@ -182,6 +303,33 @@ export function tokenAccountAssertion(
 export function tokenAccountAssertion(
   kind: 'TokenAccountOwnerIsDerived'
 ): GetDataEnumKind<TokenAccountAssertionArgs, 'TokenAccountOwnerIsDerived'>;
+export function tokenAccountAssertion(
+  kind: 'DelegateIn',
+  data: GetDataEnumKindContent<TokenAccountAssertionArgs, 'DelegateIn'>
+): GetDataEnumKind<TokenAccountAssertionArgs, 'DelegateIn'>;
+export function tokenAccountAssertion(
+  kind: 'MintAccountRef',
+  data: GetDataEnumKindContent<TokenAccountAssertionArgs, 'MintAccountRef'>
+): GetDataEnumKind<TokenAccountAssertionArgs, 'MintAccountRef'>;
+export function tokenAccountAssertion(
+  kind: 'OwnerAccountRef',
+  data: GetDataEnumKindContent<TokenAccountAssertionArgs, 'OwnerAccountRef'>
+): GetDataEnumKind<TokenAccountAssertionArgs, 'OwnerAccountRef'>;
+export function tokenAccountAssertion(
+  kind: 'DelegateAccountRef',
+  data: GetDataEnumKindContent<TokenAccountAssertionArgs, 'DelegateAccountRef'>
+): GetDataEnumKind<TokenAccountAssertionArgs, 'DelegateAccountRef'>;
+export function tokenAccountAssertion(
+  kind: 'CloseAuthorityAccountRef',
+  data: GetDataEnumKindContent<
+    TokenAccountAssertionArgs,
+    'CloseAuthorityAccountRef'
+  >
+): GetDataEnumKind<TokenAccountAssertionArgs, 'CloseAuthorityAccountRef'>;
+export function tokenAccountAssertion(
+  kind: 'UiAmount',
+  data: GetDataEnumKindContent<TokenAccountAssertionArgs, 'UiAmount'>
+): GetDataEnumKind<TokenAccountAssertionArgs, 'UiAmount'>;
 export function tokenAccountAssertion<
   K extends TokenAccountAssertionArgs['__kind'],
 >(kind: K, data?: any): Extract<TokenAccountAssertionArgs, { __kind: K }> {
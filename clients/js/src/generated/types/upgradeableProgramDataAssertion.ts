@@ -11,6 +11,7 @@ import {
   GetDataEnumKind,
   GetDataEnumKindContent,
   Serializer,
+  bytes,
   dataEnum,
   option,
   publicKey as publicKeySerializer,
@@ -25,6 +26,11 @@ import {
   getEquatableOperatorSerializer,
   getIntegerOperatorSerializer,
 } from '.';
+import {
+  CompactU64,
+  CompactU64Args,
+  getCompactU64Serializer,
+} from '../../hooked';
 
 export type UpgradeableProgramDataAssertion =
   | {
@@ -32,7 +38,14 @@ export type UpgradeableProgramDataAssertion =
       value: Option<PublicKey>;
       operator: EquatableOperator;
     }
-  | { __kind: 'Slot'; value: bigint; operator: IntegerOperator };
+  | { __kind: 'Slot'; value: bigint; operator: IntegerOperator }
+  | {
+      __kind: 'BytecodeHash';
+      expectedHash: Uint8Array;
+      previousHash: Option<Uint8Array>;
+      offset: CompactU64;
+      length: Option<CompactU64>;
+    };
 
 export type UpgradeableProgramDataAssertionArgs =
   | {
@@ -40,7 +53,14 @@ export type UpgradeableProgramDataAssertionArgs =
       value: OptionOrNullable<PublicKey>;
       operator: EquatableOperatorArgs;
     }
-  | { __kind: 'Slot'; value: number | bigint; operator: IntegerOperatorArgs };
+  | { __kind: 'Slot'; value: number | bigint; operator: IntegerOperatorArgs }
+  | {
+      __kind: 'BytecodeHash';
+      expectedHash: Uint8Array;
+      previousHash: OptionOrNullable<Uint8Array>;
+      offset: CompactU64Args;
+      length: OptionOrNullable<CompactU64Args>;
+    };
 
 export function getUpgradeableProgramDataAssertionSerializer(): Serializer<
   UpgradeableProgramDataAssertionArgs,
@@ -69,6 +89,20 @@ export function getUpgradeableProgramDataAssertionSerializer(): Serializer<
           ]
         ),
       ],
+      [
+        'BytecodeHash',
+        struct<
+          GetDataEnumKindContent<
+            UpgradeableProgramDataAssertion,
+            'BytecodeHash'
+          >
+        >([
+          ['expectedHash', bytes({ size: 32 })],
+          ['previousHash', option(bytes({ size: 32 }))],
+          ['offset', getCompactU64Serializer()],
+          ['length', option(getCompactU64Serializer())],
+        ]),
+      ],
     ],
     { description: 'UpgradeableProgramDataAssertion' }
   ) as Serializer<
@@ -89,6 +123,13 @@ export function upgradeableProgramDataAssertion(
   kind: 'Slot',
   data: GetDataEnumKindContent<UpgradeableProgramDataAssertionArgs, 'Slot'>
 ): GetDataEnumKind<UpgradeableProgramDataAssertionArgs, 'Slot'>;
+export function upgradeableProgramDataAssertion(
+  kind: 'BytecodeHash',
+  data: GetDataEnumKindContent<
+    UpgradeableProgramDataAssertionArgs,
+    'BytecodeHash'
+  >
+): GetDataEnumKind<UpgradeableProgramDataAssertionArgs, 'BytecodeHash'>;
 export function upgradeableProgramDataAssertion<
   K extends UpgradeableProgramDataAssertionArgs['__kind'],
 >(
@@ -6,38 +6,122 @@
  * @see https://github.com/codama-idl/codama
  */
 
+import { PublicKey } from '@metaplex-foundation/umi';
 import {
   GetDataEnumKind,
   GetDataEnumKindContent,
   Serializer,
   dataEnum,
+  publicKey as publicKeySerializer,
   struct,
   tuple,
-  u16,
+  u64,
+  unit,
 } from '@metaplex-foundation/umi/serializers';
 import {
+  AccountHashScope,
+  AccountHashScopeArgs,
   AccountInfoField,
   AccountInfoFieldArgs,
+  ArithmeticMode,
+  ArithmeticModeArgs,
+  ArithmeticSource,
+  ArithmeticSourceArgs,
   ClockField,
   ClockFieldArgs,
   DataValue,
   DataValueArgs,
+  EpochScheduleField,
+  EpochScheduleFieldArgs,
+  HashAlgorithm,
+  HashAlgorithmArgs,
+  IntegerType,
+  IntegerTypeArgs,
+  StakeActivationField,
+  StakeActivationFieldArgs,
+  getAccountHashScopeSerializer,
   getAccountInfoFieldSerializer,
+  getArithmeticModeSerializer,
+  getArithmeticSourceSerializer,
   getClockFieldSerializer,
   getDataValueSerializer,
+  getEpochScheduleFieldSerializer,
+  getHashAlgorithmSerializer,
+  getIntegerTypeSerializer,
+  getStakeActivationFieldSerializer,
 } from '.';
+import {
+  CompactDataRanges,
+  CompactDataRangesArgs,
+  CompactU64,
+  CompactU64Args,
+  getCompactDataRangesSerializer,
+  getCompactU64Serializer,
+} from '../../hooked';
 
 export type WriteType =
-  | { __kind: 'AccountData'; offset: number; dataLength: number }
+  | { __kind: 'AccountData'; offset: CompactU64; dataLength: CompactU64 }
   | { __kind: 'AccountInfoField'; fields: [AccountInfoField] }
   | { __kind: 'DataValue'; fields: [DataValue] }
-  | { __kind: 'Clock'; fields: [ClockField] };
+  | { __kind: 'Clock'; fields: [ClockField] }
+  | { __kind: 'AccountHash'; algorithm: HashAlgorithm; scope: AccountHashScope }
+  | { __kind: 'AccountDataRanges'; ranges: CompactDataRanges }
+  | {
+      __kind: 'Arithmetic';
+      mode: ArithmeticMode;
+      integerType: IntegerType;
+      source: ArithmeticSource;
+    }
+  | { __kind: 'MemoryHeader'; nonce: bigint }
+  | { __kind: 'TokenAccountSnapshot' }
+  | { __kind: 'MintAccountSnapshot' }
+  | {
+      __kind: 'ReturnData';
+      programId: PublicKey;
+      offset: CompactU64;
+      length: CompactU64;
+    }
+  | { __kind: 'RentMinimumBalance'; dataLength: CompactU64 }
+  | { __kind: 'EpochSchedule'; fields: [EpochScheduleField] }
+  | { __kind: 'InstructionIndex' }
+  | { __kind: 'StakeActivation'; fields: [StakeActivationField] }
+  | { __kind: 'Resize'; space: CompactU64 };
 
 export type WriteTypeArgs =
-  | { __kind: 'AccountData'; offset: number; dataLength: number }
+  | {
+      __kind: 'AccountData';
+      offset: CompactU64Args;
+      dataLength: CompactU64Args;
+    }
   | { __kind: 'AccountInfoField'; fields: [AccountInfoFieldArgs] }
   | { __kind: 'DataValue'; fields: [DataValueArgs] }
-  | { __kind: 'Clock'; fields: [ClockFieldArgs] };
+  | { __kind: 'Clock'; fields: [ClockFieldArgs] }
+  | {
+      __kind: 'AccountHash';
+      algorithm: HashAlgorithmArgs;
+      scope: AccountHashScopeArgs;
+    }
+  | { __kind: 'AccountDataRanges'; ranges: CompactDataRangesArgs }
+  | {
+      __kind: 'Arithmetic';
+      mode: ArithmeticModeArgs;
+      integerType: IntegerTypeArgs;
+      source: ArithmeticSourceArgs;
+    }
+  | { __kind: 'MemoryHeader'; nonce: number | bigint }
+  | { __kind: 'TokenAccountSnapshot' }
+  | { __kind: 'MintAccountSnapshot' }
+  | {
+      __kind: 'ReturnData';
+      programId: PublicKey;
+      offset: CompactU64Args;
+      length: CompactU64Args;
+    }
+  | { __kind: 'RentMinimumBalance'; dataLength: CompactU64Args }
+  | { __kind: 'EpochSchedule'; fields: [EpochScheduleFieldArgs] }
+  | { __kind: 'InstructionIndex' }
+  | { __kind: 'StakeActivation'; fields: [StakeActivationFieldArgs] }
+  | { __kind: 'Resize'; space: CompactU64Args };
 
 export function getWriteTypeSerializer(): Serializer<WriteTypeArgs, WriteType> {
   return dataEnum<WriteType>(
@@ -45,8 +129,8 @@ export function getWriteTypeSerializer(): Serializer<WriteTypeArgs, WriteType> {
       [
         'AccountData',
         struct<GetDataEnumKindContent<WriteType, 'AccountData'>>([
-          ['offset', u16()],
-          ['dataLength', u16()],
+          ['offset', getCompactU64Serializer()],
+          ['dataLength', getCompactU64Serializer()],
         ]),
       ],
       [
@@ -67,6 +151,68 @@ export function getWriteTypeSerializer(): Serializer<WriteTypeArgs, WriteType> {
           ['fields', tuple([getClockFieldSerializer()])],
         ]),
       ],
+      [
+        'AccountHash',
+        struct<GetDataEnumKindContent<WriteType, 'AccountHash'>>([
+          ['algorithm', getHashAlgorithmSerializer()],
+          ['scope', getAccountHashScopeSerializer()],
+        ]),
+      ],
+      [
+        'AccountDataRanges',
+        struct<GetDataEnumKindContent<WriteType, 'AccountDataRanges'>>([
+          ['ranges', getCompactDataRangesSerializer()],
+        ]),
+      ],
+      [
+        'Arithmetic',
+        struct<GetDataEnumKindContent<WriteType, 'Arithmetic'>>([
+          ['mode', getArithmeticModeSerializer()],
+          ['integerType', getIntegerTypeSerializer()],
+          ['source', getArithmeticSourceSerializer()],
+        ]),
+      ],
+      [
+        'MemoryHeader',
+        struct<GetDataEnumKindContent<WriteType, 'MemoryHeader'>>([
+          ['nonce', u64()],
+        ]),
+      ],
+      ['TokenAccountSnapshot', unit()],
+      ['MintAccountSnapshot', unit()],
+      [
+        'ReturnData',
+        struct<GetDataEnumKindContent<WriteType, 'ReturnData'>>([
+          ['programId', publicKeySerializer()],
+          ['offset', getCompactU64Serializer()],
+          ['length', getCompactU64Serializer()],
+        ]),
+      ],
+      [
+        'RentMinimumBalance',
+        struct<GetDataEnumKindContent<WriteType, 'RentMinimumBalance'>>([
+          ['dataLength', getCompactU64Serializer()],
+        ]),
+      ],
+      [
+        'EpochSchedule',
+        struct<GetDataEnumKindContent<WriteType, 'EpochSchedule'>>([
+          ['fields', tuple([getEpochScheduleFieldSerializer()])],
+        ]),
+      ],
+      ['InstructionIndex', unit()],
+      [
+        'StakeActivation',
+        struct<GetDataEnumKindContent<WriteType, 'StakeActivation'>>([
+          ['fields', tuple([getStakeActivationFieldSerializer()])],
+        ]),
+      ],
+      [
+        'Resize',
+        struct<GetDataEnumKindContent<WriteType, 'Resize'>>([
+          ['space', getCompactU64Serializer()],
+        ]),
+      ],
     ],
     { description: 'WriteType' }
   ) as Serializer<WriteTypeArgs, WriteType>;
@@ -89,6 +235,51 @@ export function writeType(
   kind: 'Clock',
   data: GetDataEnumKindContent<WriteTypeArgs, 'Clock'>['fields']
 ): GetDataEnumKind<WriteTypeArgs, 'Clock'>;
+export function writeType(
+  kind: 'AccountHash',
+  data: GetDataEnumKindContent<WriteTypeArgs, 'AccountHash'>
+): GetDataEnumKind<WriteTypeArgs, 'AccountHash'>;
+export function writeType(
+  kind: 'AccountDataRanges',
+  data: GetDataEnumKindContent<WriteTypeArgs, 'AccountDataRanges'>
+): GetDataEnumKind<WriteTypeArgs, 'AccountDataRanges'>;
+export function writeType(
+  kind: 'Arithmetic',
+  data: GetDataEnumKindContent<WriteTypeArgs, 'Arithmetic'>
+): GetDataEnumKind<WriteTypeArgs, 'Arithmetic'>;
+export function writeType(
+  kind: 'MemoryHeader',
+  data: GetDataEnumKindContent<WriteTypeArgs, 'MemoryHeader'>
+): GetDataEnumKind<WriteTypeArgs, 'MemoryHeader'>;
+export function writeType(
+  kind: 'TokenAccountSnapshot'
+): GetDataEnumKind<WriteTypeArgs, 'TokenAccountSnapshot'>;
+export function writeType(
+  kind: 'MintAccountSnapshot'
+): GetDataEnumKind<WriteTypeArgs, 'MintAccountSnapshot'>;
+export function writeType(
+  kind: 'ReturnData',
+  data: GetDataEnumKindContent<WriteTypeArgs, 'ReturnData'>
+): GetDataEnumKind<WriteTypeArgs, 'ReturnData'>;
+export function writeType(
+  kind: 'RentMinimumBalance',
+  data: GetDataEnumKindContent<WriteTypeArgs, 'RentMinimumBalance'>
+): GetDataEnumKind<WriteTypeArgs, 'RentMinimumBalance'>;
+export function writeType(
+  kind: 'EpochSchedule',
+  data: GetDataEnumKindContent<WriteTypeArgs, 'EpochSchedule'>['fields']
+): GetDataEnumKind<WriteTypeArgs, 'EpochSchedule'>;
+export function writeType(
+  kind: 'InstructionIndex'
+): GetDataEnumKind<WriteTypeArgs, 'InstructionIndex'>;
+export function writeType(
+  kind: 'StakeActivation',
+  data: GetDataEnumKindContent<WriteTypeArgs, 'StakeActivation'>['fields']
+): GetDataEnumKind<WriteTypeArgs, 'StakeActivation'>;
+export function writeType(
+  kind: 'Resize',
+  data: GetDataEnumKindContent<WriteTypeArgs, 'Resize'>
+): GetDataEnumKind<WriteTypeArgs, 'Resize'>;
 export function writeType<K extends WriteTypeArgs['__kind']>(
   kind: K,
   data?: any
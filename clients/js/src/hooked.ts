@@ -3,13 +3,36 @@ import {
   mapSerializer,
 } from '@metaplex-foundation/umi-serializers-core';
 import { LEB128 } from '@minhducsun2002/leb128';
-import { array, struct, u8 } from '@metaplex-foundation/umi/serializers';
+import { Option, OptionOrNullable, PublicKey } from '@metaplex-foundation/umi';
+import {
+  array,
+  option,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
 import {
   AccountInfoAssertion,
   AccountInfoAssertionArgs,
+  DataRange,
+  DataRangeArgs,
   DataValueAssertion,
+  KnownProgram,
+  KnownProgramArgs,
+  MemoryAccount,
+  MemoryAccountArgs,
+  MemoryDeltaAssertion,
+  MemoryDeltaAssertionArgs,
+  MemoryWriteEntry,
+  MemoryWriteEntryArgs,
   MintAccountAssertion,
   MintAccountAssertionArgs,
+  OffsetPath,
+  OffsetPathArgs,
+  OffsetStep,
+  OffsetStepArgs,
+  PdaSeed,
+  PdaSeedArgs,
   StakeAccountAssertion,
   StakeAccountAssertionArgs,
   TokenAccountAssertion,
@@ -17,8 +40,16 @@ import {
   UpgradeableLoaderStateAssertion,
   UpgradeableLoaderStateAssertionArgs,
   getAccountInfoAssertionSerializer,
+  getDataRangeSerializer,
   getDataValueAssertionSerializer,
+  getKnownProgramSerializer,
+  getMemoryAccountSerializer,
+  getMemoryDeltaAssertionSerializer,
+  getMemoryWriteEntrySerializer,
   getMintAccountAssertionSerializer,
+  getOffsetPathSerializer,
+  getOffsetStepSerializer,
+  getPdaSeedSerializer,
   getStakeAccountAssertionSerializer,
   getTokenAccountAssertionSerializer,
   getUpgradeableLoaderStateAssertionSerializer,
@@ -129,3 +160,123 @@ export function getCompactBytesSerializer() {
     size: getCompactU64Serializer(),
   });
 }
+
+export type AccountDataPathAssertion = {
+  path: OffsetPath;
+  assertion: DataValueAssertion;
+};
+
+export type AccountDataPathAssertionArgs = {
+  path: OffsetPathArgs;
+  assertion: DataValueAssertion;
+};
+
+export function getAccountDataPathAssertionSerializer(): Serializer<
+  AccountDataPathAssertionArgs,
+  AccountDataPathAssertion
+> {
+  return mapSerializer<
+    AccountDataPathAssertionArgs,
+    any,
+    AccountDataPathAssertion
+  >(
+    struct<AccountDataPathAssertion>(
+      [
+        ['path', getOffsetPathSerializer()],
+        ['assertion', getDataValueAssertionSerializer()],
+      ],
+      { description: 'AccountDataPathAssertion' }
+    ),
+    (value) => value
+  ) as Serializer<AccountDataPathAssertionArgs, AccountDataPathAssertion>;
+}
+
+export type AccountDataPathAssertions = Array<AccountDataPathAssertion>;
+export type AccountDataPathAssertionsArgs = Array<AccountDataPathAssertionArgs>;
+
+export function getAccountDataPathAssertionsSerializer() {
+  return array(getAccountDataPathAssertionSerializer(), {
+    size: getCompactU64Serializer(),
+  });
+}
+
+export type OffsetSteps = Array<OffsetStep>;
+export type OffsetStepsArgs = Array<OffsetStepArgs>;
+
+export function getOffsetStepsSerializer() {
+  return array(getOffsetStepSerializer(), {
+    size: getCompactU64Serializer(),
+  });
+}
+
+export type MemoryWriteEntries = Array<MemoryWriteEntry>;
+export type MemoryWriteEntriesArgs = Array<MemoryWriteEntryArgs>;
+
+export function getMemoryWriteEntriesSerializer() {
+  return array(getMemoryWriteEntrySerializer(), {
+    size: getCompactU64Serializer(),
+  });
+}
+
+export type MemoryAccounts = Array<MemoryAccount>;
+export type MemoryAccountsArgs = Array<MemoryAccountArgs>;
+
+export function getMemoryAccountsSerializer() {
+  return array(getMemoryAccountSerializer(), {
+    size: getCompactU64Serializer(),
+  });
+}
+
+export type MemoryDeltaAssertions = Array<MemoryDeltaAssertion>;
+export type MemoryDeltaAssertionsArgs = Array<MemoryDeltaAssertionArgs>;
+
+export function getMemoryDeltaAssertionsSerializer() {
+  return array(getMemoryDeltaAssertionSerializer(), {
+    size: getCompactU64Serializer(),
+  });
+}
+
+export type CompactPubkeys = Array<PublicKey>;
+export type CompactPubkeysArgs = Array<PublicKey>;
+
+export function getCompactPubkeysSerializer() {
+  return array(publicKeySerializer(), {
+    size: getCompactU64Serializer(),
+  });
+}
+
+export type CompactOptionPubkeys = Array<Option<PublicKey>>;
+export type CompactOptionPubkeysArgs = Array<OptionOrNullable<PublicKey>>;
+
+export function getCompactOptionPubkeysSerializer() {
+  return array(option(publicKeySerializer()), {
+    size: getCompactU64Serializer(),
+  });
+}
+
+export type CompactKnownPrograms = Array<KnownProgram>;
+export type CompactKnownProgramsArgs = Array<KnownProgramArgs>;
+
+export function getCompactKnownProgramsSerializer() {
+  return array(getKnownProgramSerializer(), {
+    size: getCompactU64Serializer(),
+  });
+}
+
+export type CompactPdaSeeds = Array<PdaSeed>;
+export type CompactPdaSeedsArgs = Array<PdaSeedArgs>;
+
+export function getCompactPdaSeedsSerializer() {
+  return array(getPdaSeedSerializer(), {
+    size: getCompactU64Serializer(),
+  });
+}
+
+export type CompactDataRanges = Array<DataRange>;
+export type CompactDataRangesArgs = Array<DataRangeArgs>;
+
+export function getCompactDataRangesSerializer() {
+  return array(getDataRangeSerializer(), {
+    size: getCompactU64Serializer(),
+  });
+}
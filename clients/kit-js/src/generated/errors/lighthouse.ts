@@ -48,6 +48,10 @@ export const LIGHTHOUSE_ERROR__ACCOUNT_DISCRIMINATOR_VALIDATION_FAILED = 0x177e;
 export const LIGHTHOUSE_ERROR__ACCOUNT_VALIDATION_FAILED = 0x177f; // 6015
 /** CrossProgramInvokeViolation: CrossProgramInvokeViolation */
 export const LIGHTHOUSE_ERROR__CROSS_PROGRAM_INVOKE_VIOLATION = 0x1780; // 6016
+/** ArithmeticOverflow: ArithmeticOverflow */
+export const LIGHTHOUSE_ERROR__ARITHMETIC_OVERFLOW = 0x1781; // 6017
+/** ReturnDataMismatch: ReturnDataMismatch */
+export const LIGHTHOUSE_ERROR__RETURN_DATA_MISMATCH = 0x1782; // 6018
 
 export type LighthouseError =
   | typeof LIGHTHOUSE_ERROR__ACCOUNT_BORROW_FAILED
@@ -58,6 +62,7 @@ export type LighthouseError =
   | typeof LIGHTHOUSE_ERROR__ACCOUNT_OWNER_MISMATCH
   | typeof LIGHTHOUSE_ERROR__ACCOUNT_OWNER_VALIDATION_FAILED
   | typeof LIGHTHOUSE_ERROR__ACCOUNT_VALIDATION_FAILED
+  | typeof LIGHTHOUSE_ERROR__ARITHMETIC_OVERFLOW
   | typeof LIGHTHOUSE_ERROR__ASSERTION_FAILED
   | typeof LIGHTHOUSE_ERROR__BUMP_NOT_FOUND
   | typeof LIGHTHOUSE_ERROR__CROSS_PROGRAM_INVOKE_VIOLATION
@@ -66,7 +71,8 @@ export type LighthouseError =
   | typeof LIGHTHOUSE_ERROR__INDEX_OUT_OF_BOUNDS
   | typeof LIGHTHOUSE_ERROR__INVALID_INSTRUCTION_DATA
   | typeof LIGHTHOUSE_ERROR__NOT_ENOUGH_ACCOUNTS
-  | typeof LIGHTHOUSE_ERROR__RANGE_OUT_OF_BOUNDS;
+  | typeof LIGHTHOUSE_ERROR__RANGE_OUT_OF_BOUNDS
+  | typeof LIGHTHOUSE_ERROR__RETURN_DATA_MISMATCH;
 
 let lighthouseErrorMessages: Record<LighthouseError, string> | undefined;
 if (process.env.NODE_ENV !== 'production') {
@@ -79,6 +85,7 @@ if (process.env.NODE_ENV !== 'production') {
     [LIGHTHOUSE_ERROR__ACCOUNT_OWNER_MISMATCH]: `AccountOwnerMismatch`,
     [LIGHTHOUSE_ERROR__ACCOUNT_OWNER_VALIDATION_FAILED]: `AccountOwnerValidationFailed`,
     [LIGHTHOUSE_ERROR__ACCOUNT_VALIDATION_FAILED]: `AccountValidaitonFailed`,
+    [LIGHTHOUSE_ERROR__ARITHMETIC_OVERFLOW]: `ArithmeticOverflow`,
     [LIGHTHOUSE_ERROR__ASSERTION_FAILED]: `AssertionFailed`,
     [LIGHTHOUSE_ERROR__BUMP_NOT_FOUND]: `BumpNotFound`,
     [LIGHTHOUSE_ERROR__CROSS_PROGRAM_INVOKE_VIOLATION]: `CrossProgramInvokeViolation`,
@@ -88,6 +95,7 @@ if (process.env.NODE_ENV !== 'production') {
     [LIGHTHOUSE_ERROR__INVALID_INSTRUCTION_DATA]: `Invalid instruction`,
     [LIGHTHOUSE_ERROR__NOT_ENOUGH_ACCOUNTS]: `NotEnoughAccounts`,
     [LIGHTHOUSE_ERROR__RANGE_OUT_OF_BOUNDS]: `RangeOutOfBounds`,
+    [LIGHTHOUSE_ERROR__RETURN_DATA_MISMATCH]: `ReturnDataMismatch`,
   };
 }
 
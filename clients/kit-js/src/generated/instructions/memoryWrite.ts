@@ -149,7 +149,7 @@ export type MemoryWriteInput<
   systemProgram?: Address<TAccountSystemProgram>;
   /** Payer account */
   payer: TransactionSigner<TAccountPayer>;
-  /** Memory account */
+  /** Memory account, grows by at most 10 KiB per instruction */
   memory: Address<TAccountMemory>;
   /** Account to be written to memory */
   sourceAccount: Address<TAccountSourceAccount>;
@@ -249,7 +249,7 @@ export type ParsedMemoryWriteInstruction<
     systemProgram: TAccountMetas[1];
     /** Payer account */
     payer: TAccountMetas[2];
-    /** Memory account */
+    /** Memory account, grows by at most 10 KiB per instruction */
     memory: TAccountMetas[3];
     /** Account to be written to memory */
     sourceAccount: TAccountMetas[4];
@@ -15,22 +15,32 @@ import {
 import {
   type ParsedAssertAccountDataInstruction,
   type ParsedAssertAccountDataMultiInstruction,
+  type ParsedAssertAccountDataPathInstruction,
+  type ParsedAssertAccountDataPathMultiInstruction,
   type ParsedAssertAccountDeltaInstruction,
   type ParsedAssertAccountInfoInstruction,
   type ParsedAssertAccountInfoMultiInstruction,
   type ParsedAssertBubblegumTreeConfigAccountInstruction,
+  type ParsedAssertMemoryDeltaAndCloseInstruction,
   type ParsedAssertMerkleTreeAccountInstruction,
+  type ParsedAssertMintAccountDeltaInstruction,
   type ParsedAssertMintAccountInstruction,
   type ParsedAssertMintAccountMultiInstruction,
+  type ParsedAssertReturnDataInstruction,
   type ParsedAssertStakeAccountInstruction,
   type ParsedAssertStakeAccountMultiInstruction,
   type ParsedAssertSysvarClockInstruction,
+  type ParsedAssertTokenAccountDeltaInstruction,
   type ParsedAssertTokenAccountInstruction,
   type ParsedAssertTokenAccountMultiInstruction,
   type ParsedAssertUpgradeableLoaderAccountInstruction,
   type ParsedAssertUpgradeableLoaderAccountMultiInstruction,
   type ParsedMemoryCloseInstruction,
+  type ParsedMemoryCloseKeyedInstruction,
   type ParsedMemoryWriteInstruction,
+  type ParsedMemoryWriteKeyedInstruction,
+  type ParsedMemoryWriteMultiInstruction,
+  type ParsedMemoryWriteMultiKeyedInstruction,
 } from '../instructions';
 
 export const LIGHTHOUSE_PROGRAM_ADDRESS =
@@ -55,6 +65,16 @@ export enum LighthouseInstruction {
   AssertSysvarClock,
   AssertMerkleTreeAccount,
   AssertBubblegumTreeConfigAccount,
+  AssertAccountDataPath,
+  AssertAccountDataPathMulti,
+  MemoryWriteMulti,
+  AssertMemoryDeltaAndClose,
+  AssertTokenAccountDelta,
+  AssertMintAccountDelta,
+  MemoryWriteKeyed,
+  MemoryWriteMultiKeyed,
+  MemoryCloseKeyed,
+  AssertReturnData,
 }
 
 export function identifyLighthouseInstruction(
@@ -115,6 +135,36 @@ export function identifyLighthouseInstruction(
   if (containsBytes(data, getU8Encoder().encode(17), 0)) {
     return LighthouseInstruction.AssertBubblegumTreeConfigAccount;
   }
+  if (containsBytes(data, getU8Encoder().encode(18), 0)) {
+    return LighthouseInstruction.AssertAccountDataPath;
+  }
+  if (containsBytes(data, getU8Encoder().encode(19), 0)) {
+    return LighthouseInstruction.AssertAccountDataPathMulti;
+  }
+  if (containsBytes(data, getU8Encoder().encode(20), 0)) {
+    return LighthouseInstruction.MemoryWriteMulti;
+  }
+  if (containsBytes(data, getU8Encoder().encode(21), 0)) {
+    return LighthouseInstruction.AssertMemoryDeltaAndClose;
+  }
+  if (containsBytes(data, getU8Encoder().encode(22), 0)) {
+    return LighthouseInstruction.AssertTokenAccountDelta;
+  }
+  if (containsBytes(data, getU8Encoder().encode(23), 0)) {
+    return LighthouseInstruction.AssertMintAccountDelta;
+  }
+  if (containsBytes(data, getU8Encoder().encode(24), 0)) {
+    return LighthouseInstruction.MemoryWriteKeyed;
+  }
+  if (containsBytes(data, getU8Encoder().encode(25), 0)) {
+    return LighthouseInstruction.MemoryWriteMultiKeyed;
+  }
+  if (containsBytes(data, getU8Encoder().encode(26), 0)) {
+    return LighthouseInstruction.MemoryCloseKeyed;
+  }
+  if (containsBytes(data, getU8Encoder().encode(27), 0)) {
+    return LighthouseInstruction.AssertReturnData;
+  }
   throw new Error(
     'The provided instruction could not be identified as a lighthouse instruction.'
   );
@@ -176,4 +226,34 @@ export type ParsedLighthouseInstruction<
     } & ParsedAssertMerkleTreeAccountInstruction<TProgram>)
   | ({
       instructionType: LighthouseInstruction.AssertBubblegumTreeConfigAccount;
-    } & ParsedAssertBubblegumTreeConfigAccountInstruction<TProgram>);
+    } & ParsedAssertBubblegumTreeConfigAccountInstruction<TProgram>)
+  | ({
+      instructionType: LighthouseInstruction.AssertAccountDataPath;
+    } & ParsedAssertAccountDataPathInstruction<TProgram>)
+  | ({
+      instructionType: LighthouseInstruction.AssertAccountDataPathMulti;
+    } & ParsedAssertAccountDataPathMultiInstruction<TProgram>)
+  | ({
+      instructionType: LighthouseInstruction.MemoryWriteMulti;
+    } & ParsedMemoryWriteMultiInstruction<TProgram>)
+  | ({
+      instructionType: LighthouseInstruction.AssertMemoryDeltaAndClose;
+    } & ParsedAssertMemoryDeltaAndCloseInstruction<TProgram>)
+  | ({
+      instructionType: LighthouseInstruction.AssertTokenAccountDelta;
+    } & ParsedAssertTokenAccountDeltaInstruction<TProgram>)
+  | ({
+      instructionType: LighthouseInstruction.AssertMintAccountDelta;
+    } & ParsedAssertMintAccountDeltaInstruction<TProgram>)
+  | ({
+      instructionType: LighthouseInstruction.MemoryWriteKeyed;
+    } & ParsedMemoryWriteKeyedInstruction<TProgram>)
+  | ({
+      instructionType: LighthouseInstruction.MemoryWriteMultiKeyed;
+    } & ParsedMemoryWriteMultiKeyedInstruction<TProgram>)
+  | ({
+      instructionType: LighthouseInstruction.MemoryCloseKeyed;
+    } & ParsedMemoryCloseKeyedInstruction<TProgram>)
+  | ({
+      instructionType: LighthouseInstruction.AssertReturnData;
+    } & ParsedAssertReturnDataInstruction<TProgram>);
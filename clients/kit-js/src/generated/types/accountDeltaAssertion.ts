@@ -23,10 +23,14 @@ import {
   getAccountInfoDeltaAssertionEncoder,
   getDataValueDeltaAssertionDecoder,
   getDataValueDeltaAssertionEncoder,
+  getMemoryGuardDecoder,
+  getMemoryGuardEncoder,
   type AccountInfoDeltaAssertion,
   type AccountInfoDeltaAssertionArgs,
   type DataValueDeltaAssertion,
   type DataValueDeltaAssertionArgs,
+  type MemoryGuard,
+  type MemoryGuardArgs,
 } from '.';
 import {
   getCompactU64Decoder,
@@ -46,6 +50,19 @@ export type AccountDeltaAssertion =
       aOffset: CompactU64;
       bOffset: CompactU64;
       assertion: DataValueDeltaAssertion;
+    }
+  | {
+      __kind: 'GuardedData';
+      guard: MemoryGuard;
+      aOffset: CompactU64;
+      bOffset: CompactU64;
+      assertion: DataValueDeltaAssertion;
+    }
+  | {
+      __kind: 'GuardedAccountInfo';
+      guard: MemoryGuard;
+      aOffset: CompactU64;
+      assertion: AccountInfoDeltaAssertion;
     };
 
 export type AccountDeltaAssertionArgs =
@@ -59,6 +76,19 @@ export type AccountDeltaAssertionArgs =
       aOffset: CompactU64Args;
       bOffset: CompactU64Args;
       assertion: DataValueDeltaAssertionArgs;
+    }
+  | {
+      __kind: 'GuardedData';
+      guard: MemoryGuardArgs;
+      aOffset: CompactU64Args;
+      bOffset: CompactU64Args;
+      assertion: DataValueDeltaAssertionArgs;
+    }
+  | {
+      __kind: 'GuardedAccountInfo';
+      guard: MemoryGuardArgs;
+      aOffset: CompactU64Args;
+      assertion: AccountInfoDeltaAssertionArgs;
     };
 
 export function getAccountDeltaAssertionEncoder(): Encoder<AccountDeltaAssertionArgs> {
@@ -78,6 +108,23 @@ export function getAccountDeltaAssertionEncoder(): Encoder<AccountDeltaAssertion
         ['assertion', getDataValueDeltaAssertionEncoder()],
       ]),
     ],
+    [
+      'GuardedData',
+      getStructEncoder([
+        ['guard', getMemoryGuardEncoder()],
+        ['aOffset', getCompactU64Encoder()],
+        ['bOffset', getCompactU64Encoder()],
+        ['assertion', getDataValueDeltaAssertionEncoder()],
+      ]),
+    ],
+    [
+      'GuardedAccountInfo',
+      getStructEncoder([
+        ['guard', getMemoryGuardEncoder()],
+        ['aOffset', getCompactU64Encoder()],
+        ['assertion', getAccountInfoDeltaAssertionEncoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -98,6 +145,23 @@ export function getAccountDeltaAssertionDecoder(): Decoder<AccountDeltaAssertion
         ['assertion', getDataValueDeltaAssertionDecoder()],
       ]),
     ],
+    [
+      'GuardedData',
+      getStructDecoder([
+        ['guard', getMemoryGuardDecoder()],
+        ['aOffset', getCompactU64Decoder()],
+        ['bOffset', getCompactU64Decoder()],
+        ['assertion', getDataValueDeltaAssertionDecoder()],
+      ]),
+    ],
+    [
+      'GuardedAccountInfo',
+      getStructDecoder([
+        ['guard', getMemoryGuardDecoder()],
+        ['aOffset', getCompactU64Decoder()],
+        ['assertion', getAccountInfoDeltaAssertionDecoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -132,6 +196,30 @@ export function accountDeltaAssertion(
     'Data'
   >
 ): GetDiscriminatedUnionVariant<AccountDeltaAssertionArgs, '__kind', 'Data'>;
+export function accountDeltaAssertion(
+  kind: 'GuardedData',
+  data: GetDiscriminatedUnionVariantContent<
+    AccountDeltaAssertionArgs,
+    '__kind',
+    'GuardedData'
+  >
+): GetDiscriminatedUnionVariant<
+  AccountDeltaAssertionArgs,
+  '__kind',
+  'GuardedData'
+>;
+export function accountDeltaAssertion(
+  kind: 'GuardedAccountInfo',
+  data: GetDiscriminatedUnionVariantContent<
+    AccountDeltaAssertionArgs,
+    '__kind',
+    'GuardedAccountInfo'
+  >
+): GetDiscriminatedUnionVariant<
+  AccountDeltaAssertionArgs,
+  '__kind',
+  'GuardedAccountInfo'
+>;
 export function accountDeltaAssertion<
   K extends AccountDeltaAssertionArgs['__kind'],
   Data,
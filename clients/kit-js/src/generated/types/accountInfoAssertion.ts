@@ -18,16 +18,24 @@ import {
   getBytesEncoder,
   getDiscriminatedUnionDecoder,
   getDiscriminatedUnionEncoder,
+  getI128Decoder,
+  getI128Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Address,
   type Codec,
   type Decoder,
   type Encoder,
   type GetDiscriminatedUnionVariant,
   type GetDiscriminatedUnionVariantContent,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
@@ -37,16 +45,32 @@ import {
   getIntegerOperatorEncoder,
   getKnownProgramDecoder,
   getKnownProgramEncoder,
+  getSetOperatorDecoder,
+  getSetOperatorEncoder,
   type EquatableOperator,
   type EquatableOperatorArgs,
   type IntegerOperator,
   type IntegerOperatorArgs,
   type KnownProgram,
   type KnownProgramArgs,
+  type SetOperator,
+  type SetOperatorArgs,
 } from '.';
 import {
+  getCompactKnownProgramsDecoder,
+  getCompactKnownProgramsEncoder,
+  getCompactPdaSeedsDecoder,
+  getCompactPdaSeedsEncoder,
+  getCompactPubkeysDecoder,
+  getCompactPubkeysEncoder,
   getCompactU64Decoder,
   getCompactU64Encoder,
+  type CompactKnownPrograms,
+  type CompactKnownProgramsArgs,
+  type CompactPdaSeeds,
+  type CompactPdaSeedsArgs,
+  type CompactPubkeys,
+  type CompactPubkeysArgs,
   type CompactU64,
   type CompactU64Args,
 } from '../../hooked';
@@ -65,6 +89,32 @@ export type AccountInfoAssertion =
       expectedHash: ReadonlyUint8Array;
       start: CompactU64;
       length: CompactU64;
+    }
+  | { __kind: 'OwnerIn'; value: CompactPubkeys; operator: SetOperator }
+  | {
+      __kind: 'KnownOwnerIn';
+      value: CompactKnownPrograms;
+      operator: SetOperator;
+    }
+  | {
+      __kind: 'OwnerAccountRef';
+      accountIndex: number;
+      operator: EquatableOperator;
+    }
+  | { __kind: 'IsRentExempt'; value: boolean; operator: EquatableOperator }
+  | { __kind: 'Exists'; value: boolean; operator: EquatableOperator }
+  | { __kind: 'IsWallet'; value: boolean; operator: EquatableOperator }
+  | {
+      __kind: 'LamportsAboveRentMinimum';
+      value: bigint;
+      operator: IntegerOperator;
+    }
+  | {
+      __kind: 'KeyIsDerived';
+      seeds: CompactPdaSeeds;
+      programId: Address;
+      bump: Option<number>;
+      operator: EquatableOperator;
     };
 
 export type AccountInfoAssertionArgs =
@@ -97,6 +147,32 @@ export type AccountInfoAssertionArgs =
       expectedHash: ReadonlyUint8Array;
       start: CompactU64Args;
       length: CompactU64Args;
+    }
+  | { __kind: 'OwnerIn'; value: CompactPubkeysArgs; operator: SetOperatorArgs }
+  | {
+      __kind: 'KnownOwnerIn';
+      value: CompactKnownProgramsArgs;
+      operator: SetOperatorArgs;
+    }
+  | {
+      __kind: 'OwnerAccountRef';
+      accountIndex: number;
+      operator: EquatableOperatorArgs;
+    }
+  | { __kind: 'IsRentExempt'; value: boolean; operator: EquatableOperatorArgs }
+  | { __kind: 'Exists'; value: boolean; operator: EquatableOperatorArgs }
+  | { __kind: 'IsWallet'; value: boolean; operator: EquatableOperatorArgs }
+  | {
+      __kind: 'LamportsAboveRentMinimum';
+      value: number | bigint;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'KeyIsDerived';
+      seeds: CompactPdaSeedsArgs;
+      programId: Address;
+      bump: OptionOrNullable<number>;
+      operator: EquatableOperatorArgs;
     };
 
 export function getAccountInfoAssertionEncoder(): Encoder<AccountInfoAssertionArgs> {
@@ -165,6 +241,64 @@ export function getAccountInfoAssertionEncoder(): Encoder<AccountInfoAssertionAr
         ['length', getCompactU64Encoder()],
       ]),
     ],
+    [
+      'OwnerIn',
+      getStructEncoder([
+        ['value', getCompactPubkeysEncoder()],
+        ['operator', getSetOperatorEncoder()],
+      ]),
+    ],
+    [
+      'KnownOwnerIn',
+      getStructEncoder([
+        ['value', getCompactKnownProgramsEncoder()],
+        ['operator', getSetOperatorEncoder()],
+      ]),
+    ],
+    [
+      'OwnerAccountRef',
+      getStructEncoder([
+        ['accountIndex', getU8Encoder()],
+        ['operator', getEquatableOperatorEncoder()],
+      ]),
+    ],
+    [
+      'IsRentExempt',
+      getStructEncoder([
+        ['value', getBooleanEncoder()],
+        ['operator', getEquatableOperatorEncoder()],
+      ]),
+    ],
+    [
+      'Exists',
+      getStructEncoder([
+        ['value', getBooleanEncoder()],
+        ['operator', getEquatableOperatorEncoder()],
+      ]),
+    ],
+    [
+      'IsWallet',
+      getStructEncoder([
+        ['value', getBooleanEncoder()],
+        ['operator', getEquatableOperatorEncoder()],
+      ]),
+    ],
+    [
+      'LamportsAboveRentMinimum',
+      getStructEncoder([
+        ['value', getI128Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'KeyIsDerived',
+      getStructEncoder([
+        ['seeds', getCompactPdaSeedsEncoder()],
+        ['programId', getAddressEncoder()],
+        ['bump', getOptionEncoder(getU8Encoder())],
+        ['operator', getEquatableOperatorEncoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -234,6 +368,64 @@ export function getAccountInfoAssertionDecoder(): Decoder<AccountInfoAssertion>
         ['length', getCompactU64Decoder()],
       ]),
     ],
+    [
+      'OwnerIn',
+      getStructDecoder([
+        ['value', getCompactPubkeysDecoder()],
+        ['operator', getSetOperatorDecoder()],
+      ]),
+    ],
+    [
+      'KnownOwnerIn',
+      getStructDecoder([
+        ['value', getCompactKnownProgramsDecoder()],
+        ['operator', getSetOperatorDecoder()],
+      ]),
+    ],
+    [
+      'OwnerAccountRef',
+      getStructDecoder([
+        ['accountIndex', getU8Decoder()],
+        ['operator', getEquatableOperatorDecoder()],
+      ]),
+    ],
+    [
+      'IsRentExempt',
+      getStructDecoder([
+        ['value', getBooleanDecoder()],
+        ['operator', getEquatableOperatorDecoder()],
+      ]),
+    ],
+    [
+      'Exists',
+      getStructDecoder([
+        ['value', getBooleanDecoder()],
+        ['operator', getEquatableOperatorDecoder()],
+      ]),
+    ],
+    [
+      'IsWallet',
+      getStructDecoder([
+        ['value', getBooleanDecoder()],
+        ['operator', getEquatableOperatorDecoder()],
+      ]),
+    ],
+    [
+      'LamportsAboveRentMinimum',
+      getStructDecoder([
+        ['value', getI128Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'KeyIsDerived',
+      getStructDecoder([
+        ['seeds', getCompactPdaSeedsDecoder()],
+        ['programId', getAddressDecoder()],
+        ['bump', getOptionDecoder(getU8Decoder())],
+        ['operator', getEquatableOperatorDecoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -344,6 +536,90 @@ export function accountInfoAssertion(
   '__kind',
   'VerifyDatahash'
 >;
+export function accountInfoAssertion(
+  kind: 'OwnerIn',
+  data: GetDiscriminatedUnionVariantContent<
+    AccountInfoAssertionArgs,
+    '__kind',
+    'OwnerIn'
+  >
+): GetDiscriminatedUnionVariant<AccountInfoAssertionArgs, '__kind', 'OwnerIn'>;
+export function accountInfoAssertion(
+  kind: 'KnownOwnerIn',
+  data: GetDiscriminatedUnionVariantContent<
+    AccountInfoAssertionArgs,
+    '__kind',
+    'KnownOwnerIn'
+  >
+): GetDiscriminatedUnionVariant<
+  AccountInfoAssertionArgs,
+  '__kind',
+  'KnownOwnerIn'
+>;
+export function accountInfoAssertion(
+  kind: 'OwnerAccountRef',
+  data: GetDiscriminatedUnionVariantContent<
+    AccountInfoAssertionArgs,
+    '__kind',
+    'OwnerAccountRef'
+  >
+): GetDiscriminatedUnionVariant<
+  AccountInfoAssertionArgs,
+  '__kind',
+  'OwnerAccountRef'
+>;
+export function accountInfoAssertion(
+  kind: 'IsRentExempt',
+  data: GetDiscriminatedUnionVariantContent<
+    AccountInfoAssertionArgs,
+    '__kind',
+    'IsRentExempt'
+  >
+): GetDiscriminatedUnionVariant<
+  AccountInfoAssertionArgs,
+  '__kind',
+  'IsRentExempt'
+>;
+export function accountInfoAssertion(
+  kind: 'Exists',
+  data: GetDiscriminatedUnionVariantContent<
+    AccountInfoAssertionArgs,
+    '__kind',
+    'Exists'
+  >
+): GetDiscriminatedUnionVariant<AccountInfoAssertionArgs, '__kind', 'Exists'>;
+export function accountInfoAssertion(
+  kind: 'IsWallet',
+  data: GetDiscriminatedUnionVariantContent<
+    AccountInfoAssertionArgs,
+    '__kind',
+    'IsWallet'
+  >
+): GetDiscriminatedUnionVariant<AccountInfoAssertionArgs, '__kind', 'IsWallet'>;
+export function accountInfoAssertion(
+  kind: 'LamportsAboveRentMinimum',
+  data: GetDiscriminatedUnionVariantContent<
+    AccountInfoAssertionArgs,
+    '__kind',
+    'LamportsAboveRentMinimum'
+  >
+): GetDiscriminatedUnionVariant<
+  AccountInfoAssertionArgs,
+  '__kind',
+  'LamportsAboveRentMinimum'
+>;
+export function accountInfoAssertion(
+  kind: 'KeyIsDerived',
+  data: GetDiscriminatedUnionVariantContent<
+    AccountInfoAssertionArgs,
+    '__kind',
+    'KeyIsDerived'
+  >
+): GetDiscriminatedUnionVariant<
+  AccountInfoAssertionArgs,
+  '__kind',
+  'KeyIsDerived'
+>;
 export function accountInfoAssertion<
   K extends AccountInfoAssertionArgs['__kind'],
   Data,
@@ -8,10 +8,14 @@
 
 import {
   combineCodec,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getDiscriminatedUnionDecoder,
   getDiscriminatedUnionEncoder,
   getI128Decoder,
   getI128Encoder,
+  getI32Decoder,
+  getI32Encoder,
   getStructDecoder,
   getStructEncoder,
   type Codec,
@@ -21,12 +25,20 @@ import {
   type GetDiscriminatedUnionVariantContent,
 } from '@solana/kit';
 import {
+  getAccountHashScopeDecoder,
+  getAccountHashScopeEncoder,
   getEquatableOperatorDecoder,
   getEquatableOperatorEncoder,
+  getHashAlgorithmDecoder,
+  getHashAlgorithmEncoder,
   getIntegerOperatorDecoder,
   getIntegerOperatorEncoder,
+  type AccountHashScope,
+  type AccountHashScopeArgs,
   type EquatableOperator,
   type EquatableOperatorArgs,
+  type HashAlgorithm,
+  type HashAlgorithmArgs,
   type IntegerOperator,
   type IntegerOperatorArgs,
 } from '.';
@@ -35,7 +47,21 @@ export type AccountInfoDeltaAssertion =
   | { __kind: 'Lamports'; value: bigint; operator: IntegerOperator }
   | { __kind: 'DataLength'; value: bigint; operator: IntegerOperator }
   | { __kind: 'Owner'; operator: EquatableOperator }
-  | { __kind: 'RentEpoch'; value: bigint; operator: IntegerOperator };
+  | { __kind: 'RentEpoch'; value: bigint; operator: IntegerOperator }
+  | {
+      __kind: 'LamportsBps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperator;
+    }
+  | { __kind: 'Executable'; operator: EquatableOperator }
+  | { __kind: 'Key'; operator: EquatableOperator }
+  | {
+      __kind: 'Hash';
+      algorithm: HashAlgorithm;
+      scope: AccountHashScope;
+      operator: EquatableOperator;
+    };
 
 export type AccountInfoDeltaAssertionArgs =
   | {
@@ -53,6 +79,20 @@ export type AccountInfoDeltaAssertionArgs =
       __kind: 'RentEpoch';
       value: number | bigint;
       operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'LamportsBps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperatorArgs;
+    }
+  | { __kind: 'Executable'; operator: EquatableOperatorArgs }
+  | { __kind: 'Key'; operator: EquatableOperatorArgs }
+  | {
+      __kind: 'Hash';
+      algorithm: HashAlgorithmArgs;
+      scope: AccountHashScopeArgs;
+      operator: EquatableOperatorArgs;
     };
 
 export function getAccountInfoDeltaAssertionEncoder(): Encoder<AccountInfoDeltaAssertionArgs> {
@@ -79,6 +119,27 @@ export function getAccountInfoDeltaAssertionEncoder(): Encoder<AccountInfoDeltaA
         ['operator', getIntegerOperatorEncoder()],
       ]),
     ],
+    [
+      'LamportsBps',
+      getStructEncoder([
+        ['value', getI32Encoder()],
+        ['absolute', getBooleanEncoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'Executable',
+      getStructEncoder([['operator', getEquatableOperatorEncoder()]]),
+    ],
+    ['Key', getStructEncoder([['operator', getEquatableOperatorEncoder()]])],
+    [
+      'Hash',
+      getStructEncoder([
+        ['algorithm', getHashAlgorithmEncoder()],
+        ['scope', getAccountHashScopeEncoder()],
+        ['operator', getEquatableOperatorEncoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -106,6 +167,27 @@ export function getAccountInfoDeltaAssertionDecoder(): Decoder<AccountInfoDeltaA
         ['operator', getIntegerOperatorDecoder()],
       ]),
     ],
+    [
+      'LamportsBps',
+      getStructDecoder([
+        ['value', getI32Decoder()],
+        ['absolute', getBooleanDecoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'Executable',
+      getStructDecoder([['operator', getEquatableOperatorDecoder()]]),
+    ],
+    ['Key', getStructDecoder([['operator', getEquatableOperatorDecoder()]])],
+    [
+      'Hash',
+      getStructDecoder([
+        ['algorithm', getHashAlgorithmDecoder()],
+        ['scope', getAccountHashScopeDecoder()],
+        ['operator', getEquatableOperatorDecoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -168,6 +250,50 @@ export function accountInfoDeltaAssertion(
   '__kind',
   'RentEpoch'
 >;
+export function accountInfoDeltaAssertion(
+  kind: 'LamportsBps',
+  data: GetDiscriminatedUnionVariantContent<
+    AccountInfoDeltaAssertionArgs,
+    '__kind',
+    'LamportsBps'
+  >
+): GetDiscriminatedUnionVariant<
+  AccountInfoDeltaAssertionArgs,
+  '__kind',
+  'LamportsBps'
+>;
+export function accountInfoDeltaAssertion(
+  kind: 'Executable',
+  data: GetDiscriminatedUnionVariantContent<
+    AccountInfoDeltaAssertionArgs,
+    '__kind',
+    'Executable'
+  >
+): GetDiscriminatedUnionVariant<
+  AccountInfoDeltaAssertionArgs,
+  '__kind',
+  'Executable'
+>;
+export function accountInfoDeltaAssertion(
+  kind: 'Key',
+  data: GetDiscriminatedUnionVariantContent<
+    AccountInfoDeltaAssertionArgs,
+    '__kind',
+    'Key'
+  >
+): GetDiscriminatedUnionVariant<AccountInfoDeltaAssertionArgs, '__kind', 'Key'>;
+export function accountInfoDeltaAssertion(
+  kind: 'Hash',
+  data: GetDiscriminatedUnionVariantContent<
+    AccountInfoDeltaAssertionArgs,
+    '__kind',
+    'Hash'
+  >
+): GetDiscriminatedUnionVariant<
+  AccountInfoDeltaAssertionArgs,
+  '__kind',
+  'Hash'
+>;
 export function accountInfoDeltaAssertion<
   K extends AccountInfoDeltaAssertionArgs['__kind'],
   Data,
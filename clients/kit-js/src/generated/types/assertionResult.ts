@@ -12,6 +12,8 @@ import {
   combineCodec,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBooleanDecoder,
   getBooleanEncoder,
   getBytesDecoder,
@@ -112,6 +114,62 @@ export type AssertionResult =
   | {
       __kind: 'Bool';
       fields: readonly [Option<boolean>, Option<boolean>, number, boolean];
+    }
+  | {
+      __kind: 'Range';
+      fields: readonly [Option<bigint>, bigint, bigint, number, boolean];
+    }
+  | {
+      __kind: 'PubkeySet';
+      fields: readonly [
+        Option<Address>,
+        Array<Option<Address>>,
+        number,
+        boolean,
+      ];
+    }
+  | {
+      __kind: 'BytesPattern';
+      fields: readonly [
+        Option<number>,
+        number,
+        ReadonlyUint8Array,
+        number,
+        boolean,
+      ];
+    }
+  | {
+      __kind: 'I80F48';
+      fields: readonly [Option<bigint>, Option<bigint>, number, boolean];
+    }
+  | {
+      __kind: 'U64F64';
+      fields: readonly [Option<bigint>, Option<bigint>, number, boolean];
+    }
+  | {
+      __kind: 'Wad';
+      fields: readonly [Option<bigint>, Option<bigint>, number, boolean];
+    }
+  | {
+      __kind: 'Ray';
+      fields: readonly [Option<bigint>, Option<bigint>, number, boolean];
+    }
+  | {
+      __kind: 'F32';
+      fields: readonly [Option<number>, Option<number>, number, boolean];
+    }
+  | {
+      __kind: 'F64';
+      fields: readonly [Option<bigint>, Option<bigint>, number, boolean];
+    }
+  | {
+      __kind: 'String';
+      fields: readonly [
+        ReadonlyUint8Array,
+        ReadonlyUint8Array,
+        number,
+        boolean,
+      ];
     };
 
 export type AssertionResultArgs =
@@ -231,6 +289,98 @@ export type AssertionResultArgs =
         number,
         boolean,
       ];
+    }
+  | {
+      __kind: 'Range';
+      fields: readonly [
+        OptionOrNullable<number | bigint>,
+        number | bigint,
+        number | bigint,
+        number,
+        boolean,
+      ];
+    }
+  | {
+      __kind: 'PubkeySet';
+      fields: readonly [
+        OptionOrNullable<Address>,
+        Array<OptionOrNullable<Address>>,
+        number,
+        boolean,
+      ];
+    }
+  | {
+      __kind: 'BytesPattern';
+      fields: readonly [
+        OptionOrNullable<number>,
+        number,
+        ReadonlyUint8Array,
+        number,
+        boolean,
+      ];
+    }
+  | {
+      __kind: 'I80F48';
+      fields: readonly [
+        OptionOrNullable<number | bigint>,
+        OptionOrNullable<number | bigint>,
+        number,
+        boolean,
+      ];
+    }
+  | {
+      __kind: 'U64F64';
+      fields: readonly [
+        OptionOrNullable<number | bigint>,
+        OptionOrNullable<number | bigint>,
+        number,
+        boolean,
+      ];
+    }
+  | {
+      __kind: 'Wad';
+      fields: readonly [
+        OptionOrNullable<number | bigint>,
+        OptionOrNullable<number | bigint>,
+        number,
+        boolean,
+      ];
+    }
+  | {
+      __kind: 'Ray';
+      fields: readonly [
+        OptionOrNullable<number | bigint>,
+        OptionOrNullable<number | bigint>,
+        number,
+        boolean,
+      ];
+    }
+  | {
+      __kind: 'F32';
+      fields: readonly [
+        OptionOrNullable<number>,
+        OptionOrNullable<number>,
+        number,
+        boolean,
+      ];
+    }
+  | {
+      __kind: 'F64';
+      fields: readonly [
+        OptionOrNullable<number | bigint>,
+        OptionOrNullable<number | bigint>,
+        number,
+        boolean,
+      ];
+    }
+  | {
+      __kind: 'String';
+      fields: readonly [
+        ReadonlyUint8Array,
+        ReadonlyUint8Array,
+        number,
+        boolean,
+      ];
     };
 
 export function getAssertionResultEncoder(): Encoder<AssertionResultArgs> {
@@ -417,6 +567,148 @@ export function getAssertionResultEncoder(): Encoder<AssertionResultArgs> {
         ],
       ]),
     ],
+    [
+      'Range',
+      getStructEncoder([
+        [
+          'fields',
+          getTupleEncoder([
+            getOptionEncoder(getI128Encoder()),
+            getI128Encoder(),
+            getI128Encoder(),
+            getU8Encoder(),
+            getBooleanEncoder(),
+          ]),
+        ],
+      ]),
+    ],
+    [
+      'PubkeySet',
+      getStructEncoder([
+        [
+          'fields',
+          getTupleEncoder([
+            getOptionEncoder(getAddressEncoder()),
+            getArrayEncoder(getOptionEncoder(getAddressEncoder())),
+            getU8Encoder(),
+            getBooleanEncoder(),
+          ]),
+        ],
+      ]),
+    ],
+    [
+      'BytesPattern',
+      getStructEncoder([
+        [
+          'fields',
+          getTupleEncoder([
+            getOptionEncoder(getU32Encoder()),
+            getU32Encoder(),
+            addEncoderSizePrefix(getBytesEncoder(), getU32Encoder()),
+            getU8Encoder(),
+            getBooleanEncoder(),
+          ]),
+        ],
+      ]),
+    ],
+    [
+      'I80F48',
+      getStructEncoder([
+        [
+          'fields',
+          getTupleEncoder([
+            getOptionEncoder(getI128Encoder()),
+            getOptionEncoder(getI128Encoder()),
+            getU8Encoder(),
+            getBooleanEncoder(),
+          ]),
+        ],
+      ]),
+    ],
+    [
+      'U64F64',
+      getStructEncoder([
+        [
+          'fields',
+          getTupleEncoder([
+            getOptionEncoder(getU128Encoder()),
+            getOptionEncoder(getU128Encoder()),
+            getU8Encoder(),
+            getBooleanEncoder(),
+          ]),
+        ],
+      ]),
+    ],
+    [
+      'Wad',
+      getStructEncoder([
+        [
+          'fields',
+          getTupleEncoder([
+            getOptionEncoder(getU128Encoder()),
+            getOptionEncoder(getU128Encoder()),
+            getU8Encoder(),
+            getBooleanEncoder(),
+          ]),
+        ],
+      ]),
+    ],
+    [
+      'Ray',
+      getStructEncoder([
+        [
+          'fields',
+          getTupleEncoder([
+            getOptionEncoder(getU128Encoder()),
+            getOptionEncoder(getU128Encoder()),
+            getU8Encoder(),
+            getBooleanEncoder(),
+          ]),
+        ],
+      ]),
+    ],
+    [
+      'F32',
+      getStructEncoder([
+        [
+          'fields',
+          getTupleEncoder([
+            getOptionEncoder(getU32Encoder()),
+            getOptionEncoder(getU32Encoder()),
+            getU8Encoder(),
+            getBooleanEncoder(),
+          ]),
+        ],
+      ]),
+    ],
+    [
+      'F64',
+      getStructEncoder([
+        [
+          'fields',
+          getTupleEncoder([
+            getOptionEncoder(getU64Encoder()),
+            getOptionEncoder(getU64Encoder()),
+            getU8Encoder(),
+            getBooleanEncoder(),
+          ]),
+        ],
+      ]),
+    ],
+    [
+      'String',
+      getStructEncoder([
+        [
+          'fields',
+          getTupleEncoder([
+            addEncoderSizePrefix(getBytesEncoder(), getU32Encoder()),
+            addEncoderSizePrefix(getBytesEncoder(), getU32Encoder()),
+            getU8Encoder(),
+            getBooleanEncoder(),
+          ]),
+        ],
+      ]),
+    ],
   ]);
 }
 
@@ -604,6 +896,148 @@ export function getAssertionResultDecoder(): Decoder<AssertionResult> {
         ],
       ]),
     ],
+    [
+      'Range',
+      getStructDecoder([
+        [
+          'fields',
+          getTupleDecoder([
+            getOptionDecoder(getI128Decoder()),
+            getI128Decoder(),
+            getI128Decoder(),
+            getU8Decoder(),
+            getBooleanDecoder(),
+          ]),
+        ],
+      ]),
+    ],
+    [
+      'PubkeySet',
+      getStructDecoder([
+        [
+          'fields',
+          getTupleDecoder([
+            getOptionDecoder(getAddressDecoder()),
+            getArrayDecoder(getOptionDecoder(getAddressDecoder())),
+            getU8Decoder(),
+            getBooleanDecoder(),
+          ]),
+        ],
+      ]),
+    ],
+    [
+      'BytesPattern',
+      getStructDecoder([
+        [
+          'fields',
+          getTupleDecoder([
+            getOptionDecoder(getU32Decoder()),
+            getU32Decoder(),
+            addDecoderSizePrefix(getBytesDecoder(), getU32Decoder()),
+            getU8Decoder(),
+            getBooleanDecoder(),
+          ]),
+        ],
+      ]),
+    ],
+    [
+      'I80F48',
+      getStructDecoder([
+        [
+          'fields',
+          getTupleDecoder([
+            getOptionDecoder(getI128Decoder()),
+            getOptionDecoder(getI128Decoder()),
+            getU8Decoder(),
+            getBooleanDecoder(),
+          ]),
+        ],
+      ]),
+    ],
+    [
+      'U64F64',
+      getStructDecoder([
+        [
+          'fields',
+          getTupleDecoder([
+            getOptionDecoder(getU128Decoder()),
+            getOptionDecoder(getU128Decoder()),
+            getU8Decoder(),
+            getBooleanDecoder(),
+          ]),
+        ],
+      ]),
+    ],
+    [
+      'Wad',
+      getStructDecoder([
+        [
+          'fields',
+          getTupleDecoder([
+            getOptionDecoder(getU128Decoder()),
+            getOptionDecoder(getU128Decoder()),
+            getU8Decoder(),
+            getBooleanDecoder(),
+          ]),
+        ],
+      ]),
+    ],
+    [
+      'Ray',
+      getStructDecoder([
+        [
+          'fields',
+          getTupleDecoder([
+            getOptionDecoder(getU128Decoder()),
+            getOptionDecoder(getU128Decoder()),
+            getU8Decoder(),
+            getBooleanDecoder(),
+          ]),
+        ],
+      ]),
+    ],
+    [
+      'F32',
+      getStructDecoder([
+        [
+          'fields',
+          getTupleDecoder([
+            getOptionDecoder(getU32Decoder()),
+            getOptionDecoder(getU32Decoder()),
+            getU8Decoder(),
+            getBooleanDecoder(),
+          ]),
+        ],
+      ]),
+    ],
+    [
+      'F64',
+      getStructDecoder([
+        [
+          'fields',
+          getTupleDecoder([
+            getOptionDecoder(getU64Decoder()),
+            getOptionDecoder(getU64Decoder()),
+            getU8Decoder(),
+            getBooleanDecoder(),
+          ]),
+        ],
+      ]),
+    ],
+    [
+      'String',
+      getStructDecoder([
+        [
+          'fields',
+          getTupleDecoder([
+            addDecoderSizePrefix(getBytesDecoder(), getU32Decoder()),
+            addDecoderSizePrefix(getBytesDecoder(), getU32Decoder()),
+            getU8Decoder(),
+            getBooleanDecoder(),
+          ]),
+        ],
+      ]),
+    ],
   ]);
 }
 
@@ -719,6 +1153,86 @@ export function assertionResult(
     'Bool'
   >['fields']
 ): GetDiscriminatedUnionVariant<AssertionResultArgs, '__kind', 'Bool'>;
+export function assertionResult(
+  kind: 'Range',
+  data: GetDiscriminatedUnionVariantContent<
+    AssertionResultArgs,
+    '__kind',
+    'Range'
+  >['fields']
+): GetDiscriminatedUnionVariant<AssertionResultArgs, '__kind', 'Range'>;
+export function assertionResult(
+  kind: 'PubkeySet',
+  data: GetDiscriminatedUnionVariantContent<
+    AssertionResultArgs,
+    '__kind',
+    'PubkeySet'
+  >['fields']
+): GetDiscriminatedUnionVariant<AssertionResultArgs, '__kind', 'PubkeySet'>;
+export function assertionResult(
+  kind: 'BytesPattern',
+  data: GetDiscriminatedUnionVariantContent<
+    AssertionResultArgs,
+    '__kind',
+    'BytesPattern'
+  >['fields']
+): GetDiscriminatedUnionVariant<AssertionResultArgs, '__kind', 'BytesPattern'>;
+export function assertionResult(
+  kind: 'I80F48',
+  data: GetDiscriminatedUnionVariantContent<
+    AssertionResultArgs,
+    '__kind',
+    'I80F48'
+  >['fields']
+): GetDiscriminatedUnionVariant<AssertionResultArgs, '__kind', 'I80F48'>;
+export function assertionResult(
+  kind: 'U64F64',
+  data: GetDiscriminatedUnionVariantContent<
+    AssertionResultArgs,
+    '__kind',
+    'U64F64'
+  >['fields']
+): GetDiscriminatedUnionVariant<AssertionResultArgs, '__kind', 'U64F64'>;
+export function assertionResult(
+  kind: 'Wad',
+  data: GetDiscriminatedUnionVariantContent<
+    AssertionResultArgs,
+    '__kind',
+    'Wad'
+  >['fields']
+): GetDiscriminatedUnionVariant<AssertionResultArgs, '__kind', 'Wad'>;
+export function assertionResult(
+  kind: 'Ray',
+  data: GetDiscriminatedUnionVariantContent<
+    AssertionResultArgs,
+    '__kind',
+    'Ray'
+  >['fields']
+): GetDiscriminatedUnionVariant<AssertionResultArgs, '__kind', 'Ray'>;
+export function assertionResult(
+  kind: 'F32',
+  data: GetDiscriminatedUnionVariantContent<
+    AssertionResultArgs,
+    '__kind',
+    'F32'
+  >['fields']
+): GetDiscriminatedUnionVariant<AssertionResultArgs, '__kind', 'F32'>;
+export function assertionResult(
+  kind: 'F64',
+  data: GetDiscriminatedUnionVariantContent<
+    AssertionResultArgs,
+    '__kind',
+    'F64'
+  >['fields']
+): GetDiscriminatedUnionVariant<AssertionResultArgs, '__kind', 'F64'>;
+export function assertionResult(
+  kind: 'String',
+  data: GetDiscriminatedUnionVariantContent<
+    AssertionResultArgs,
+    '__kind',
+    'String'
+  >['fields']
+): GetDiscriminatedUnionVariant<AssertionResultArgs, '__kind', 'String'>;
 export function assertionResult<K extends AssertionResultArgs['__kind'], Data>(
   kind: K,
   data?: Data
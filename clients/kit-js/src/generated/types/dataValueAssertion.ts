@@ -14,6 +14,10 @@ import {
   getBooleanEncoder,
   getDiscriminatedUnionDecoder,
   getDiscriminatedUnionEncoder,
+  getF32Decoder,
+  getF32Encoder,
+  getF64Decoder,
+  getF64Encoder,
   getI128Decoder,
   getI128Encoder,
   getI16Decoder,
@@ -24,6 +28,8 @@ import {
   getI64Encoder,
   getI8Decoder,
   getI8Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU128Decoder,
@@ -42,22 +48,52 @@ import {
   type Encoder,
   type GetDiscriminatedUnionVariant,
   type GetDiscriminatedUnionVariantContent,
+  type Option,
+  type OptionOrNullable,
 } from '@solana/kit';
 import {
+  getBytesOperatorDecoder,
+  getBytesOperatorEncoder,
   getEquatableOperatorDecoder,
   getEquatableOperatorEncoder,
   getIntegerOperatorDecoder,
   getIntegerOperatorEncoder,
+  getRangeOperatorDecoder,
+  getRangeOperatorEncoder,
+  getSetOperatorDecoder,
+  getSetOperatorEncoder,
+  getStringLayoutDecoder,
+  getStringLayoutEncoder,
+  getStringOperatorDecoder,
+  getStringOperatorEncoder,
+  type BytesOperator,
+  type BytesOperatorArgs,
   type EquatableOperator,
   type EquatableOperatorArgs,
   type IntegerOperator,
   type IntegerOperatorArgs,
+  type RangeOperator,
+  type RangeOperatorArgs,
+  type SetOperator,
+  type SetOperatorArgs,
+  type StringLayout,
+  type StringLayoutArgs,
+  type StringOperator,
+  type StringOperatorArgs,
 } from '.';
 import {
   getCompactBytesDecoder,
   getCompactBytesEncoder,
+  getCompactPubkeysDecoder,
+  getCompactPubkeysEncoder,
+  getCompactU64Decoder,
+  getCompactU64Encoder,
   type CompactBytes,
   type CompactBytesArgs,
+  type CompactPubkeys,
+  type CompactPubkeysArgs,
+  type CompactU64,
+  type CompactU64Args,
 } from '../../hooked';
 
 export type DataValueAssertion =
@@ -73,7 +109,76 @@ export type DataValueAssertion =
   | { __kind: 'U128'; value: bigint; operator: IntegerOperator }
   | { __kind: 'I128'; value: bigint; operator: IntegerOperator }
   | { __kind: 'Bytes'; value: CompactBytes; operator: EquatableOperator }
-  | { __kind: 'Pubkey'; value: Address; operator: EquatableOperator };
+  | { __kind: 'Pubkey'; value: Address; operator: EquatableOperator }
+  | { __kind: 'PubkeyIn'; value: CompactPubkeys; operator: SetOperator }
+  | {
+      __kind: 'BytesMasked';
+      value: CompactBytes;
+      mask: CompactBytes;
+      operator: EquatableOperator;
+    }
+  | {
+      __kind: 'BytesPattern';
+      value: CompactBytes;
+      length: Option<CompactU64>;
+      operator: BytesOperator;
+    }
+  | { __kind: 'I80F48'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'U64F64'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'Wad'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'Ray'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'F32'; value: number; operator: IntegerOperator }
+  | { __kind: 'F64'; value: number; operator: IntegerOperator }
+  | { __kind: 'U16BE'; value: number; operator: IntegerOperator }
+  | { __kind: 'I16BE'; value: number; operator: IntegerOperator }
+  | { __kind: 'U32BE'; value: number; operator: IntegerOperator }
+  | { __kind: 'I32BE'; value: number; operator: IntegerOperator }
+  | { __kind: 'U64BE'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'I64BE'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'U128BE'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'I128BE'; value: bigint; operator: IntegerOperator }
+  | {
+      __kind: 'UnsignedInt';
+      value: bigint;
+      byteWidth: number;
+      bigEndian: boolean;
+      operator: IntegerOperator;
+    }
+  | {
+      __kind: 'BitField';
+      value: bigint;
+      byteWidth: number;
+      bigEndian: boolean;
+      shift: number;
+      bitWidth: number;
+      operator: IntegerOperator;
+    }
+  | {
+      __kind: 'String';
+      value: CompactBytes;
+      layout: StringLayout;
+      operator: StringOperator;
+    }
+  | {
+      __kind: 'StringLength';
+      value: number;
+      layout: StringLayout;
+      operator: IntegerOperator;
+    }
+  | {
+      __kind: 'PubkeyAccountRef';
+      accountIndex: number;
+      operator: EquatableOperator;
+    }
+  | {
+      __kind: 'IntegerRange';
+      lower: bigint;
+      upper: bigint;
+      byteWidth: number;
+      signed: boolean;
+      bigEndian: boolean;
+      operator: RangeOperator;
+    };
 
 export type DataValueAssertionArgs =
   | { __kind: 'Bool'; value: boolean; operator: EquatableOperatorArgs }
@@ -92,7 +197,76 @@ export type DataValueAssertionArgs =
       value: CompactBytesArgs;
       operator: EquatableOperatorArgs;
     }
-  | { __kind: 'Pubkey'; value: Address; operator: EquatableOperatorArgs };
+  | { __kind: 'Pubkey'; value: Address; operator: EquatableOperatorArgs }
+  | { __kind: 'PubkeyIn'; value: CompactPubkeysArgs; operator: SetOperatorArgs }
+  | {
+      __kind: 'BytesMasked';
+      value: CompactBytesArgs;
+      mask: CompactBytesArgs;
+      operator: EquatableOperatorArgs;
+    }
+  | {
+      __kind: 'BytesPattern';
+      value: CompactBytesArgs;
+      length: OptionOrNullable<CompactU64Args>;
+      operator: BytesOperatorArgs;
+    }
+  | { __kind: 'I80F48'; value: number | bigint; operator: IntegerOperatorArgs }
+  | { __kind: 'U64F64'; value: number | bigint; operator: IntegerOperatorArgs }
+  | { __kind: 'Wad'; value: number | bigint; operator: IntegerOperatorArgs }
+  | { __kind: 'Ray'; value: number | bigint; operator: IntegerOperatorArgs }
+  | { __kind: 'F32'; value: number; operator: IntegerOperatorArgs }
+  | { __kind: 'F64'; value: number; operator: IntegerOperatorArgs }
+  | { __kind: 'U16BE'; value: number; operator: IntegerOperatorArgs }
+  | { __kind: 'I16BE'; value: number; operator: IntegerOperatorArgs }
+  | { __kind: 'U32BE'; value: number; operator: IntegerOperatorArgs }
+  | { __kind: 'I32BE'; value: number; operator: IntegerOperatorArgs }
+  | { __kind: 'U64BE'; value: number | bigint; operator: IntegerOperatorArgs }
+  | { __kind: 'I64BE'; value: number | bigint; operator: IntegerOperatorArgs }
+  | { __kind: 'U128BE'; value: number | bigint; operator: IntegerOperatorArgs }
+  | { __kind: 'I128BE'; value: number | bigint; operator: IntegerOperatorArgs }
+  | {
+      __kind: 'UnsignedInt';
+      value: number | bigint;
+      byteWidth: number;
+      bigEndian: boolean;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'BitField';
+      value: number | bigint;
+      byteWidth: number;
+      bigEndian: boolean;
+      shift: number;
+      bitWidth: number;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'String';
+      value: CompactBytesArgs;
+      layout: StringLayoutArgs;
+      operator: StringOperatorArgs;
+    }
+  | {
+      __kind: 'StringLength';
+      value: number;
+      layout: StringLayoutArgs;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'PubkeyAccountRef';
+      accountIndex: number;
+      operator: EquatableOperatorArgs;
+    }
+  | {
+      __kind: 'IntegerRange';
+      lower: number | bigint;
+      upper: number | bigint;
+      byteWidth: number;
+      signed: boolean;
+      bigEndian: boolean;
+      operator: RangeOperatorArgs;
+    };
 
 export function getDataValueAssertionEncoder(): Encoder<DataValueAssertionArgs> {
   return getDiscriminatedUnionEncoder([
@@ -187,6 +361,181 @@ export function getDataValueAssertionEncoder(): Encoder<DataValueAssertionArgs>
         ['operator', getEquatableOperatorEncoder()],
       ]),
     ],
+    [
+      'PubkeyIn',
+      getStructEncoder([
+        ['value', getCompactPubkeysEncoder()],
+        ['operator', getSetOperatorEncoder()],
+      ]),
+    ],
+    [
+      'BytesMasked',
+      getStructEncoder([
+        ['value', getCompactBytesEncoder()],
+        ['mask', getCompactBytesEncoder()],
+        ['operator', getEquatableOperatorEncoder()],
+      ]),
+    ],
+    [
+      'BytesPattern',
+      getStructEncoder([
+        ['value', getCompactBytesEncoder()],
+        ['length', getOptionEncoder(getCompactU64Encoder())],
+        ['operator', getBytesOperatorEncoder()],
+      ]),
+    ],
+    [
+      'I80F48',
+      getStructEncoder([
+        ['value', getI128Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'U64F64',
+      getStructEncoder([
+        ['value', getU128Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'Wad',
+      getStructEncoder([
+        ['value', getU128Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'Ray',
+      getStructEncoder([
+        ['value', getU128Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'F32',
+      getStructEncoder([
+        ['value', getF32Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'F64',
+      getStructEncoder([
+        ['value', getF64Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'U16BE',
+      getStructEncoder([
+        ['value', getU16Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'I16BE',
+      getStructEncoder([
+        ['value', getI16Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'U32BE',
+      getStructEncoder([
+        ['value', getU32Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'I32BE',
+      getStructEncoder([
+        ['value', getI32Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'U64BE',
+      getStructEncoder([
+        ['value', getU64Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'I64BE',
+      getStructEncoder([
+        ['value', getI64Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'U128BE',
+      getStructEncoder([
+        ['value', getU128Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'I128BE',
+      getStructEncoder([
+        ['value', getI128Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'UnsignedInt',
+      getStructEncoder([
+        ['value', getU128Encoder()],
+        ['byteWidth', getU8Encoder()],
+        ['bigEndian', getBooleanEncoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'BitField',
+      getStructEncoder([
+        ['value', getU128Encoder()],
+        ['byteWidth', getU8Encoder()],
+        ['bigEndian', getBooleanEncoder()],
+        ['shift', getU8Encoder()],
+        ['bitWidth', getU8Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'String',
+      getStructEncoder([
+        ['value', getCompactBytesEncoder()],
+        ['layout', getStringLayoutEncoder()],
+        ['operator', getStringOperatorEncoder()],
+      ]),
+    ],
+    [
+      'StringLength',
+      getStructEncoder([
+        ['value', getU32Encoder()],
+        ['layout', getStringLayoutEncoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'PubkeyAccountRef',
+      getStructEncoder([
+        ['accountIndex', getU8Encoder()],
+        ['operator', getEquatableOperatorEncoder()],
+      ]),
+    ],
+    [
+      'IntegerRange',
+      getStructEncoder([
+        ['lower', getI128Encoder()],
+        ['upper', getI128Encoder()],
+        ['byteWidth', getU8Encoder()],
+        ['signed', getBooleanEncoder()],
+        ['bigEndian', getBooleanEncoder()],
+        ['operator', getRangeOperatorEncoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -283,6 +632,181 @@ export function getDataValueAssertionDecoder(): Decoder<DataValueAssertion> {
         ['operator', getEquatableOperatorDecoder()],
       ]),
     ],
+    [
+      'PubkeyIn',
+      getStructDecoder([
+        ['value', getCompactPubkeysDecoder()],
+        ['operator', getSetOperatorDecoder()],
+      ]),
+    ],
+    [
+      'BytesMasked',
+      getStructDecoder([
+        ['value', getCompactBytesDecoder()],
+        ['mask', getCompactBytesDecoder()],
+        ['operator', getEquatableOperatorDecoder()],
+      ]),
+    ],
+    [
+      'BytesPattern',
+      getStructDecoder([
+        ['value', getCompactBytesDecoder()],
+        ['length', getOptionDecoder(getCompactU64Decoder())],
+        ['operator', getBytesOperatorDecoder()],
+      ]),
+    ],
+    [
+      'I80F48',
+      getStructDecoder([
+        ['value', getI128Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'U64F64',
+      getStructDecoder([
+        ['value', getU128Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'Wad',
+      getStructDecoder([
+        ['value', getU128Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'Ray',
+      getStructDecoder([
+        ['value', getU128Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'F32',
+      getStructDecoder([
+        ['value', getF32Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'F64',
+      getStructDecoder([
+        ['value', getF64Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'U16BE',
+      getStructDecoder([
+        ['value', getU16Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'I16BE',
+      getStructDecoder([
+        ['value', getI16Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'U32BE',
+      getStructDecoder([
+        ['value', getU32Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'I32BE',
+      getStructDecoder([
+        ['value', getI32Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'U64BE',
+      getStructDecoder([
+        ['value', getU64Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'I64BE',
+      getStructDecoder([
+        ['value', getI64Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'U128BE',
+      getStructDecoder([
+        ['value', getU128Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'I128BE',
+      getStructDecoder([
+        ['value', getI128Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'UnsignedInt',
+      getStructDecoder([
+        ['value', getU128Decoder()],
+        ['byteWidth', getU8Decoder()],
+        ['bigEndian', getBooleanDecoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'BitField',
+      getStructDecoder([
+        ['value', getU128Decoder()],
+        ['byteWidth', getU8Decoder()],
+        ['bigEndian', getBooleanDecoder()],
+        ['shift', getU8Decoder()],
+        ['bitWidth', getU8Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'String',
+      getStructDecoder([
+        ['value', getCompactBytesDecoder()],
+        ['layout', getStringLayoutDecoder()],
+        ['operator', getStringOperatorDecoder()],
+      ]),
+    ],
+    [
+      'StringLength',
+      getStructDecoder([
+        ['value', getU32Decoder()],
+        ['layout', getStringLayoutDecoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'PubkeyAccountRef',
+      getStructDecoder([
+        ['accountIndex', getU8Decoder()],
+        ['operator', getEquatableOperatorDecoder()],
+      ]),
+    ],
+    [
+      'IntegerRange',
+      getStructDecoder([
+        ['lower', getI128Decoder()],
+        ['upper', getI128Decoder()],
+        ['byteWidth', getU8Decoder()],
+        ['signed', getBooleanDecoder()],
+        ['bigEndian', getBooleanDecoder()],
+        ['operator', getRangeOperatorDecoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -401,6 +925,214 @@ export function dataValueAssertion(
     'Pubkey'
   >
 ): GetDiscriminatedUnionVariant<DataValueAssertionArgs, '__kind', 'Pubkey'>;
+export function dataValueAssertion(
+  kind: 'PubkeyIn',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueAssertionArgs,
+    '__kind',
+    'PubkeyIn'
+  >
+): GetDiscriminatedUnionVariant<DataValueAssertionArgs, '__kind', 'PubkeyIn'>;
+export function dataValueAssertion(
+  kind: 'BytesMasked',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueAssertionArgs,
+    '__kind',
+    'BytesMasked'
+  >
+): GetDiscriminatedUnionVariant<
+  DataValueAssertionArgs,
+  '__kind',
+  'BytesMasked'
+>;
+export function dataValueAssertion(
+  kind: 'BytesPattern',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueAssertionArgs,
+    '__kind',
+    'BytesPattern'
+  >
+): GetDiscriminatedUnionVariant<
+  DataValueAssertionArgs,
+  '__kind',
+  'BytesPattern'
+>;
+export function dataValueAssertion(
+  kind: 'I80F48',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueAssertionArgs,
+    '__kind',
+    'I80F48'
+  >
+): GetDiscriminatedUnionVariant<DataValueAssertionArgs, '__kind', 'I80F48'>;
+export function dataValueAssertion(
+  kind: 'U64F64',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueAssertionArgs,
+    '__kind',
+    'U64F64'
+  >
+): GetDiscriminatedUnionVariant<DataValueAssertionArgs, '__kind', 'U64F64'>;
+export function dataValueAssertion(
+  kind: 'Wad',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueAssertionArgs,
+    '__kind',
+    'Wad'
+  >
+): GetDiscriminatedUnionVariant<DataValueAssertionArgs, '__kind', 'Wad'>;
+export function dataValueAssertion(
+  kind: 'Ray',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueAssertionArgs,
+    '__kind',
+    'Ray'
+  >
+): GetDiscriminatedUnionVariant<DataValueAssertionArgs, '__kind', 'Ray'>;
+export function dataValueAssertion(
+  kind: 'F32',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueAssertionArgs,
+    '__kind',
+    'F32'
+  >
+): GetDiscriminatedUnionVariant<DataValueAssertionArgs, '__kind', 'F32'>;
+export function dataValueAssertion(
+  kind: 'F64',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueAssertionArgs,
+    '__kind',
+    'F64'
+  >
+): GetDiscriminatedUnionVariant<DataValueAssertionArgs, '__kind', 'F64'>;
+export function dataValueAssertion(
+  kind: 'U16BE',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueAssertionArgs,
+    '__kind',
+    'U16BE'
+  >
+): GetDiscriminatedUnionVariant<DataValueAssertionArgs, '__kind', 'U16BE'>;
+export function dataValueAssertion(
+  kind: 'I16BE',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueAssertionArgs,
+    '__kind',
+    'I16BE'
+  >
+): GetDiscriminatedUnionVariant<DataValueAssertionArgs, '__kind', 'I16BE'>;
+export function dataValueAssertion(
+  kind: 'U32BE',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueAssertionArgs,
+    '__kind',
+    'U32BE'
+  >
+): GetDiscriminatedUnionVariant<DataValueAssertionArgs, '__kind', 'U32BE'>;
+export function dataValueAssertion(
+  kind: 'I32BE',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueAssertionArgs,
+    '__kind',
+    'I32BE'
+  >
+): GetDiscriminatedUnionVariant<DataValueAssertionArgs, '__kind', 'I32BE'>;
+export function dataValueAssertion(
+  kind: 'U64BE',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueAssertionArgs,
+    '__kind',
+    'U64BE'
+  >
+): GetDiscriminatedUnionVariant<DataValueAssertionArgs, '__kind', 'U64BE'>;
+export function dataValueAssertion(
+  kind: 'I64BE',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueAssertionArgs,
+    '__kind',
+    'I64BE'
+  >
+): GetDiscriminatedUnionVariant<DataValueAssertionArgs, '__kind', 'I64BE'>;
+export function dataValueAssertion(
+  kind: 'U128BE',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueAssertionArgs,
+    '__kind',
+    'U128BE'
+  >
+): GetDiscriminatedUnionVariant<DataValueAssertionArgs, '__kind', 'U128BE'>;
+export function dataValueAssertion(
+  kind: 'I128BE',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueAssertionArgs,
+    '__kind',
+    'I128BE'
+  >
+): GetDiscriminatedUnionVariant<DataValueAssertionArgs, '__kind', 'I128BE'>;
+export function dataValueAssertion(
+  kind: 'UnsignedInt',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueAssertionArgs,
+    '__kind',
+    'UnsignedInt'
+  >
+): GetDiscriminatedUnionVariant<
+  DataValueAssertionArgs,
+  '__kind',
+  'UnsignedInt'
+>;
+export function dataValueAssertion(
+  kind: 'BitField',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueAssertionArgs,
+    '__kind',
+    'BitField'
+  >
+): GetDiscriminatedUnionVariant<DataValueAssertionArgs, '__kind', 'BitField'>;
+export function dataValueAssertion(
+  kind: 'String',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueAssertionArgs,
+    '__kind',
+    'String'
+  >
+): GetDiscriminatedUnionVariant<DataValueAssertionArgs, '__kind', 'String'>;
+export function dataValueAssertion(
+  kind: 'StringLength',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueAssertionArgs,
+    '__kind',
+    'StringLength'
+  >
+): GetDiscriminatedUnionVariant<
+  DataValueAssertionArgs,
+  '__kind',
+  'StringLength'
+>;
+export function dataValueAssertion(
+  kind: 'PubkeyAccountRef',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueAssertionArgs,
+    '__kind',
+    'PubkeyAccountRef'
+  >
+): GetDiscriminatedUnionVariant<
+  DataValueAssertionArgs,
+  '__kind',
+  'PubkeyAccountRef'
+>;
+export function dataValueAssertion(
+  kind: 'IntegerRange',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueAssertionArgs,
+    '__kind',
+    'IntegerRange'
+  >
+): GetDiscriminatedUnionVariant<
+  DataValueAssertionArgs,
+  '__kind',
+  'IntegerRange'
+>;
 export function dataValueAssertion<
   K extends DataValueAssertionArgs['__kind'],
   Data,
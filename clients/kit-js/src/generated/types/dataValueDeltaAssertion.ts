@@ -8,6 +8,8 @@
 
 import {
   combineCodec,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getDiscriminatedUnionDecoder,
   getDiscriminatedUnionEncoder,
   getI128Decoder,
@@ -48,7 +50,58 @@ export type DataValueDeltaAssertion =
   | { __kind: 'I32'; value: bigint; operator: IntegerOperator }
   | { __kind: 'U64'; value: bigint; operator: IntegerOperator }
   | { __kind: 'I64'; value: bigint; operator: IntegerOperator }
-  | { __kind: 'Bytes'; length: number; operator: EquatableOperator };
+  | { __kind: 'Bytes'; length: number; operator: EquatableOperator }
+  | {
+      __kind: 'U8Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperator;
+    }
+  | {
+      __kind: 'I8Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperator;
+    }
+  | {
+      __kind: 'U16Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperator;
+    }
+  | {
+      __kind: 'I16Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperator;
+    }
+  | {
+      __kind: 'U32Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperator;
+    }
+  | {
+      __kind: 'I32Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperator;
+    }
+  | {
+      __kind: 'U64Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperator;
+    }
+  | {
+      __kind: 'I64Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperator;
+    }
+  | { __kind: 'U128'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'I128'; value: bigint; operator: IntegerOperator }
+  | { __kind: 'Pubkey'; operator: EquatableOperator };
 
 export type DataValueDeltaAssertionArgs =
   | { __kind: 'U8'; value: number; operator: IntegerOperatorArgs }
@@ -59,7 +112,58 @@ export type DataValueDeltaAssertionArgs =
   | { __kind: 'I32'; value: number | bigint; operator: IntegerOperatorArgs }
   | { __kind: 'U64'; value: number | bigint; operator: IntegerOperatorArgs }
   | { __kind: 'I64'; value: number | bigint; operator: IntegerOperatorArgs }
-  | { __kind: 'Bytes'; length: number; operator: EquatableOperatorArgs };
+  | { __kind: 'Bytes'; length: number; operator: EquatableOperatorArgs }
+  | {
+      __kind: 'U8Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'I8Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'U16Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'I16Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'U32Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'I32Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'U64Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperatorArgs;
+    }
+  | {
+      __kind: 'I64Bps';
+      value: number;
+      absolute: boolean;
+      operator: IntegerOperatorArgs;
+    }
+  | { __kind: 'U128'; value: number | bigint; operator: IntegerOperatorArgs }
+  | { __kind: 'I128'; value: number | bigint; operator: IntegerOperatorArgs }
+  | { __kind: 'Pubkey'; operator: EquatableOperatorArgs };
 
 export function getDataValueDeltaAssertionEncoder(): Encoder<DataValueDeltaAssertionArgs> {
   return getDiscriminatedUnionEncoder([
@@ -126,6 +230,85 @@ export function getDataValueDeltaAssertionEncoder(): Encoder<DataValueDeltaAsser
         ['operator', getEquatableOperatorEncoder()],
       ]),
     ],
+    [
+      'U8Bps',
+      getStructEncoder([
+        ['value', getI32Encoder()],
+        ['absolute', getBooleanEncoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'I8Bps',
+      getStructEncoder([
+        ['value', getI32Encoder()],
+        ['absolute', getBooleanEncoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'U16Bps',
+      getStructEncoder([
+        ['value', getI32Encoder()],
+        ['absolute', getBooleanEncoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'I16Bps',
+      getStructEncoder([
+        ['value', getI32Encoder()],
+        ['absolute', getBooleanEncoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'U32Bps',
+      getStructEncoder([
+        ['value', getI32Encoder()],
+        ['absolute', getBooleanEncoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'I32Bps',
+      getStructEncoder([
+        ['value', getI32Encoder()],
+        ['absolute', getBooleanEncoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'U64Bps',
+      getStructEncoder([
+        ['value', getI32Encoder()],
+        ['absolute', getBooleanEncoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'I64Bps',
+      getStructEncoder([
+        ['value', getI32Encoder()],
+        ['absolute', getBooleanEncoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'U128',
+      getStructEncoder([
+        ['value', getI128Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    [
+      'I128',
+      getStructEncoder([
+        ['value', getI128Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
+    ['Pubkey', getStructEncoder([['operator', getEquatableOperatorEncoder()]])],
   ]);
 }
 
@@ -194,6 +377,85 @@ export function getDataValueDeltaAssertionDecoder(): Decoder<DataValueDeltaAsser
         ['operator', getEquatableOperatorDecoder()],
       ]),
     ],
+    [
+      'U8Bps',
+      getStructDecoder([
+        ['value', getI32Decoder()],
+        ['absolute', getBooleanDecoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'I8Bps',
+      getStructDecoder([
+        ['value', getI32Decoder()],
+        ['absolute', getBooleanDecoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'U16Bps',
+      getStructDecoder([
+        ['value', getI32Decoder()],
+        ['absolute', getBooleanDecoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'I16Bps',
+      getStructDecoder([
+        ['value', getI32Decoder()],
+        ['absolute', getBooleanDecoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'U32Bps',
+      getStructDecoder([
+        ['value', getI32Decoder()],
+        ['absolute', getBooleanDecoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'I32Bps',
+      getStructDecoder([
+        ['value', getI32Decoder()],
+        ['absolute', getBooleanDecoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'U64Bps',
+      getStructDecoder([
+        ['value', getI32Decoder()],
+        ['absolute', getBooleanDecoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'I64Bps',
+      getStructDecoder([
+        ['value', getI32Decoder()],
+        ['absolute', getBooleanDecoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'U128',
+      getStructDecoder([
+        ['value', getI128Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    [
+      'I128',
+      getStructDecoder([
+        ['value', getI128Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
+    ['Pubkey', getStructDecoder([['operator', getEquatableOperatorDecoder()]])],
   ]);
 }
 
@@ -280,6 +542,122 @@ export function dataValueDeltaAssertion(
     'Bytes'
   >
 ): GetDiscriminatedUnionVariant<DataValueDeltaAssertionArgs, '__kind', 'Bytes'>;
+export function dataValueDeltaAssertion(
+  kind: 'U8Bps',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueDeltaAssertionArgs,
+    '__kind',
+    'U8Bps'
+  >
+): GetDiscriminatedUnionVariant<DataValueDeltaAssertionArgs, '__kind', 'U8Bps'>;
+export function dataValueDeltaAssertion(
+  kind: 'I8Bps',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueDeltaAssertionArgs,
+    '__kind',
+    'I8Bps'
+  >
+): GetDiscriminatedUnionVariant<DataValueDeltaAssertionArgs, '__kind', 'I8Bps'>;
+export function dataValueDeltaAssertion(
+  kind: 'U16Bps',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueDeltaAssertionArgs,
+    '__kind',
+    'U16Bps'
+  >
+): GetDiscriminatedUnionVariant<
+  DataValueDeltaAssertionArgs,
+  '__kind',
+  'U16Bps'
+>;
+export function dataValueDeltaAssertion(
+  kind: 'I16Bps',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueDeltaAssertionArgs,
+    '__kind',
+    'I16Bps'
+  >
+): GetDiscriminatedUnionVariant<
+  DataValueDeltaAssertionArgs,
+  '__kind',
+  'I16Bps'
+>;
+export function dataValueDeltaAssertion(
+  kind: 'U32Bps',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueDeltaAssertionArgs,
+    '__kind',
+    'U32Bps'
+  >
+): GetDiscriminatedUnionVariant<
+  DataValueDeltaAssertionArgs,
+  '__kind',
+  'U32Bps'
+>;
+export function dataValueDeltaAssertion(
+  kind: 'I32Bps',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueDeltaAssertionArgs,
+    '__kind',
+    'I32Bps'
+  >
+): GetDiscriminatedUnionVariant<
+  DataValueDeltaAssertionArgs,
+  '__kind',
+  'I32Bps'
+>;
+export function dataValueDeltaAssertion(
+  kind: 'U64Bps',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueDeltaAssertionArgs,
+    '__kind',
+    'U64Bps'
+  >
+): GetDiscriminatedUnionVariant<
+  DataValueDeltaAssertionArgs,
+  '__kind',
+  'U64Bps'
+>;
+export function dataValueDeltaAssertion(
+  kind: 'I64Bps',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueDeltaAssertionArgs,
+    '__kind',
+    'I64Bps'
+  >
+): GetDiscriminatedUnionVariant<
+  DataValueDeltaAssertionArgs,
+  '__kind',
+  'I64Bps'
+>;
+export function dataValueDeltaAssertion(
+  kind: 'U128',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueDeltaAssertionArgs,
+    '__kind',
+    'U128'
+  >
+): GetDiscriminatedUnionVariant<DataValueDeltaAssertionArgs, '__kind', 'U128'>;
+export function dataValueDeltaAssertion(
+  kind: 'I128',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueDeltaAssertionArgs,
+    '__kind',
+    'I128'
+  >
+): GetDiscriminatedUnionVariant<DataValueDeltaAssertionArgs, '__kind', 'I128'>;
+export function dataValueDeltaAssertion(
+  kind: 'Pubkey',
+  data: GetDiscriminatedUnionVariantContent<
+    DataValueDeltaAssertionArgs,
+    '__kind',
+    'Pubkey'
+  >
+): GetDiscriminatedUnionVariant<
+  DataValueDeltaAssertionArgs,
+  '__kind',
+  'Pubkey'
+>;
 export function dataValueDeltaAssertion<
   K extends DataValueDeltaAssertionArgs['__kind'],
   Data,
@@ -7,30 +7,57 @@
  */
 
 export * from './accountDeltaAssertion';
+export * from './accountHashScope';
 export * from './accountInfoAssertion';
 export * from './accountInfoDeltaAssertion';
 export * from './accountInfoField';
+export * from './arithmeticMode';
+export * from './arithmeticSource';
 export * from './assertionResult';
 export * from './bubblegumTreeConfigAssertion';
+export * from './bytesOperator';
 export * from './clockField';
+export * from './dataRange';
 export * from './dataValue';
 export * from './dataValueAssertion';
 export * from './dataValueDeltaAssertion';
+export * from './epochScheduleField';
 export * from './equatableOperator';
+export * from './hashAlgorithm';
 export * from './integerOperator';
+export * from './integerType';
 export * from './knownProgram';
 export * from './logLevel';
+export * from './memoryAccount';
+export * from './memoryDeltaAssertion';
+export * from './memoryGuard';
+export * from './memoryHeader';
+export * from './memoryKey';
+export * from './memoryTag';
+export * from './memoryWriteEntry';
 export * from './merkleTreeAssertion';
 export * from './metaAssertion';
 export * from './mintAccountAssertion';
+export * from './mintAccountDeltaAssertion';
+export * from './offsetPath';
+export * from './offsetStep';
+export * from './pdaSeed';
+export * from './rangeOperator';
+export * from './setOperator';
 export * from './stakeAccountAssertion';
+export * from './stakeActivationAssertion';
+export * from './stakeActivationField';
 export * from './stakeAssertion';
 export * from './stakeStateType';
+export * from './stringLayout';
+export * from './stringOperator';
 export * from './sysvarClockAssertion';
 export * from './tokenAccountAssertion';
+export * from './tokenAccountDeltaAssertion';
 export * from './upgradableBufferAssertion';
 export * from './upgradeableLoaderStateAssertion';
 export * from './upgradeableLoaderStateType';
 export * from './upgradeableProgramAssertion';
 export * from './upgradeableProgramDataAssertion';
 export * from './writeType';
+export * from './writeTypeTag';
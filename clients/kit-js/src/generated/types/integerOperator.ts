@@ -8,32 +8,137 @@
 
 import {
   combineCodec,
-  getEnumDecoder,
-  getEnumEncoder,
+  getDiscriminatedUnionDecoder,
+  getDiscriminatedUnionEncoder,
+  getI128Decoder,
+  getI128Encoder,
+  getStructDecoder,
+  getStructEncoder,
+  getUnitDecoder,
+  getUnitEncoder,
   type Codec,
   type Decoder,
   type Encoder,
+  type GetDiscriminatedUnionVariant,
+  type GetDiscriminatedUnionVariantContent,
 } from '@solana/kit';
 
-export enum IntegerOperator {
-  Equal,
-  NotEqual,
-  GreaterThan,
-  LessThan,
-  GreaterThanOrEqual,
-  LessThanOrEqual,
-  Contains,
-  DoesNotContain,
-}
+export type IntegerOperator =
+  | { __kind: 'Equal' }
+  | { __kind: 'NotEqual' }
+  | { __kind: 'GreaterThan' }
+  | { __kind: 'LessThan' }
+  | { __kind: 'GreaterThanOrEqual' }
+  | { __kind: 'LessThanOrEqual' }
+  | { __kind: 'Contains' }
+  | { __kind: 'DoesNotContain' }
+  | { __kind: 'Between'; lower: bigint; upper: bigint }
+  | { __kind: 'NotBetween'; lower: bigint; upper: bigint }
+  | { __kind: 'BetweenExclusive'; lower: bigint; upper: bigint }
+  | { __kind: 'NotBetweenExclusive'; lower: bigint; upper: bigint };
 
-export type IntegerOperatorArgs = IntegerOperator;
+export type IntegerOperatorArgs =
+  | { __kind: 'Equal' }
+  | { __kind: 'NotEqual' }
+  | { __kind: 'GreaterThan' }
+  | { __kind: 'LessThan' }
+  | { __kind: 'GreaterThanOrEqual' }
+  | { __kind: 'LessThanOrEqual' }
+  | { __kind: 'Contains' }
+  | { __kind: 'DoesNotContain' }
+  | { __kind: 'Between'; lower: number | bigint; upper: number | bigint }
+  | { __kind: 'NotBetween'; lower: number | bigint; upper: number | bigint }
+  | {
+      __kind: 'BetweenExclusive';
+      lower: number | bigint;
+      upper: number | bigint;
+    }
+  | {
+      __kind: 'NotBetweenExclusive';
+      lower: number | bigint;
+      upper: number | bigint;
+    };
 
 export function getIntegerOperatorEncoder(): Encoder<IntegerOperatorArgs> {
-  return getEnumEncoder(IntegerOperator);
+  return getDiscriminatedUnionEncoder([
+    ['Equal', getUnitEncoder()],
+    ['NotEqual', getUnitEncoder()],
+    ['GreaterThan', getUnitEncoder()],
+    ['LessThan', getUnitEncoder()],
+    ['GreaterThanOrEqual', getUnitEncoder()],
+    ['LessThanOrEqual', getUnitEncoder()],
+    ['Contains', getUnitEncoder()],
+    ['DoesNotContain', getUnitEncoder()],
+    [
+      'Between',
+      getStructEncoder([
+        ['lower', getI128Encoder()],
+        ['upper', getI128Encoder()],
+      ]),
+    ],
+    [
+      'NotBetween',
+      getStructEncoder([
+        ['lower', getI128Encoder()],
+        ['upper', getI128Encoder()],
+      ]),
+    ],
+    [
+      'BetweenExclusive',
+      getStructEncoder([
+        ['lower', getI128Encoder()],
+        ['upper', getI128Encoder()],
+      ]),
+    ],
+    [
+      'NotBetweenExclusive',
+      getStructEncoder([
+        ['lower', getI128Encoder()],
+        ['upper', getI128Encoder()],
+      ]),
+    ],
+  ]);
 }
 
 export function getIntegerOperatorDecoder(): Decoder<IntegerOperator> {
-  return getEnumDecoder(IntegerOperator);
+  return getDiscriminatedUnionDecoder([
+    ['Equal', getUnitDecoder()],
+    ['NotEqual', getUnitDecoder()],
+    ['GreaterThan', getUnitDecoder()],
+    ['LessThan', getUnitDecoder()],
+    ['GreaterThanOrEqual', getUnitDecoder()],
+    ['LessThanOrEqual', getUnitDecoder()],
+    ['Contains', getUnitDecoder()],
+    ['DoesNotContain', getUnitDecoder()],
+    [
+      'Between',
+      getStructDecoder([
+        ['lower', getI128Decoder()],
+        ['upper', getI128Decoder()],
+      ]),
+    ],
+    [
+      'NotBetween',
+      getStructDecoder([
+        ['lower', getI128Decoder()],
+        ['upper', getI128Decoder()],
+      ]),
+    ],
+    [
+      'BetweenExclusive',
+      getStructDecoder([
+        ['lower', getI128Decoder()],
+        ['upper', getI128Decoder()],
+      ]),
+    ],
+    [
+      'NotBetweenExclusive',
+      getStructDecoder([
+        ['lower', getI128Decoder()],
+        ['upper', getI128Decoder()],
+      ]),
+    ],
+  ]);
 }
 
 export function getIntegerOperatorCodec(): Codec<
@@ -42,3 +147,96 @@ export function getIntegerOperatorCodec(): Codec<
 > {
   return combineCodec(getIntegerOperatorEncoder(), getIntegerOperatorDecoder());
 }
+
+// Data Enum Helpers.
+export function integerOperator(
+  kind: 'Equal'
+): GetDiscriminatedUnionVariant<IntegerOperatorArgs, '__kind', 'Equal'>;
+export function integerOperator(
+  kind: 'NotEqual'
+): GetDiscriminatedUnionVariant<IntegerOperatorArgs, '__kind', 'NotEqual'>;
+export function integerOperator(
+  kind: 'GreaterThan'
+): GetDiscriminatedUnionVariant<IntegerOperatorArgs, '__kind', 'GreaterThan'>;
+export function integerOperator(
+  kind: 'LessThan'
+): GetDiscriminatedUnionVariant<IntegerOperatorArgs, '__kind', 'LessThan'>;
+export function integerOperator(
+  kind: 'GreaterThanOrEqual'
+): GetDiscriminatedUnionVariant<
+  IntegerOperatorArgs,
+  '__kind',
+  'GreaterThanOrEqual'
+>;
+export function integerOperator(
+  kind: 'LessThanOrEqual'
+): GetDiscriminatedUnionVariant<
+  IntegerOperatorArgs,
+  '__kind',
+  'LessThanOrEqual'
+>;
+export function integerOperator(
+  kind: 'Contains'
+): GetDiscriminatedUnionVariant<IntegerOperatorArgs, '__kind', 'Contains'>;
+export function integerOperator(
+  kind: 'DoesNotContain'
+): GetDiscriminatedUnionVariant<
+  IntegerOperatorArgs,
+  '__kind',
+  'DoesNotContain'
+>;
+export function integerOperator(
+  kind: 'Between',
+  data: GetDiscriminatedUnionVariantContent<
+    IntegerOperatorArgs,
+    '__kind',
+    'Between'
+  >
+): GetDiscriminatedUnionVariant<IntegerOperatorArgs, '__kind', 'Between'>;
+export function integerOperator(
+  kind: 'NotBetween',
+  data: GetDiscriminatedUnionVariantContent<
+    IntegerOperatorArgs,
+    '__kind',
+    'NotBetween'
+  >
+): GetDiscriminatedUnionVariant<IntegerOperatorArgs, '__kind', 'NotBetween'>;
+export function integerOperator(
+  kind: 'BetweenExclusive',
+  data: GetDiscriminatedUnionVariantContent<
+    IntegerOperatorArgs,
+    '__kind',
+    'BetweenExclusive'
+  >
+): GetDiscriminatedUnionVariant<
+  IntegerOperatorArgs,
+  '__kind',
+  'BetweenExclusive'
+>;
+export function integerOperator(
+  kind: 'NotBetweenExclusive',
+  data: GetDiscriminatedUnionVariantContent<
+    IntegerOperatorArgs,
+    '__kind',
+    'NotBetweenExclusive'
+  >
+): GetDiscriminatedUnionVariant<
+  IntegerOperatorArgs,
+  '__kind',
+  'NotBetweenExclusive'
+>;
+export function integerOperator<K extends IntegerOperatorArgs['__kind'], Data>(
+  kind: K,
+  data?: Data
+) {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+
+export function isIntegerOperator<K extends IntegerOperator['__kind']>(
+  kind: K,
+  value: IntegerOperator
+): value is IntegerOperator & { __kind: K } {
+  return value.__kind === kind;
+}
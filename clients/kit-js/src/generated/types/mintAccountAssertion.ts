@@ -36,11 +36,21 @@ import {
   getEquatableOperatorEncoder,
   getIntegerOperatorDecoder,
   getIntegerOperatorEncoder,
+  getSetOperatorDecoder,
+  getSetOperatorEncoder,
   type EquatableOperator,
   type EquatableOperatorArgs,
   type IntegerOperator,
   type IntegerOperatorArgs,
+  type SetOperator,
+  type SetOperatorArgs,
 } from '.';
+import {
+  getCompactOptionPubkeysDecoder,
+  getCompactOptionPubkeysEncoder,
+  type CompactOptionPubkeys,
+  type CompactOptionPubkeysArgs,
+} from '../../hooked';
 
 export type MintAccountAssertion =
   | {
@@ -55,6 +65,21 @@ export type MintAccountAssertion =
       __kind: 'FreezeAuthority';
       value: Option<Address>;
       operator: EquatableOperator;
+    }
+  | {
+      __kind: 'MintAuthorityIn';
+      value: CompactOptionPubkeys;
+      operator: SetOperator;
+    }
+  | {
+      __kind: 'MintAuthorityAccountRef';
+      accountIndex: number;
+      operator: EquatableOperator;
+    }
+  | {
+      __kind: 'FreezeAuthorityAccountRef';
+      accountIndex: number;
+      operator: EquatableOperator;
     };
 
 export type MintAccountAssertionArgs =
@@ -70,6 +95,21 @@ export type MintAccountAssertionArgs =
       __kind: 'FreezeAuthority';
       value: OptionOrNullable<Address>;
       operator: EquatableOperatorArgs;
+    }
+  | {
+      __kind: 'MintAuthorityIn';
+      value: CompactOptionPubkeysArgs;
+      operator: SetOperatorArgs;
+    }
+  | {
+      __kind: 'MintAuthorityAccountRef';
+      accountIndex: number;
+      operator: EquatableOperatorArgs;
+    }
+  | {
+      __kind: 'FreezeAuthorityAccountRef';
+      accountIndex: number;
+      operator: EquatableOperatorArgs;
     };
 
 export function getMintAccountAssertionEncoder(): Encoder<MintAccountAssertionArgs> {
@@ -109,6 +149,27 @@ export function getMintAccountAssertionEncoder(): Encoder<MintAccountAssertionAr
         ['operator', getEquatableOperatorEncoder()],
       ]),
     ],
+    [
+      'MintAuthorityIn',
+      getStructEncoder([
+        ['value', getCompactOptionPubkeysEncoder()],
+        ['operator', getSetOperatorEncoder()],
+      ]),
+    ],
+    [
+      'MintAuthorityAccountRef',
+      getStructEncoder([
+        ['accountIndex', getU8Encoder()],
+        ['operator', getEquatableOperatorEncoder()],
+      ]),
+    ],
+    [
+      'FreezeAuthorityAccountRef',
+      getStructEncoder([
+        ['accountIndex', getU8Encoder()],
+        ['operator', getEquatableOperatorEncoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -149,6 +210,27 @@ export function getMintAccountAssertionDecoder(): Decoder<MintAccountAssertion>
         ['operator', getEquatableOperatorDecoder()],
       ]),
     ],
+    [
+      'MintAuthorityIn',
+      getStructDecoder([
+        ['value', getCompactOptionPubkeysDecoder()],
+        ['operator', getSetOperatorDecoder()],
+      ]),
+    ],
+    [
+      'MintAuthorityAccountRef',
+      getStructDecoder([
+        ['accountIndex', getU8Decoder()],
+        ['operator', getEquatableOperatorDecoder()],
+      ]),
+    ],
+    [
+      'FreezeAuthorityAccountRef',
+      getStructDecoder([
+        ['accountIndex', getU8Decoder()],
+        ['operator', getEquatableOperatorDecoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -215,6 +297,42 @@ export function mintAccountAssertion(
   '__kind',
   'FreezeAuthority'
 >;
+export function mintAccountAssertion(
+  kind: 'MintAuthorityIn',
+  data: GetDiscriminatedUnionVariantContent<
+    MintAccountAssertionArgs,
+    '__kind',
+    'MintAuthorityIn'
+  >
+): GetDiscriminatedUnionVariant<
+  MintAccountAssertionArgs,
+  '__kind',
+  'MintAuthorityIn'
+>;
+export function mintAccountAssertion(
+  kind: 'MintAuthorityAccountRef',
+  data: GetDiscriminatedUnionVariantContent<
+    MintAccountAssertionArgs,
+    '__kind',
+    'MintAuthorityAccountRef'
+  >
+): GetDiscriminatedUnionVariant<
+  MintAccountAssertionArgs,
+  '__kind',
+  'MintAuthorityAccountRef'
+>;
+export function mintAccountAssertion(
+  kind: 'FreezeAuthorityAccountRef',
+  data: GetDiscriminatedUnionVariantContent<
+    MintAccountAssertionArgs,
+    '__kind',
+    'FreezeAuthorityAccountRef'
+  >
+): GetDiscriminatedUnionVariant<
+  MintAccountAssertionArgs,
+  '__kind',
+  'FreezeAuthorityAccountRef'
+>;
 export function mintAccountAssertion<
   K extends MintAccountAssertionArgs['__kind'],
   Data,
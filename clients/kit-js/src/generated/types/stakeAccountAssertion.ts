@@ -29,6 +29,8 @@ import {
   getIntegerOperatorEncoder,
   getMetaAssertionDecoder,
   getMetaAssertionEncoder,
+  getStakeActivationAssertionDecoder,
+  getStakeActivationAssertionEncoder,
   getStakeAssertionDecoder,
   getStakeAssertionEncoder,
   getStakeStateTypeDecoder,
@@ -39,6 +41,8 @@ import {
   type IntegerOperatorArgs,
   type MetaAssertion,
   type MetaAssertionArgs,
+  type StakeActivationAssertion,
+  type StakeActivationAssertionArgs,
   type StakeAssertion,
   type StakeAssertionArgs,
   type StakeStateType,
@@ -49,7 +53,11 @@ export type StakeAccountAssertion =
   | { __kind: 'State'; value: StakeStateType; operator: EquatableOperator }
   | { __kind: 'MetaAssertion'; fields: readonly [MetaAssertion] }
   | { __kind: 'StakeAssertion'; fields: readonly [StakeAssertion] }
-  | { __kind: 'StakeFlags'; value: number; operator: IntegerOperator };
+  | { __kind: 'StakeFlags'; value: number; operator: IntegerOperator }
+  | {
+      __kind: 'StakeActivationAssertion';
+      fields: readonly [StakeActivationAssertion];
+    };
 
 export type StakeAccountAssertionArgs =
   | {
@@ -59,7 +67,11 @@ export type StakeAccountAssertionArgs =
     }
   | { __kind: 'MetaAssertion'; fields: readonly [MetaAssertionArgs] }
   | { __kind: 'StakeAssertion'; fields: readonly [StakeAssertionArgs] }
-  | { __kind: 'StakeFlags'; value: number; operator: IntegerOperatorArgs };
+  | { __kind: 'StakeFlags'; value: number; operator: IntegerOperatorArgs }
+  | {
+      __kind: 'StakeActivationAssertion';
+      fields: readonly [StakeActivationAssertionArgs];
+    };
 
 export function getStakeAccountAssertionEncoder(): Encoder<StakeAccountAssertionArgs> {
   return getDiscriminatedUnionEncoder([
@@ -89,6 +101,12 @@ export function getStakeAccountAssertionEncoder(): Encoder<StakeAccountAssertion
         ['operator', getIntegerOperatorEncoder()],
       ]),
     ],
+    [
+      'StakeActivationAssertion',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getStakeActivationAssertionEncoder()])],
+      ]),
+    ],
   ]);
 }
 
@@ -120,6 +138,12 @@ export function getStakeAccountAssertionDecoder(): Decoder<StakeAccountAssertion
         ['operator', getIntegerOperatorDecoder()],
       ]),
     ],
+    [
+      'StakeActivationAssertion',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getStakeActivationAssertionDecoder()])],
+      ]),
+    ],
   ]);
 }
 
@@ -178,6 +202,18 @@ export function stakeAccountAssertion(
   '__kind',
   'StakeFlags'
 >;
+export function stakeAccountAssertion(
+  kind: 'StakeActivationAssertion',
+  data: GetDiscriminatedUnionVariantContent<
+    StakeAccountAssertionArgs,
+    '__kind',
+    'StakeActivationAssertion'
+  >['fields']
+): GetDiscriminatedUnionVariant<
+  StakeAccountAssertionArgs,
+  '__kind',
+  'StakeActivationAssertion'
+>;
 export function stakeAccountAssertion<
   K extends StakeAccountAssertionArgs['__kind'],
   Data,
@@ -12,6 +12,8 @@ import {
   getAddressEncoder,
   getDiscriminatedUnionDecoder,
   getDiscriminatedUnionEncoder,
+  getI8Decoder,
+  getI8Encoder,
   getOptionDecoder,
   getOptionEncoder,
   getStructDecoder,
@@ -36,11 +38,21 @@ import {
   getEquatableOperatorEncoder,
   getIntegerOperatorDecoder,
   getIntegerOperatorEncoder,
+  getSetOperatorDecoder,
+  getSetOperatorEncoder,
   type EquatableOperator,
   type EquatableOperatorArgs,
   type IntegerOperator,
   type IntegerOperatorArgs,
+  type SetOperator,
+  type SetOperatorArgs,
 } from '.';
+import {
+  getCompactOptionPubkeysDecoder,
+  getCompactOptionPubkeysEncoder,
+  type CompactOptionPubkeys,
+  type CompactOptionPubkeysArgs,
+} from '../../hooked';
 
 export type TokenAccountAssertion =
   | { __kind: 'Mint'; value: Address; operator: EquatableOperator }
@@ -55,7 +67,34 @@ export type TokenAccountAssertion =
       value: Option<Address>;
       operator: EquatableOperator;
     }
-  | { __kind: 'TokenAccountOwnerIsDerived' };
+  | { __kind: 'TokenAccountOwnerIsDerived' }
+  | { __kind: 'DelegateIn'; value: CompactOptionPubkeys; operator: SetOperator }
+  | {
+      __kind: 'MintAccountRef';
+      accountIndex: number;
+      operator: EquatableOperator;
+    }
+  | {
+      __kind: 'OwnerAccountRef';
+      accountIndex: number;
+      operator: EquatableOperator;
+    }
+  | {
+      __kind: 'DelegateAccountRef';
+      accountIndex: number;
+      operator: EquatableOperator;
+    }
+  | {
+      __kind: 'CloseAuthorityAccountRef';
+      accountIndex: number;
+      operator: EquatableOperator;
+    }
+  | {
+      __kind: 'UiAmount';
+      mantissa: bigint;
+      exponent: number;
+      operator: IntegerOperator;
+    };
 
 export type TokenAccountAssertionArgs =
   | { __kind: 'Mint'; value: Address; operator: EquatableOperatorArgs }
@@ -82,7 +121,38 @@ export type TokenAccountAssertionArgs =
       value: OptionOrNullable<Address>;
       operator: EquatableOperatorArgs;
     }
-  | { __kind: 'TokenAccountOwnerIsDerived' };
+  | { __kind: 'TokenAccountOwnerIsDerived' }
+  | {
+      __kind: 'DelegateIn';
+      value: CompactOptionPubkeysArgs;
+      operator: SetOperatorArgs;
+    }
+  | {
+      __kind: 'MintAccountRef';
+      accountIndex: number;
+      operator: EquatableOperatorArgs;
+    }
+  | {
+      __kind: 'OwnerAccountRef';
+      accountIndex: number;
+      operator: EquatableOperatorArgs;
+    }
+  | {
+      __kind: 'DelegateAccountRef';
+      accountIndex: number;
+      operator: EquatableOperatorArgs;
+    }
+  | {
+      __kind: 'CloseAuthorityAccountRef';
+      accountIndex: number;
+      operator: EquatableOperatorArgs;
+    }
+  | {
+      __kind: 'UiAmount';
+      mantissa: number | bigint;
+      exponent: number;
+      operator: IntegerOperatorArgs;
+    };
 
 export function getTokenAccountAssertionEncoder(): Encoder<TokenAccountAssertionArgs> {
   return getDiscriminatedUnionEncoder([
@@ -143,6 +213,49 @@ export function getTokenAccountAssertionEncoder(): Encoder<TokenAccountAssertion
       ]),
     ],
     ['TokenAccountOwnerIsDerived', getUnitEncoder()],
+    [
+      'DelegateIn',
+      getStructEncoder([
+        ['value', getCompactOptionPubkeysEncoder()],
+        ['operator', getSetOperatorEncoder()],
+      ]),
+    ],
+    [
+      'MintAccountRef',
+      getStructEncoder([
+        ['accountIndex', getU8Encoder()],
+        ['operator', getEquatableOperatorEncoder()],
+      ]),
+    ],
+    [
+      'OwnerAccountRef',
+      getStructEncoder([
+        ['accountIndex', getU8Encoder()],
+        ['operator', getEquatableOperatorEncoder()],
+      ]),
+    ],
+    [
+      'DelegateAccountRef',
+      getStructEncoder([
+        ['accountIndex', getU8Encoder()],
+        ['operator', getEquatableOperatorEncoder()],
+      ]),
+    ],
+    [
+      'CloseAuthorityAccountRef',
+      getStructEncoder([
+        ['accountIndex', getU8Encoder()],
+        ['operator', getEquatableOperatorEncoder()],
+      ]),
+    ],
+    [
+      'UiAmount',
+      getStructEncoder([
+        ['mantissa', getU64Encoder()],
+        ['exponent', getI8Encoder()],
+        ['operator', getIntegerOperatorEncoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -205,6 +318,49 @@ export function getTokenAccountAssertionDecoder(): Decoder<TokenAccountAssertion
       ]),
     ],
     ['TokenAccountOwnerIsDerived', getUnitDecoder()],
+    [
+      'DelegateIn',
+      getStructDecoder([
+        ['value', getCompactOptionPubkeysDecoder()],
+        ['operator', getSetOperatorDecoder()],
+      ]),
+    ],
+    [
+      'MintAccountRef',
+      getStructDecoder([
+        ['accountIndex', getU8Decoder()],
+        ['operator', getEquatableOperatorDecoder()],
+      ]),
+    ],
+    [
+      'OwnerAccountRef',
+      getStructDecoder([
+        ['accountIndex', getU8Decoder()],
+        ['operator', getEquatableOperatorDecoder()],
+      ]),
+    ],
+    [
+      'DelegateAccountRef',
+      getStructDecoder([
+        ['accountIndex', getU8Decoder()],
+        ['operator', getEquatableOperatorDecoder()],
+      ]),
+    ],
+    [
+      'CloseAuthorityAccountRef',
+      getStructDecoder([
+        ['accountIndex', getU8Decoder()],
+        ['operator', getEquatableOperatorDecoder()],
+      ]),
+    ],
+    [
+      'UiAmount',
+      getStructDecoder([
+        ['mantissa', getU64Decoder()],
+        ['exponent', getI8Decoder()],
+        ['operator', getIntegerOperatorDecoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -306,6 +462,78 @@ export function tokenAccountAssertion(
   '__kind',
   'TokenAccountOwnerIsDerived'
 >;
+export function tokenAccountAssertion(
+  kind: 'DelegateIn',
+  data: GetDiscriminatedUnionVariantContent<
+    TokenAccountAssertionArgs,
+    '__kind',
+    'DelegateIn'
+  >
+): GetDiscriminatedUnionVariant<
+  TokenAccountAssertionArgs,
+  '__kind',
+  'DelegateIn'
+>;
+export function tokenAccountAssertion(
+  kind: 'MintAccountRef',
+  data: GetDiscriminatedUnionVariantContent<
+    TokenAccountAssertionArgs,
+    '__kind',
+    'MintAccountRef'
+  >
+): GetDiscriminatedUnionVariant<
+  TokenAccountAssertionArgs,
+  '__kind',
+  'MintAccountRef'
+>;
+export function tokenAccountAssertion(
+  kind: 'OwnerAccountRef',
+  data: GetDiscriminatedUnionVariantContent<
+    TokenAccountAssertionArgs,
+    '__kind',
+    'OwnerAccountRef'
+  >
+): GetDiscriminatedUnionVariant<
+  TokenAccountAssertionArgs,
+  '__kind',
+  'OwnerAccountRef'
+>;
+export function tokenAccountAssertion(
+  kind: 'DelegateAccountRef',
+  data: GetDiscriminatedUnionVariantContent<
+    TokenAccountAssertionArgs,
+    '__kind',
+    'DelegateAccountRef'
+  >
+): GetDiscriminatedUnionVariant<
+  TokenAccountAssertionArgs,
+  '__kind',
+  'DelegateAccountRef'
+>;
+export function tokenAccountAssertion(
+  kind: 'CloseAuthorityAccountRef',
+  data: GetDiscriminatedUnionVariantContent<
+    TokenAccountAssertionArgs,
+    '__kind',
+    'CloseAuthorityAccountRef'
+  >
+): GetDiscriminatedUnionVariant<
+  TokenAccountAssertionArgs,
+  '__kind',
+  'CloseAuthorityAccountRef'
+>;
+export function tokenAccountAssertion(
+  kind: 'UiAmount',
+  data: GetDiscriminatedUnionVariantContent<
+    TokenAccountAssertionArgs,
+    '__kind',
+    'UiAmount'
+  >
+): GetDiscriminatedUnionVariant<
+  TokenAccountAssertionArgs,
+  '__kind',
+  'UiAmount'
+>;
 export function tokenAccountAssertion<
   K extends TokenAccountAssertionArgs['__kind'],
   Data,
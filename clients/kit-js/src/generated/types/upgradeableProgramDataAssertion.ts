@@ -8,8 +8,12 @@
 
 import {
   combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
   getDiscriminatedUnionDecoder,
   getDiscriminatedUnionEncoder,
   getOptionDecoder,
@@ -26,6 +30,7 @@ import {
   type GetDiscriminatedUnionVariantContent,
   type Option,
   type OptionOrNullable,
+  type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
   getEquatableOperatorDecoder,
@@ -37,6 +42,12 @@ import {
   type IntegerOperator,
   type IntegerOperatorArgs,
 } from '.';
+import {
+  getCompactU64Decoder,
+  getCompactU64Encoder,
+  type CompactU64,
+  type CompactU64Args,
+} from '../../hooked';
 
 export type UpgradeableProgramDataAssertion =
   | {
@@ -44,7 +55,14 @@ export type UpgradeableProgramDataAssertion =
       value: Option<Address>;
       operator: EquatableOperator;
     }
-  | { __kind: 'Slot'; value: bigint; operator: IntegerOperator };
+  | { __kind: 'Slot'; value: bigint; operator: IntegerOperator }
+  | {
+      __kind: 'BytecodeHash';
+      expectedHash: ReadonlyUint8Array;
+      previousHash: Option<ReadonlyUint8Array>;
+      offset: CompactU64;
+      length: Option<CompactU64>;
+    };
 
 export type UpgradeableProgramDataAssertionArgs =
   | {
@@ -52,7 +70,14 @@ export type UpgradeableProgramDataAssertionArgs =
       value: OptionOrNullable<Address>;
       operator: EquatableOperatorArgs;
     }
-  | { __kind: 'Slot'; value: number | bigint; operator: IntegerOperatorArgs };
+  | { __kind: 'Slot'; value: number | bigint; operator: IntegerOperatorArgs }
+  | {
+      __kind: 'BytecodeHash';
+      expectedHash: ReadonlyUint8Array;
+      previousHash: OptionOrNullable<ReadonlyUint8Array>;
+      offset: CompactU64Args;
+      length: OptionOrNullable<CompactU64Args>;
+    };
 
 export function getUpgradeableProgramDataAssertionEncoder(): Encoder<UpgradeableProgramDataAssertionArgs> {
   return getDiscriminatedUnionEncoder([
@@ -70,6 +95,18 @@ export function getUpgradeableProgramDataAssertionEncoder(): Encoder<Upgradeable
         ['operator', getIntegerOperatorEncoder()],
       ]),
     ],
+    [
+      'BytecodeHash',
+      getStructEncoder([
+        ['expectedHash', fixEncoderSize(getBytesEncoder(), 32)],
+        [
+          'previousHash',
+          getOptionEncoder(fixEncoderSize(getBytesEncoder(), 32)),
+        ],
+        ['offset', getCompactU64Encoder()],
+        ['length', getOptionEncoder(getCompactU64Encoder())],
+      ]),
+    ],
   ]);
 }
 
@@ -89,6 +126,18 @@ export function getUpgradeableProgramDataAssertionDecoder(): Decoder<Upgradeable
         ['operator', getIntegerOperatorDecoder()],
       ]),
     ],
+    [
+      'BytecodeHash',
+      getStructDecoder([
+        ['expectedHash', fixDecoderSize(getBytesDecoder(), 32)],
+        [
+          'previousHash',
+          getOptionDecoder(fixDecoderSize(getBytesDecoder(), 32)),
+        ],
+        ['offset', getCompactU64Decoder()],
+        ['length', getOptionDecoder(getCompactU64Decoder())],
+      ]),
+    ],
   ]);
 }
 
@@ -127,6 +176,18 @@ export function upgradeableProgramDataAssertion(
   '__kind',
   'Slot'
 >;
+export function upgradeableProgramDataAssertion(
+  kind: 'BytecodeHash',
+  data: GetDiscriminatedUnionVariantContent<
+    UpgradeableProgramDataAssertionArgs,
+    '__kind',
+    'BytecodeHash'
+  >
+): GetDiscriminatedUnionVariant<
+  UpgradeableProgramDataAssertionArgs,
+  '__kind',
+  'BytecodeHash'
+>;
 export function upgradeableProgramDataAssertion<
   K extends UpgradeableProgramDataAssertionArgs['__kind'],
   Data,
@@ -8,14 +8,19 @@
 
 import {
   combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
   getDiscriminatedUnionDecoder,
   getDiscriminatedUnionEncoder,
   getStructDecoder,
   getStructEncoder,
   getTupleDecoder,
   getTupleEncoder,
-  getU16Decoder,
-  getU16Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  getUnitDecoder,
+  getUnitEncoder,
+  type Address,
   type Codec,
   type Decoder,
   type Encoder,
@@ -23,39 +28,129 @@ import {
   type GetDiscriminatedUnionVariantContent,
 } from '@solana/kit';
 import {
+  getAccountHashScopeDecoder,
+  getAccountHashScopeEncoder,
   getAccountInfoFieldDecoder,
   getAccountInfoFieldEncoder,
+  getArithmeticModeDecoder,
+  getArithmeticModeEncoder,
+  getArithmeticSourceDecoder,
+  getArithmeticSourceEncoder,
   getClockFieldDecoder,
   getClockFieldEncoder,
   getDataValueDecoder,
   getDataValueEncoder,
+  getEpochScheduleFieldDecoder,
+  getEpochScheduleFieldEncoder,
+  getHashAlgorithmDecoder,
+  getHashAlgorithmEncoder,
+  getIntegerTypeDecoder,
+  getIntegerTypeEncoder,
+  getStakeActivationFieldDecoder,
+  getStakeActivationFieldEncoder,
+  type AccountHashScope,
+  type AccountHashScopeArgs,
   type AccountInfoField,
   type AccountInfoFieldArgs,
+  type ArithmeticMode,
+  type ArithmeticModeArgs,
+  type ArithmeticSource,
+  type ArithmeticSourceArgs,
   type ClockField,
   type ClockFieldArgs,
   type DataValue,
   type DataValueArgs,
+  type EpochScheduleField,
+  type EpochScheduleFieldArgs,
+  type HashAlgorithm,
+  type HashAlgorithmArgs,
+  type IntegerType,
+  type IntegerTypeArgs,
+  type StakeActivationField,
+  type StakeActivationFieldArgs,
 } from '.';
+import {
+  getCompactDataRangesDecoder,
+  getCompactDataRangesEncoder,
+  getCompactU64Decoder,
+  getCompactU64Encoder,
+  type CompactDataRanges,
+  type CompactDataRangesArgs,
+  type CompactU64,
+  type CompactU64Args,
+} from '../../hooked';
 
 export type WriteType =
-  | { __kind: 'AccountData'; offset: number; dataLength: number }
+  | { __kind: 'AccountData'; offset: CompactU64; dataLength: CompactU64 }
   | { __kind: 'AccountInfoField'; fields: readonly [AccountInfoField] }
   | { __kind: 'DataValue'; fields: readonly [DataValue] }
-  | { __kind: 'Clock'; fields: readonly [ClockField] };
+  | { __kind: 'Clock'; fields: readonly [ClockField] }
+  | { __kind: 'AccountHash'; algorithm: HashAlgorithm; scope: AccountHashScope }
+  | { __kind: 'AccountDataRanges'; ranges: CompactDataRanges }
+  | {
+      __kind: 'Arithmetic';
+      mode: ArithmeticMode;
+      integerType: IntegerType;
+      source: ArithmeticSource;
+    }
+  | { __kind: 'MemoryHeader'; nonce: bigint }
+  | { __kind: 'TokenAccountSnapshot' }
+  | { __kind: 'MintAccountSnapshot' }
+  | {
+      __kind: 'ReturnData';
+      programId: Address;
+      offset: CompactU64;
+      length: CompactU64;
+    }
+  | { __kind: 'RentMinimumBalance'; dataLength: CompactU64 }
+  | { __kind: 'EpochSchedule'; fields: readonly [EpochScheduleField] }
+  | { __kind: 'InstructionIndex' }
+  | { __kind: 'StakeActivation'; fields: readonly [StakeActivationField] }
+  | { __kind: 'Resize'; space: CompactU64 };
 
 export type WriteTypeArgs =
-  | { __kind: 'AccountData'; offset: number; dataLength: number }
+  | {
+      __kind: 'AccountData';
+      offset: CompactU64Args;
+      dataLength: CompactU64Args;
+    }
   | { __kind: 'AccountInfoField'; fields: readonly [AccountInfoFieldArgs] }
   | { __kind: 'DataValue'; fields: readonly [DataValueArgs] }
-  | { __kind: 'Clock'; fields: readonly [ClockFieldArgs] };
+  | { __kind: 'Clock'; fields: readonly [ClockFieldArgs] }
+  | {
+      __kind: 'AccountHash';
+      algorithm: HashAlgorithmArgs;
+      scope: AccountHashScopeArgs;
+    }
+  | { __kind: 'AccountDataRanges'; ranges: CompactDataRangesArgs }
+  | {
+      __kind: 'Arithmetic';
+      mode: ArithmeticModeArgs;
+      integerType: IntegerTypeArgs;
+      source: ArithmeticSourceArgs;
+    }
+  | { __kind: 'MemoryHeader'; nonce: number | bigint }
+  | { __kind: 'TokenAccountSnapshot' }
+  | { __kind: 'MintAccountSnapshot' }
+  | {
+      __kind: 'ReturnData';
+      programId: Address;
+      offset: CompactU64Args;
+      length: CompactU64Args;
+    }
+  | { __kind: 'RentMinimumBalance'; dataLength: CompactU64Args }
+  | { __kind: 'EpochSchedule'; fields: readonly [EpochScheduleFieldArgs] }
+  | { __kind: 'InstructionIndex' }
+  | { __kind: 'StakeActivation'; fields: readonly [StakeActivationFieldArgs] }
+  | { __kind: 'Resize'; space: CompactU64Args };
 
 export function getWriteTypeEncoder(): Encoder<WriteTypeArgs> {
   return getDiscriminatedUnionEncoder([
     [
       'AccountData',
       getStructEncoder([
-        ['offset', getU16Encoder()],
-        ['dataLength', getU16Encoder()],
+        ['offset', getCompactU64Encoder()],
+        ['dataLength', getCompactU64Encoder()],
       ]),
     ],
     [
@@ -72,6 +167,54 @@ export function getWriteTypeEncoder(): Encoder<WriteTypeArgs> {
       'Clock',
       getStructEncoder([['fields', getTupleEncoder([getClockFieldEncoder()])]]),
     ],
+    [
+      'AccountHash',
+      getStructEncoder([
+        ['algorithm', getHashAlgorithmEncoder()],
+        ['scope', getAccountHashScopeEncoder()],
+      ]),
+    ],
+    [
+      'AccountDataRanges',
+      getStructEncoder([['ranges', getCompactDataRangesEncoder()]]),
+    ],
+    [
+      'Arithmetic',
+      getStructEncoder([
+        ['mode', getArithmeticModeEncoder()],
+        ['integerType', getIntegerTypeEncoder()],
+        ['source', getArithmeticSourceEncoder()],
+      ]),
+    ],
+    ['MemoryHeader', getStructEncoder([['nonce', getU64Encoder()]])],
+    ['TokenAccountSnapshot', getUnitEncoder()],
+    ['MintAccountSnapshot', getUnitEncoder()],
+    [
+      'ReturnData',
+      getStructEncoder([
+        ['programId', getAddressEncoder()],
+        ['offset', getCompactU64Encoder()],
+        ['length', getCompactU64Encoder()],
+      ]),
+    ],
+    [
+      'RentMinimumBalance',
+      getStructEncoder([['dataLength', getCompactU64Encoder()]]),
+    ],
+    [
+      'EpochSchedule',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getEpochScheduleFieldEncoder()])],
+      ]),
+    ],
+    ['InstructionIndex', getUnitEncoder()],
+    [
+      'StakeActivation',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getStakeActivationFieldEncoder()])],
+      ]),
+    ],
+    ['Resize', getStructEncoder([['space', getCompactU64Encoder()]])],
   ]);
 }
 
@@ -80,8 +223,8 @@ export function getWriteTypeDecoder(): Decoder<WriteType> {
     [
       'AccountData',
       getStructDecoder([
-        ['offset', getU16Decoder()],
-        ['dataLength', getU16Decoder()],
+        ['offset', getCompactU64Decoder()],
+        ['dataLength', getCompactU64Decoder()],
       ]),
     ],
     [
@@ -98,6 +241,54 @@ export function getWriteTypeDecoder(): Decoder<WriteType> {
       'Clock',
       getStructDecoder([['fields', getTupleDecoder([getClockFieldDecoder()])]]),
     ],
+    [
+      'AccountHash',
+      getStructDecoder([
+        ['algorithm', getHashAlgorithmDecoder()],
+        ['scope', getAccountHashScopeDecoder()],
+      ]),
+    ],
+    [
+      'AccountDataRanges',
+      getStructDecoder([['ranges', getCompactDataRangesDecoder()]]),
+    ],
+    [
+      'Arithmetic',
+      getStructDecoder([
+        ['mode', getArithmeticModeDecoder()],
+        ['integerType', getIntegerTypeDecoder()],
+        ['source', getArithmeticSourceDecoder()],
+      ]),
+    ],
+    ['MemoryHeader', getStructDecoder([['nonce', getU64Decoder()]])],
+    ['TokenAccountSnapshot', getUnitDecoder()],
+    ['MintAccountSnapshot', getUnitDecoder()],
+    [
+      'ReturnData',
+      getStructDecoder([
+        ['programId', getAddressDecoder()],
+        ['offset', getCompactU64Decoder()],
+        ['length', getCompactU64Decoder()],
+      ]),
+    ],
+    [
+      'RentMinimumBalance',
+      getStructDecoder([['dataLength', getCompactU64Decoder()]]),
+    ],
+    [
+      'EpochSchedule',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getEpochScheduleFieldDecoder()])],
+      ]),
+    ],
+    ['InstructionIndex', getUnitDecoder()],
+    [
+      'StakeActivation',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getStakeActivationFieldDecoder()])],
+      ]),
+    ],
+    ['Resize', getStructDecoder([['space', getCompactU64Decoder()]])],
   ]);
 }
 
@@ -138,6 +329,87 @@ export function writeType(
     'Clock'
   >['fields']
 ): GetDiscriminatedUnionVariant<WriteTypeArgs, '__kind', 'Clock'>;
+export function writeType(
+  kind: 'AccountHash',
+  data: GetDiscriminatedUnionVariantContent<
+    WriteTypeArgs,
+    '__kind',
+    'AccountHash'
+  >
+): GetDiscriminatedUnionVariant<WriteTypeArgs, '__kind', 'AccountHash'>;
+export function writeType(
+  kind: 'AccountDataRanges',
+  data: GetDiscriminatedUnionVariantContent<
+    WriteTypeArgs,
+    '__kind',
+    'AccountDataRanges'
+  >
+): GetDiscriminatedUnionVariant<WriteTypeArgs, '__kind', 'AccountDataRanges'>;
+export function writeType(
+  kind: 'Arithmetic',
+  data: GetDiscriminatedUnionVariantContent<
+    WriteTypeArgs,
+    '__kind',
+    'Arithmetic'
+  >
+): GetDiscriminatedUnionVariant<WriteTypeArgs, '__kind', 'Arithmetic'>;
+export function writeType(
+  kind: 'MemoryHeader',
+  data: GetDiscriminatedUnionVariantContent<
+    WriteTypeArgs,
+    '__kind',
+    'MemoryHeader'
+  >
+): GetDiscriminatedUnionVariant<WriteTypeArgs, '__kind', 'MemoryHeader'>;
+export function writeType(
+  kind: 'TokenAccountSnapshot'
+): GetDiscriminatedUnionVariant<
+  WriteTypeArgs,
+  '__kind',
+  'TokenAccountSnapshot'
+>;
+export function writeType(
+  kind: 'MintAccountSnapshot'
+): GetDiscriminatedUnionVariant<WriteTypeArgs, '__kind', 'MintAccountSnapshot'>;
+export function writeType(
+  kind: 'ReturnData',
+  data: GetDiscriminatedUnionVariantContent<
+    WriteTypeArgs,
+    '__kind',
+    'ReturnData'
+  >
+): GetDiscriminatedUnionVariant<WriteTypeArgs, '__kind', 'ReturnData'>;
+export function writeType(
+  kind: 'RentMinimumBalance',
+  data: GetDiscriminatedUnionVariantContent<
+    WriteTypeArgs,
+    '__kind',
+    'RentMinimumBalance'
+  >
+): GetDiscriminatedUnionVariant<WriteTypeArgs, '__kind', 'RentMinimumBalance'>;
+export function writeType(
+  kind: 'EpochSchedule',
+  data: GetDiscriminatedUnionVariantContent<
+    WriteTypeArgs,
+    '__kind',
+    'EpochSchedule'
+  >['fields']
+): GetDiscriminatedUnionVariant<WriteTypeArgs, '__kind', 'EpochSchedule'>;
+export function writeType(
+  kind: 'InstructionIndex'
+): GetDiscriminatedUnionVariant<WriteTypeArgs, '__kind', 'InstructionIndex'>;
+export function writeType(
+  kind: 'StakeActivation',
+  data: GetDiscriminatedUnionVariantContent<
+    WriteTypeArgs,
+    '__kind',
+    'StakeActivation'
+  >['fields']
+): GetDiscriminatedUnionVariant<WriteTypeArgs, '__kind', 'StakeActivation'>;
+export function writeType(
+  kind: 'Resize',
+  data: GetDiscriminatedUnionVariantContent<WriteTypeArgs, '__kind', 'Resize'>
+): GetDiscriminatedUnionVariant<WriteTypeArgs, '__kind', 'Resize'>;
 export function writeType<K extends WriteTypeArgs['__kind'], Data>(
   kind: K,
   data?: Data
@@ -1,14 +1,21 @@
 import {
+  Address,
   Decoder,
   Encoder,
   Offset,
+  Option,
+  OptionOrNullable,
   ReadonlyUint8Array,
   VariableSizeDecoder,
   VariableSizeEncoder,
   createDecoder,
   createEncoder,
+  getAddressDecoder,
+  getAddressEncoder,
   getArrayDecoder,
   getArrayEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU8Decoder,
@@ -19,10 +26,26 @@ import { LEB128, UnsignedLEB128 } from '@minhducsun2002/leb128';
 import {
   AccountInfoAssertion,
   AccountInfoAssertionArgs,
+  DataRange,
+  DataRangeArgs,
   DataValueAssertion,
   DataValueAssertionArgs,
+  KnownProgram,
+  KnownProgramArgs,
+  MemoryAccount,
+  MemoryAccountArgs,
+  MemoryDeltaAssertion,
+  MemoryDeltaAssertionArgs,
+  MemoryWriteEntry,
+  MemoryWriteEntryArgs,
   MintAccountAssertion,
   MintAccountAssertionArgs,
+  OffsetPath,
+  OffsetPathArgs,
+  OffsetStep,
+  OffsetStepArgs,
+  PdaSeed,
+  PdaSeedArgs,
   StakeAccountAssertion,
   StakeAccountAssertionArgs,
   TokenAccountAssertion,
@@ -31,10 +54,26 @@ import {
   UpgradeableLoaderStateAssertionArgs,
   getAccountInfoAssertionDecoder,
   getAccountInfoAssertionEncoder,
+  getDataRangeDecoder,
+  getDataRangeEncoder,
   getDataValueAssertionDecoder,
   getDataValueAssertionEncoder,
+  getKnownProgramDecoder,
+  getKnownProgramEncoder,
+  getMemoryAccountDecoder,
+  getMemoryAccountEncoder,
+  getMemoryDeltaAssertionDecoder,
+  getMemoryDeltaAssertionEncoder,
+  getMemoryWriteEntryDecoder,
+  getMemoryWriteEntryEncoder,
   getMintAccountAssertionDecoder,
   getMintAccountAssertionEncoder,
+  getOffsetPathDecoder,
+  getOffsetPathEncoder,
+  getOffsetStepDecoder,
+  getOffsetStepEncoder,
+  getPdaSeedDecoder,
+  getPdaSeedEncoder,
   getStakeAccountAssertionDecoder,
   getStakeAccountAssertionEncoder,
   getTokenAccountAssertionDecoder,
@@ -217,3 +256,180 @@ export function getCompactBytesDecoder() {
     size: getCompactU64Decoder(),
   });
 }
+
+export type AccountDataPathAssertion = {
+  path: OffsetPath;
+  assertion: DataValueAssertion;
+};
+
+export type AccountDataPathAssertionArgs = {
+  path: OffsetPathArgs;
+  assertion: DataValueAssertionArgs;
+};
+
+export function getAccountDataPathAssertionEncoder(): Encoder<AccountDataPathAssertionArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['path', getOffsetPathEncoder()],
+      ['assertion', getDataValueAssertionEncoder()],
+    ]),
+    (value) => value
+  );
+}
+
+export function getAccountDataPathAssertionDecoder(): Decoder<AccountDataPathAssertion> {
+  return getStructDecoder([
+    ['path', getOffsetPathDecoder()],
+    ['assertion', getDataValueAssertionDecoder()],
+  ]);
+}
+
+export type AccountDataPathAssertions = Array<AccountDataPathAssertion>;
+export type AccountDataPathAssertionsArgs = Array<AccountDataPathAssertionArgs>;
+
+export function getAccountDataPathAssertionsEncoder() {
+  return getArrayEncoder(getAccountDataPathAssertionEncoder(), {
+    size: getCompactU64Encoder(),
+  });
+}
+
+export function getAccountDataPathAssertionsDecoder() {
+  return getArrayDecoder(getAccountDataPathAssertionDecoder(), {
+    size: getCompactU64Decoder(),
+  });
+}
+
+export type OffsetSteps = Array<OffsetStep>;
+export type OffsetStepsArgs = Array<OffsetStepArgs>;
+
+export function getOffsetStepsEncoder() {
+  return getArrayEncoder(getOffsetStepEncoder(), {
+    size: getCompactU64Encoder(),
+  });
+}
+
+export function getOffsetStepsDecoder() {
+  return getArrayDecoder(getOffsetStepDecoder(), {
+    size: getCompactU64Decoder(),
+  });
+}
+
+export type MemoryWriteEntries = Array<MemoryWriteEntry>;
+export type MemoryWriteEntriesArgs = Array<MemoryWriteEntryArgs>;
+
+export function getMemoryWriteEntriesEncoder() {
+  return getArrayEncoder(getMemoryWriteEntryEncoder(), {
+    size: getCompactU64Encoder(),
+  });
+}
+
+export function getMemoryWriteEntriesDecoder() {
+  return getArrayDecoder(getMemoryWriteEntryDecoder(), {
+    size: getCompactU64Decoder(),
+  });
+}
+
+export type MemoryAccounts = Array<MemoryAccount>;
+export type MemoryAccountsArgs = Array<MemoryAccountArgs>;
+
+export function getMemoryAccountsEncoder() {
+  return getArrayEncoder(getMemoryAccountEncoder(), {
+    size: getCompactU64Encoder(),
+  });
+}
+
+export function getMemoryAccountsDecoder() {
+  return getArrayDecoder(getMemoryAccountDecoder(), {
+    size: getCompactU64Decoder(),
+  });
+}
+
+export type MemoryDeltaAssertions = Array<MemoryDeltaAssertion>;
+export type MemoryDeltaAssertionsArgs = Array<MemoryDeltaAssertionArgs>;
+
+export function getMemoryDeltaAssertionsEncoder() {
+  return getArrayEncoder(getMemoryDeltaAssertionEncoder(), {
+    size: getCompactU64Encoder(),
+  });
+}
+
+export function getMemoryDeltaAssertionsDecoder() {
+  return getArrayDecoder(getMemoryDeltaAssertionDecoder(), {
+    size: getCompactU64Decoder(),
+  });
+}
+
+export type CompactPubkeys = Array<Address>;
+export type CompactPubkeysArgs = Array<Address>;
+
+export function getCompactPubkeysEncoder() {
+  return getArrayEncoder(getAddressEncoder(), {
+    size: getCompactU64Encoder(),
+  });
+}
+
+export function getCompactPubkeysDecoder() {
+  return getArrayDecoder(getAddressDecoder(), {
+    size: getCompactU64Decoder(),
+  });
+}
+
+export type CompactOptionPubkeys = Array<Option<Address>>;
+export type CompactOptionPubkeysArgs = Array<OptionOrNullable<Address>>;
+
+export function getCompactOptionPubkeysEncoder() {
+  return getArrayEncoder(getOptionEncoder(getAddressEncoder()), {
+    size: getCompactU64Encoder(),
+  });
+}
+
+export function getCompactOptionPubkeysDecoder() {
+  return getArrayDecoder(getOptionDecoder(getAddressDecoder()), {
+    size: getCompactU64Decoder(),
+  });
+}
+
+export type CompactKnownPrograms = Array<KnownProgram>;
+export type CompactKnownProgramsArgs = Array<KnownProgramArgs>;
+
+export function getCompactKnownProgramsEncoder() {
+  return getArrayEncoder(getKnownProgramEncoder(), {
+    size: getCompactU64Encoder(),
+  });
+}
+
+export function getCompactKnownProgramsDecoder() {
+  return getArrayDecoder(getKnownProgramDecoder(), {
+    size: getCompactU64Decoder(),
+  });
+}
+
+export type CompactPdaSeeds = Array<PdaSeed>;
+export type CompactPdaSeedsArgs = Array<PdaSeedArgs>;
+
+export function getCompactPdaSeedsEncoder() {
+  return getArrayEncoder(getPdaSeedEncoder(), {
+    size: getCompactU64Encoder(),
+  });
+}
+
+export function getCompactPdaSeedsDecoder() {
+  return getArrayDecoder(getPdaSeedDecoder(), {
+    size: getCompactU64Decoder(),
+  });
+}
+
+export type CompactDataRanges = Array<DataRange>;
+export type CompactDataRangesArgs = Array<DataRangeArgs>;
+
+export function getCompactDataRangesEncoder() {
+  return getArrayEncoder(getDataRangeEncoder(), {
+    size: getCompactU64Encoder(),
+  });
+}
+
+export function getCompactDataRangesDecoder() {
+  return getArrayDecoder(getDataRangeDecoder(), {
+    size: getCompactU64Decoder(),
+  });
+}
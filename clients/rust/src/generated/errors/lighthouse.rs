@@ -61,6 +61,12 @@ pub enum LighthouseError {
     /// 6016 - CrossProgramInvokeViolation
     #[error("CrossProgramInvokeViolation")]
     CrossProgramInvokeViolation = 0x1780,
+    /// 6017 - ArithmeticOverflow
+    #[error("ArithmeticOverflow")]
+    ArithmeticOverflow = 0x1781,
+    /// 6018 - ReturnDataMismatch
+    #[error("ReturnDataMismatch")]
+    ReturnDataMismatch = 0x1782,
 }
 
 impl solana_program::program_error::PrintProgramError for LighthouseError {
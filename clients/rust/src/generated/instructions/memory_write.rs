@@ -19,7 +19,7 @@ pub struct MemoryWrite {
     pub system_program: solana_program::pubkey::Pubkey,
     /// Payer account
     pub payer: solana_program::pubkey::Pubkey,
-    /// Memory account
+    /// Memory account, grows by at most 10 KiB per instruction
     pub memory: solana_program::pubkey::Pubkey,
     /// Account to be written to memory
     pub source_account: solana_program::pubkey::Pubkey,
@@ -145,7 +145,7 @@ impl MemoryWriteBuilder {
         self.payer = Some(payer);
         self
     }
-    /// Memory account
+    /// Memory account, grows by at most 10 KiB per instruction
     #[inline(always)]
     pub fn memory(&mut self, memory: solana_program::pubkey::Pubkey) -> &mut Self {
         self.memory = Some(memory);
@@ -226,7 +226,7 @@ pub struct MemoryWriteCpiAccounts<'a, 'b> {
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// Payer account
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
-    /// Memory account
+    /// Memory account, grows by at most 10 KiB per instruction
     pub memory: &'b solana_program::account_info::AccountInfo<'a>,
     /// Account to be written to memory
     pub source_account: &'b solana_program::account_info::AccountInfo<'a>,
@@ -242,7 +242,7 @@ pub struct MemoryWriteCpi<'a, 'b> {
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// Payer account
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
-    /// Memory account
+    /// Memory account, grows by at most 10 KiB per instruction
     pub memory: &'b solana_program::account_info::AccountInfo<'a>,
     /// Account to be written to memory
     pub source_account: &'b solana_program::account_info::AccountInfo<'a>,
@@ -411,7 +411,7 @@ impl<'a, 'b> MemoryWriteCpiBuilder<'a, 'b> {
         self.instruction.payer = Some(payer);
         self
     }
-    /// Memory account
+    /// Memory account, grows by at most 10 KiB per instruction
     #[inline(always)]
     pub fn memory(
         &mut self,
@@ -7,38 +7,58 @@
 
 pub(crate) mod r#assert_account_data;
 pub(crate) mod r#assert_account_data_multi;
+pub(crate) mod r#assert_account_data_path;
+pub(crate) mod r#assert_account_data_path_multi;
 pub(crate) mod r#assert_account_delta;
 pub(crate) mod r#assert_account_info;
 pub(crate) mod r#assert_account_info_multi;
 pub(crate) mod r#assert_bubblegum_tree_config_account;
+pub(crate) mod r#assert_memory_delta_and_close;
 pub(crate) mod r#assert_merkle_tree_account;
 pub(crate) mod r#assert_mint_account;
+pub(crate) mod r#assert_mint_account_delta;
 pub(crate) mod r#assert_mint_account_multi;
+pub(crate) mod r#assert_return_data;
 pub(crate) mod r#assert_stake_account;
 pub(crate) mod r#assert_stake_account_multi;
 pub(crate) mod r#assert_sysvar_clock;
 pub(crate) mod r#assert_token_account;
+pub(crate) mod r#assert_token_account_delta;
 pub(crate) mod r#assert_token_account_multi;
 pub(crate) mod r#assert_upgradeable_loader_account;
 pub(crate) mod r#assert_upgradeable_loader_account_multi;
 pub(crate) mod r#memory_close;
+pub(crate) mod r#memory_close_keyed;
 pub(crate) mod r#memory_write;
+pub(crate) mod r#memory_write_keyed;
+pub(crate) mod r#memory_write_multi;
+pub(crate) mod r#memory_write_multi_keyed;
 
 pub use self::r#assert_account_data::*;
 pub use self::r#assert_account_data_multi::*;
+pub use self::r#assert_account_data_path::*;
+pub use self::r#assert_account_data_path_multi::*;
 pub use self::r#assert_account_delta::*;
 pub use self::r#assert_account_info::*;
 pub use self::r#assert_account_info_multi::*;
 pub use self::r#assert_bubblegum_tree_config_account::*;
+pub use self::r#assert_memory_delta_and_close::*;
 pub use self::r#assert_merkle_tree_account::*;
 pub use self::r#assert_mint_account::*;
+pub use self::r#assert_mint_account_delta::*;
 pub use self::r#assert_mint_account_multi::*;
+pub use self::r#assert_return_data::*;
 pub use self::r#assert_stake_account::*;
 pub use self::r#assert_stake_account_multi::*;
 pub use self::r#assert_sysvar_clock::*;
 pub use self::r#assert_token_account::*;
+pub use self::r#assert_token_account_delta::*;
 pub use self::r#assert_token_account_multi::*;
 pub use self::r#assert_upgradeable_loader_account::*;
 pub use self::r#assert_upgradeable_loader_account_multi::*;
 pub use self::r#memory_close::*;
+pub use self::r#memory_close_keyed::*;
 pub use self::r#memory_write::*;
+pub use self::r#memory_write_keyed::*;
+pub use self::r#memory_write_multi::*;
+pub use self::r#memory_write_multi_keyed::*;
@@ -7,6 +7,7 @@
 
 use crate::generated::types::AccountInfoDeltaAssertion;
 use crate::generated::types::DataValueDeltaAssertion;
+use crate::generated::types::MemoryGuard;
 use crate::hooked::CompactU64;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
@@ -23,4 +24,15 @@ pub enum AccountDeltaAssertion {
         b_offset: CompactU64,
         assertion: DataValueDeltaAssertion,
     },
+    GuardedData {
+        guard: MemoryGuard,
+        a_offset: CompactU64,
+        b_offset: CompactU64,
+        assertion: DataValueDeltaAssertion,
+    },
+    GuardedAccountInfo {
+        guard: MemoryGuard,
+        a_offset: CompactU64,
+        assertion: AccountInfoDeltaAssertion,
+    },
 }
@@ -8,6 +8,10 @@
 use crate::generated::types::EquatableOperator;
 use crate::generated::types::IntegerOperator;
 use crate::generated::types::KnownProgram;
+use crate::generated::types::SetOperator;
+use crate::hooked::CompactKnownPrograms;
+use crate::hooked::CompactPdaSeeds;
+use crate::hooked::CompactPubkeys;
 use crate::hooked::CompactU64;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
@@ -57,4 +61,42 @@ pub enum AccountInfoAssertion {
         start: CompactU64,
         length: CompactU64,
     },
+    OwnerIn {
+        value: CompactPubkeys,
+        operator: SetOperator,
+    },
+    KnownOwnerIn {
+        value: CompactKnownPrograms,
+        operator: SetOperator,
+    },
+    OwnerAccountRef {
+        account_index: u8,
+        operator: EquatableOperator,
+    },
+    IsRentExempt {
+        value: bool,
+        operator: EquatableOperator,
+    },
+    Exists {
+        value: bool,
+        operator: EquatableOperator,
+    },
+    IsWallet {
+        value: bool,
+        operator: EquatableOperator,
+    },
+    LamportsAboveRentMinimum {
+        value: i128,
+        operator: IntegerOperator,
+    },
+    KeyIsDerived {
+        seeds: CompactPdaSeeds,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        program_id: Pubkey,
+        bump: Option<u8>,
+        operator: EquatableOperator,
+    },
 }
@@ -5,7 +5,9 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::AccountHashScope;
 use crate::generated::types::EquatableOperator;
+use crate::generated::types::HashAlgorithm;
 use crate::generated::types::IntegerOperator;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
@@ -28,4 +30,20 @@ pub enum AccountInfoDeltaAssertion {
         value: i128,
         operator: IntegerOperator,
     },
+    LamportsBps {
+        value: i32,
+        absolute: bool,
+        operator: IntegerOperator,
+    },
+    Executable {
+        operator: EquatableOperator,
+    },
+    Key {
+        operator: EquatableOperator,
+    },
+    Hash {
+        algorithm: HashAlgorithm,
+        scope: AccountHashScope,
+        operator: EquatableOperator,
+    },
 }
@@ -25,4 +25,14 @@ pub enum AssertionResult {
     Pubkey(Option<Pubkey>, Option<Pubkey>, u8, bool),
     Bytes(Vec<u8>, Vec<u8>, u8, bool),
     Bool(Option<bool>, Option<bool>, u8, bool),
+    Range(Option<i128>, i128, i128, u8, bool),
+    PubkeySet(Option<Pubkey>, Vec<Option<Pubkey>>, u8, bool),
+    BytesPattern(Option<u32>, u32, Vec<u8>, u8, bool),
+    I80F48(Option<i128>, Option<i128>, u8, bool),
+    U64F64(Option<u128>, Option<u128>, u8, bool),
+    Wad(Option<u128>, Option<u128>, u8, bool),
+    Ray(Option<u128>, Option<u128>, u8, bool),
+    F32(Option<u32>, Option<u32>, u8, bool),
+    F64(Option<u64>, Option<u64>, u8, bool),
+    String(Vec<u8>, Vec<u8>, u8, bool),
 }
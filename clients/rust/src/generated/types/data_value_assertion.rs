@@ -5,14 +5,21 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::BytesOperator;
 use crate::generated::types::EquatableOperator;
 use crate::generated::types::IntegerOperator;
+use crate::generated::types::RangeOperator;
+use crate::generated::types::SetOperator;
+use crate::generated::types::StringLayout;
+use crate::generated::types::StringOperator;
 use crate::hooked::CompactBytes;
+use crate::hooked::CompactPubkeys;
+use crate::hooked::CompactU64;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::pubkey::Pubkey;
 
-#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DataValueAssertion {
     Bool {
@@ -71,4 +78,110 @@ pub enum DataValueAssertion {
         value: Pubkey,
         operator: EquatableOperator,
     },
+    PubkeyIn {
+        value: CompactPubkeys,
+        operator: SetOperator,
+    },
+    BytesMasked {
+        value: CompactBytes,
+        mask: CompactBytes,
+        operator: EquatableOperator,
+    },
+    BytesPattern {
+        value: CompactBytes,
+        length: Option<CompactU64>,
+        operator: BytesOperator,
+    },
+    I80F48 {
+        value: i128,
+        operator: IntegerOperator,
+    },
+    U64F64 {
+        value: u128,
+        operator: IntegerOperator,
+    },
+    Wad {
+        value: u128,
+        operator: IntegerOperator,
+    },
+    Ray {
+        value: u128,
+        operator: IntegerOperator,
+    },
+    F32 {
+        value: f32,
+        operator: IntegerOperator,
+    },
+    F64 {
+        value: f64,
+        operator: IntegerOperator,
+    },
+    U16BE {
+        value: u16,
+        operator: IntegerOperator,
+    },
+    I16BE {
+        value: i16,
+        operator: IntegerOperator,
+    },
+    U32BE {
+        value: u32,
+        operator: IntegerOperator,
+    },
+    I32BE {
+        value: i32,
+        operator: IntegerOperator,
+    },
+    U64BE {
+        value: u64,
+        operator: IntegerOperator,
+    },
+    I64BE {
+        value: i64,
+        operator: IntegerOperator,
+    },
+    U128BE {
+        value: u128,
+        operator: IntegerOperator,
+    },
+    I128BE {
+        value: i128,
+        operator: IntegerOperator,
+    },
+    UnsignedInt {
+        value: u128,
+        byte_width: u8,
+        big_endian: bool,
+        operator: IntegerOperator,
+    },
+    BitField {
+        value: u128,
+        byte_width: u8,
+        big_endian: bool,
+        shift: u8,
+        bit_width: u8,
+        operator: IntegerOperator,
+    },
+    String {
+        value: CompactBytes,
+        layout: StringLayout,
+        operator: StringOperator,
+    },
+    StringLength {
+        value: u32,
+        layout: StringLayout,
+        operator: IntegerOperator,
+    },
+    PubkeyAccountRef {
+        account_index: u8,
+        operator: EquatableOperator,
+    },
+    IntegerRange {
+        lower: i128,
+        upper: i128,
+        byte_width: u8,
+        signed: bool,
+        big_endian: bool,
+        operator: RangeOperator,
+    },
 }
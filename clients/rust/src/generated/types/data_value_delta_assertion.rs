@@ -49,4 +49,55 @@ pub enum DataValueDeltaAssertion {
         length: u16,
         operator: EquatableOperator,
     },
+    U8Bps {
+        value: i32,
+        absolute: bool,
+        operator: IntegerOperator,
+    },
+    I8Bps {
+        value: i32,
+        absolute: bool,
+        operator: IntegerOperator,
+    },
+    U16Bps {
+        value: i32,
+        absolute: bool,
+        operator: IntegerOperator,
+    },
+    I16Bps {
+        value: i32,
+        absolute: bool,
+        operator: IntegerOperator,
+    },
+    U32Bps {
+        value: i32,
+        absolute: bool,
+        operator: IntegerOperator,
+    },
+    I32Bps {
+        value: i32,
+        absolute: bool,
+        operator: IntegerOperator,
+    },
+    U64Bps {
+        value: i32,
+        absolute: bool,
+        operator: IntegerOperator,
+    },
+    I64Bps {
+        value: i32,
+        absolute: bool,
+        operator: IntegerOperator,
+    },
+    U128 {
+        value: i128,
+        operator: IntegerOperator,
+    },
+    I128 {
+        value: i128,
+        operator: IntegerOperator,
+    },
+    Pubkey {
+        operator: EquatableOperator,
+    },
 }
@@ -7,20 +7,8 @@
 
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
-use num_derive::FromPrimitive;
 
-#[derive(
-    BorshSerialize,
-    BorshDeserialize,
-    Clone,
-    Debug,
-    Eq,
-    PartialEq,
-    Copy,
-    PartialOrd,
-    Hash,
-    FromPrimitive,
-)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum IntegerOperator {
     Equal,
@@ -31,4 +19,8 @@ pub enum IntegerOperator {
     LessThanOrEqual,
     Contains,
     DoesNotContain,
+    Between { lower: i128, upper: i128 },
+    NotBetween { lower: i128, upper: i128 },
+    BetweenExclusive { lower: i128, upper: i128 },
+    NotBetweenExclusive { lower: i128, upper: i128 },
 }
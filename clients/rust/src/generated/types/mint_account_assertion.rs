@@ -7,6 +7,8 @@
 
 use crate::generated::types::EquatableOperator;
 use crate::generated::types::IntegerOperator;
+use crate::generated::types::SetOperator;
+use crate::hooked::CompactOptionPubkeys;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::pubkey::Pubkey;
@@ -34,4 +36,16 @@ pub enum MintAccountAssertion {
         value: Option<Pubkey>,
         operator: EquatableOperator,
     },
+    MintAuthorityIn {
+        value: CompactOptionPubkeys,
+        operator: SetOperator,
+    },
+    MintAuthorityAccountRef {
+        account_index: u8,
+        operator: EquatableOperator,
+    },
+    FreezeAuthorityAccountRef {
+        account_index: u8,
+        operator: EquatableOperator,
+    },
 }
@@ -6,59 +6,113 @@
 //!
 
 pub(crate) mod r#account_delta_assertion;
+pub(crate) mod r#account_hash_scope;
 pub(crate) mod r#account_info_assertion;
 pub(crate) mod r#account_info_delta_assertion;
 pub(crate) mod r#account_info_field;
+pub(crate) mod r#arithmetic_mode;
+pub(crate) mod r#arithmetic_source;
 pub(crate) mod r#assertion_result;
 pub(crate) mod r#bubblegum_tree_config_assertion;
+pub(crate) mod r#bytes_operator;
 pub(crate) mod r#clock_field;
+pub(crate) mod r#data_range;
 pub(crate) mod r#data_value;
 pub(crate) mod r#data_value_assertion;
 pub(crate) mod r#data_value_delta_assertion;
+pub(crate) mod r#epoch_schedule_field;
 pub(crate) mod r#equatable_operator;
+pub(crate) mod r#hash_algorithm;
 pub(crate) mod r#integer_operator;
+pub(crate) mod r#integer_type;
 pub(crate) mod r#known_program;
 pub(crate) mod r#log_level;
+pub(crate) mod r#memory_account;
+pub(crate) mod r#memory_delta_assertion;
+pub(crate) mod r#memory_guard;
+pub(crate) mod r#memory_header;
+pub(crate) mod r#memory_key;
+pub(crate) mod r#memory_tag;
+pub(crate) mod r#memory_write_entry;
 pub(crate) mod r#merkle_tree_assertion;
 pub(crate) mod r#meta_assertion;
 pub(crate) mod r#mint_account_assertion;
+pub(crate) mod r#mint_account_delta_assertion;
+pub(crate) mod r#offset_path;
+pub(crate) mod r#offset_step;
+pub(crate) mod r#pda_seed;
+pub(crate) mod r#range_operator;
+pub(crate) mod r#set_operator;
 pub(crate) mod r#stake_account_assertion;
+pub(crate) mod r#stake_activation_assertion;
+pub(crate) mod r#stake_activation_field;
 pub(crate) mod r#stake_assertion;
 pub(crate) mod r#stake_state_type;
+pub(crate) mod r#string_layout;
+pub(crate) mod r#string_operator;
 pub(crate) mod r#sysvar_clock_assertion;
 pub(crate) mod r#token_account_assertion;
+pub(crate) mod r#token_account_delta_assertion;
 pub(crate) mod r#upgradable_buffer_assertion;
 pub(crate) mod r#upgradeable_loader_state_assertion;
 pub(crate) mod r#upgradeable_loader_state_type;
 pub(crate) mod r#upgradeable_program_assertion;
 pub(crate) mod r#upgradeable_program_data_assertion;
 pub(crate) mod r#write_type;
+pub(crate) mod r#write_type_tag;
 
 pub use self::r#account_delta_assertion::*;
+pub use self::r#account_hash_scope::*;
 pub use self::r#account_info_assertion::*;
 pub use self::r#account_info_delta_assertion::*;
 pub use self::r#account_info_field::*;
+pub use self::r#arithmetic_mode::*;
+pub use self::r#arithmetic_source::*;
 pub use self::r#assertion_result::*;
 pub use self::r#bubblegum_tree_config_assertion::*;
+pub use self::r#bytes_operator::*;
 pub use self::r#clock_field::*;
+pub use self::r#data_range::*;
 pub use self::r#data_value::*;
 pub use self::r#data_value_assertion::*;
 pub use self::r#data_value_delta_assertion::*;
+pub use self::r#epoch_schedule_field::*;
 pub use self::r#equatable_operator::*;
+pub use self::r#hash_algorithm::*;
 pub use self::r#integer_operator::*;
+pub use self::r#integer_type::*;
 pub use self::r#known_program::*;
 pub use self::r#log_level::*;
+pub use self::r#memory_account::*;
+pub use self::r#memory_delta_assertion::*;
+pub use self::r#memory_guard::*;
+pub use self::r#memory_header::*;
+pub use self::r#memory_key::*;
+pub use self::r#memory_tag::*;
+pub use self::r#memory_write_entry::*;
 pub use self::r#merkle_tree_assertion::*;
 pub use self::r#meta_assertion::*;
 pub use self::r#mint_account_assertion::*;
+pub use self::r#mint_account_delta_assertion::*;
+pub use self::r#offset_path::*;
+pub use self::r#offset_step::*;
+pub use self::r#pda_seed::*;
+pub use self::r#range_operator::*;
+pub use self::r#set_operator::*;
 pub use self::r#stake_account_assertion::*;
+pub use self::r#stake_activation_assertion::*;
+pub use self::r#stake_activation_field::*;
 pub use self::r#stake_assertion::*;
 pub use self::r#stake_state_type::*;
+pub use self::r#string_layout::*;
+pub use self::r#string_operator::*;
 pub use self::r#sysvar_clock_assertion::*;
 pub use self::r#token_account_assertion::*;
+pub use self::r#token_account_delta_assertion::*;
 pub use self::r#upgradable_buffer_assertion::*;
 pub use self::r#upgradeable_loader_state_assertion::*;
 pub use self::r#upgradeable_loader_state_type::*;
 pub use self::r#upgradeable_program_assertion::*;
 pub use self::r#upgradeable_program_data_assertion::*;
 pub use self::r#write_type::*;
+pub use self::r#write_type_tag::*;
@@ -8,6 +8,7 @@
 use crate::generated::types::EquatableOperator;
 use crate::generated::types::IntegerOperator;
 use crate::generated::types::MetaAssertion;
+use crate::generated::types::StakeActivationAssertion;
 use crate::generated::types::StakeAssertion;
 use crate::generated::types::StakeStateType;
 use borsh::BorshDeserialize;
@@ -26,4 +27,5 @@ pub enum StakeAccountAssertion {
         value: u8,
         operator: IntegerOperator,
     },
+    StakeActivationAssertion(StakeActivationAssertion),
 }
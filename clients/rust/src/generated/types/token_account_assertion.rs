@@ -7,6 +7,8 @@
 
 use crate::generated::types::EquatableOperator;
 use crate::generated::types::IntegerOperator;
+use crate::generated::types::SetOperator;
+use crate::hooked::CompactOptionPubkeys;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::pubkey::Pubkey;
@@ -55,4 +57,29 @@ pub enum TokenAccountAssertion {
         operator: EquatableOperator,
     },
     TokenAccountOwnerIsDerived,
+    DelegateIn {
+        value: CompactOptionPubkeys,
+        operator: SetOperator,
+    },
+    MintAccountRef {
+        account_index: u8,
+        operator: EquatableOperator,
+    },
+    OwnerAccountRef {
+        account_index: u8,
+        operator: EquatableOperator,
+    },
+    DelegateAccountRef {
+        account_index: u8,
+        operator: EquatableOperator,
+    },
+    CloseAuthorityAccountRef {
+        account_index: u8,
+        operator: EquatableOperator,
+    },
+    UiAmount {
+        mantissa: u64,
+        exponent: i8,
+        operator: IntegerOperator,
+    },
 }
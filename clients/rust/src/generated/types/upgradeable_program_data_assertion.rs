@@ -7,6 +7,7 @@
 
 use crate::generated::types::EquatableOperator;
 use crate::generated::types::IntegerOperator;
+use crate::hooked::CompactU64;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::pubkey::Pubkey;
@@ -22,4 +23,10 @@ pub enum UpgradeableProgramDataAssertion {
         value: u64,
         operator: IntegerOperator,
     },
+    BytecodeHash {
+        expected_hash: [u8; 32],
+        previous_hash: Option<[u8; 32]>,
+        offset: CompactU64,
+        length: Option<CompactU64>,
+    },
 }
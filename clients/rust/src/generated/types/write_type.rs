@@ -5,17 +5,65 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::AccountHashScope;
 use crate::generated::types::AccountInfoField;
+use crate::generated::types::ArithmeticMode;
+use crate::generated::types::ArithmeticSource;
 use crate::generated::types::ClockField;
 use crate::generated::types::DataValue;
+use crate::generated::types::EpochScheduleField;
+use crate::generated::types::HashAlgorithm;
+use crate::generated::types::IntegerType;
+use crate::generated::types::StakeActivationField;
+use crate::hooked::CompactDataRanges;
+use crate::hooked::CompactU64;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WriteType {
-    AccountData { offset: u16, data_length: u16 },
+    AccountData {
+        offset: CompactU64,
+        data_length: CompactU64,
+    },
     AccountInfoField(AccountInfoField),
     DataValue(DataValue),
     Clock(ClockField),
+    AccountHash {
+        algorithm: HashAlgorithm,
+        scope: AccountHashScope,
+    },
+    AccountDataRanges {
+        ranges: CompactDataRanges,
+    },
+    Arithmetic {
+        mode: ArithmeticMode,
+        integer_type: IntegerType,
+        source: ArithmeticSource,
+    },
+    MemoryHeader {
+        nonce: u64,
+    },
+    TokenAccountSnapshot,
+    MintAccountSnapshot,
+    ReturnData {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        program_id: Pubkey,
+        offset: CompactU64,
+        length: CompactU64,
+    },
+    RentMinimumBalance {
+        data_length: CompactU64,
+    },
+    EpochSchedule(EpochScheduleField),
+    InstructionIndex,
+    StakeActivation(StakeActivationField),
+    Resize {
+        space: CompactU64,
+    },
 }
@@ -1,24 +1,88 @@
-use crate::types::DataValueAssertion;
 use crate::types::{
-    AccountInfoAssertion, MintAccountAssertion, StakeAccountAssertion, TokenAccountAssertion,
-    UpgradeableLoaderStateAssertion,
+    AccountInfoAssertion, IntegerOperator, KnownProgram, MintAccountAssertion, RangeOperator,
+    StakeAccountAssertion, TokenAccountAssertion, UpgradeableLoaderStateAssertion,
+};
+use crate::types::{
+    DataRange, DataValueAssertion, MemoryAccount, MemoryDeltaAssertion, MemoryWriteEntry,
+    OffsetPath, OffsetStep, PdaSeed,
 };
 
 use borsh::{BorshDeserialize, BorshSerialize};
 pub use lighthouse_common::CompactU64;
 pub use lighthouse_common::LEB128Vec;
 
-#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AccountDataAssertion {
     pub offset: CompactU64,
     pub assertion: DataValueAssertion,
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountDataPathAssertion {
+    pub path: OffsetPath,
+    pub assertion: DataValueAssertion,
+}
+
 pub type AccountInfoAssertions = LEB128Vec<AccountInfoAssertion>;
 pub type AccountDataAssertions = LEB128Vec<AccountDataAssertion>;
+pub type AccountDataPathAssertions = LEB128Vec<AccountDataPathAssertion>;
 pub type MintAccountAssertions = LEB128Vec<MintAccountAssertion>;
 pub type TokenAccountAssertions = LEB128Vec<TokenAccountAssertion>;
 pub type StakeAccountAssertions = LEB128Vec<StakeAccountAssertion>;
 pub type UpgradeableLoaderStateAssertions = LEB128Vec<UpgradeableLoaderStateAssertion>;
+pub type MemoryWriteEntries = LEB128Vec<MemoryWriteEntry>;
+pub type MemoryAccounts = LEB128Vec<MemoryAccount>;
+pub type MemoryDeltaAssertions = LEB128Vec<MemoryDeltaAssertion>;
 pub type CompactBytes = LEB128Vec<u8>;
+pub type OffsetSteps = LEB128Vec<OffsetStep>;
+pub type CompactPubkeys = LEB128Vec<solana_program::pubkey::Pubkey>;
+pub type CompactOptionPubkeys = LEB128Vec<Option<solana_program::pubkey::Pubkey>>;
+pub type CompactKnownPrograms = LEB128Vec<KnownProgram>;
+pub type CompactPdaSeeds = LEB128Vec<PdaSeed>;
+pub type CompactDataRanges = LEB128Vec<DataRange>;
+
+/// Operator code logged in `AssertionResult` payloads, matching the program's encoding.
+impl From<&IntegerOperator> for u8 {
+    fn from(operator: &IntegerOperator) -> Self {
+        match operator {
+            IntegerOperator::Equal => 0,
+            IntegerOperator::NotEqual => 1,
+            IntegerOperator::GreaterThan => 2,
+            IntegerOperator::LessThan => 3,
+            IntegerOperator::GreaterThanOrEqual => 4,
+            IntegerOperator::LessThanOrEqual => 5,
+            IntegerOperator::Contains => 6,
+            IntegerOperator::DoesNotContain => 7,
+            IntegerOperator::Between { .. } => 8,
+            IntegerOperator::NotBetween { .. } => 9,
+            IntegerOperator::BetweenExclusive { .. } => 10,
+            IntegerOperator::NotBetweenExclusive { .. } => 11,
+        }
+    }
+}
+
+impl From<IntegerOperator> for u8 {
+    fn from(operator: IntegerOperator) -> Self {
+        u8::from(&operator)
+    }
+}
+
+/// Range operators are logged with the codes of the matching `IntegerOperator` variants.
+impl From<&RangeOperator> for u8 {
+    fn from(operator: &RangeOperator) -> Self {
+        match operator {
+            RangeOperator::Between => 8,
+            RangeOperator::NotBetween => 9,
+            RangeOperator::BetweenExclusive => 10,
+            RangeOperator::NotBetweenExclusive => 11,
+        }
+    }
+}
+
+impl From<RangeOperator> for u8 {
+    fn from(operator: RangeOperator) -> Self {
+        u8::from(&operator)
+    }
+}
@@ -9,31 +9,40 @@ pub use generated::programs::LIGHTHOUSE_ID as ID;
 use solana_program::pubkey::{Pubkey, PubkeyError};
 
 pub use generated::types;
+pub use hooked::{AccountDataAssertion, AccountDataPathAssertion};
 pub use lighthouse_common::{CompactU64, LEB128Vec};
 
 pub mod instructions {
     pub use crate::generated::instructions::{
-        AssertAccountDataBuilder, AssertAccountDeltaBuilder, AssertAccountInfoBuilder,
-        AssertAccountInfoMultiBuilder, AssertBubblegumTreeConfigAccountBuilder,
-        AssertMerkleTreeAccountBuilder, AssertMintAccountBuilder, AssertMintAccountMultiBuilder,
-        AssertStakeAccountBuilder, AssertStakeAccountMultiBuilder, AssertSysvarClockBuilder,
-        AssertTokenAccountBuilder, AssertTokenAccountMultiBuilder,
+        AssertAccountDataBuilder, AssertAccountDataPathBuilder, AssertAccountDataPathMultiBuilder,
+        AssertAccountDeltaBuilder, AssertAccountInfoBuilder, AssertAccountInfoMultiBuilder,
+        AssertBubblegumTreeConfigAccountBuilder, AssertMemoryDeltaAndCloseBuilder,
+        AssertMerkleTreeAccountBuilder, AssertMintAccountBuilder, AssertMintAccountDeltaBuilder,
+        AssertMintAccountMultiBuilder, AssertReturnDataBuilder, AssertStakeAccountBuilder,
+        AssertStakeAccountMultiBuilder, AssertSysvarClockBuilder, AssertTokenAccountBuilder,
+        AssertTokenAccountDeltaBuilder, AssertTokenAccountMultiBuilder,
         AssertUpgradeableLoaderAccountBuilder, AssertUpgradeableLoaderAccountMultiBuilder,
-        MemoryCloseBuilder, MemoryWriteBuilder,
+        MemoryCloseBuilder, MemoryCloseKeyedBuilder, MemoryWriteBuilder, MemoryWriteKeyedBuilder,
+        MemoryWriteMultiBuilder, MemoryWriteMultiKeyedBuilder,
     };
 }
 
 #[cfg(feature = "cpi")]
 pub mod cpi {
     pub use crate::generated::instructions::{
-        AssertAccountDataCpiBuilder, AssertAccountDeltaCpiBuilder, AssertAccountInfoCpiBuilder,
-        AssertAccountInfoMultiCpiBuilder, AssertBubblegumTreeConfigAccountCpiBuilder,
+        AssertAccountDataCpiBuilder, AssertAccountDataPathCpiBuilder,
+        AssertAccountDataPathMultiCpiBuilder, AssertAccountDeltaCpiBuilder,
+        AssertAccountInfoCpiBuilder, AssertAccountInfoMultiCpiBuilder,
+        AssertBubblegumTreeConfigAccountCpiBuilder, AssertMemoryDeltaAndCloseCpiBuilder,
         AssertMerkleTreeAccountCpiBuilder, AssertMintAccountCpiBuilder,
-        AssertMintAccountMultiCpiBuilder, AssertStakeAccountCpiBuilder,
+        AssertMintAccountDeltaCpiBuilder, AssertMintAccountMultiCpiBuilder,
+        AssertReturnDataCpiBuilder, AssertStakeAccountCpiBuilder,
         AssertStakeAccountMultiCpiBuilder, AssertSysvarClockCpiBuilder,
-        AssertTokenAccountCpiBuilder, AssertTokenAccountMultiCpiBuilder,
-        AssertUpgradeableLoaderAccountCpiBuilder, AssertUpgradeableLoaderAccountMultiCpiBuilder,
-        MemoryCloseCpiBuilder, MemoryWriteCpiBuilder,
+        AssertTokenAccountCpiBuilder, AssertTokenAccountDeltaCpiBuilder,
+        AssertTokenAccountMultiCpiBuilder, AssertUpgradeableLoaderAccountCpiBuilder,
+        AssertUpgradeableLoaderAccountMultiCpiBuilder, MemoryCloseCpiBuilder,
+        MemoryCloseKeyedCpiBuilder, MemoryWriteCpiBuilder, MemoryWriteKeyedCpiBuilder,
+        MemoryWriteMultiCpiBuilder, MemoryWriteMultiKeyedCpiBuilder,
     };
 }
 
@@ -48,6 +57,21 @@ pub fn find_memory_pda(payer: Pubkey, memory_id: u8) -> (solana_program::pubkey:
     )
 }
 
+pub fn find_memory_pda_keyed(
+    payer: Pubkey,
+    namespace: Option<Pubkey>,
+    memory_id: u64,
+) -> (solana_program::pubkey::Pubkey, u8) {
+    let memory_id = memory_id.to_le_bytes();
+    let mut seeds = vec!["memory".as_bytes(), payer.as_ref()];
+    if let Some(namespace) = namespace.as_ref() {
+        seeds.push(namespace.as_ref());
+    }
+    seeds.push(&memory_id);
+
+    solana_program::pubkey::Pubkey::find_program_address(&seeds, &crate::ID)
+}
+
 pub fn find_memory_pda_bump_iterate(
     payer: Pubkey,
     memory_id: u8,
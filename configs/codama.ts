@@ -48,7 +48,7 @@ codama.update(
   })
 );
 
-// Default memoryId to 0 for MemoryWrite instruction.
+// Default memoryId to 0 for MemoryWrite and MemoryWriteMulti instructions.
 codama.update(
   updateInstructionsVisitor({
     memoryWrite: {
@@ -58,6 +58,13 @@ codama.update(
         },
       },
     },
+    memoryWriteMulti: {
+      arguments: {
+        memoryId: {
+          defaultValue: numberValueNode(0),
+        },
+      },
+    },
   })
 );
 
@@ -76,8 +83,8 @@ codama.update(
   ])
 );
 
-// Delete testAccountV1 type. This is used in testing but picked up by shank.
-codama.update(deleteNodesVisitor(['testAccountV1']));
+// Delete testAccountV1 and testAccount types. These are used in testing but picked up by shank.
+codama.update(deleteNodesVisitor(['testAccountV1', 'testAccount']));
 
 // Link overrides for hooked types. The hooked types are then defined in the hooked.(ts/rs) file.
 const linkOverrides = {
@@ -88,12 +95,34 @@ const linkOverrides = {
     tokenAccountAssertions: 'hooked',
     stakeAccountAssertions: 'hooked',
     upgradeableLoaderStateAssertions: 'hooked',
+    accountDataPathAssertions: 'hooked',
+    memoryWriteEntries: 'hooked',
+    memoryAccounts: 'hooked',
+    memoryDeltaAssertions: 'hooked',
+    offsetSteps: 'hooked',
     compactU64: 'hooked',
     bytes: 'hooked',
     compactBytes: 'hooked',
+    compactPubkeys: 'hooked',
+    compactOptionPubkeys: 'hooked',
+    compactKnownPrograms: 'hooked',
+    compactPdaSeeds: 'hooked',
+    compactDataRanges: 'hooked',
   },
 };
 
+// Floating point values do not implement Eq, so types and instructions that carry them
+// (directly or through a hooked type) derive every default trait except Eq.
+const floatTraits = [
+  'borsh::BorshSerialize',
+  'borsh::BorshDeserialize',
+  'serde::Serialize',
+  'serde::Deserialize',
+  'Clone',
+  'Debug',
+  'PartialEq',
+];
+
 // Render JavaScript SDK (@solana/kit v2.x.x).
 const previewJsDir = path.join(clientDir, 'kit-js', 'src', 'generated');
 const previewPrettier = require(
@@ -124,5 +153,15 @@ codama.accept(
     formatCode: true,
     crateFolder: crateDir,
     linkOverrides,
+    traitOptions: {
+      overrides: {
+        dataValueAssertion: floatTraits,
+        assertAccountData: floatTraits,
+        assertAccountDataMulti: floatTraits,
+        assertAccountDataPath: floatTraits,
+        assertAccountDataPathMulti: floatTraits,
+        assertReturnData: floatTraits,
+      },
+    },
   })
 );
@@ -66,6 +66,12 @@ pub enum LighthouseError {
     // Guards
     #[error("CrossProgramInvokeViolation")]
     CrossProgramInvokeViolation = 6016,
+
+    // Memory write errors
+    #[error("ArithmeticOverflow")]
+    ArithmeticOverflow = 6017,
+    #[error("ReturnDataMismatch")]
+    ReturnDataMismatch = 6018,
 }
 
 impl LighthouseError {
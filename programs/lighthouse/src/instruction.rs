@@ -1,11 +1,12 @@
 use crate::types::{
     assert::{
-        AccountDataAssertion, AccountDeltaAssertion, AccountInfoAssertion,
-        BubblegumTreeConfigAssertion, LogLevel, MerkleTreeAssertion, MintAccountAssertion,
+        AccountDataAssertion, AccountDataPathAssertion, AccountDeltaAssertion,
+        AccountInfoAssertion, BubblegumTreeConfigAssertion, LogLevel, MemoryDeltaAssertion,
+        MerkleTreeAssertion, MintAccountAssertion, MintAccountDeltaAssertion, ReturnDataAssertion,
         StakeAccountAssertion, SysvarClockAssertion, TokenAccountAssertion,
-        UpgradeableLoaderStateAssertion,
+        TokenAccountDeltaAssertion, UpgradeableLoaderStateAssertion,
     },
-    write::WriteType,
+    write::{MemoryAccount, MemoryKey, MemoryWriteEntry, WriteType},
 };
 use borsh::{BorshDeserialize, BorshSerialize};
 use lighthouse_common::{CompactU64, LEB128Vec};
@@ -15,10 +16,14 @@ use shank::ShankInstruction;
 // The encoding/decoding is handled custom in the sdks
 type AccountInfoAssertions = LEB128Vec<AccountInfoAssertion>;
 type AccountDataAssertions = LEB128Vec<AccountDataAssertion>;
+type AccountDataPathAssertions = LEB128Vec<AccountDataPathAssertion>;
 type MintAccountAssertions = LEB128Vec<MintAccountAssertion>;
 type TokenAccountAssertions = LEB128Vec<TokenAccountAssertion>;
 type StakeAccountAssertions = LEB128Vec<StakeAccountAssertion>;
 type UpgradeableLoaderStateAssertions = LEB128Vec<UpgradeableLoaderStateAssertion>;
+type MemoryWriteEntries = LEB128Vec<MemoryWriteEntry>;
+type MemoryAccounts = LEB128Vec<MemoryAccount>;
+type MemoryDeltaAssertions = LEB128Vec<MemoryDeltaAssertion>;
 
 #[derive(BorshSerialize, BorshDeserialize, ShankInstruction)]
 #[rustfmt::skip]
@@ -26,7 +31,7 @@ pub(crate) enum LighthouseInstruction {
     #[account(0, name = "program_id", desc = "Lighthouse program")]
     #[account(1, name = "system_program", desc = "System program")]
     #[account(2, name = "payer", desc = "Payer account", signer, writable)]
-    #[account(3, name = "memory", desc = "Memory account", writable)]
+    #[account(3, name = "memory", desc = "Memory account, grows by at most 10 KiB per instruction", writable)]
     #[account(4, name = "source_account", desc = "Account to be written to memory")]
     MemoryWrite { 
         memory_id: u8,
@@ -91,6 +96,76 @@ pub(crate) enum LighthouseInstruction {
 
     #[account(0, name = "target_account", desc = "Target mpl-bubblegum tree config account to be asserted")]
     AssertBubblegumTreeConfigAccount { log_level: LogLevel, assertion: BubblegumTreeConfigAssertion },
+
+    #[account(0, name = "target_account", desc = "Target account to be asserted")]
+    AssertAccountDataPath { log_level: LogLevel, assertion: AccountDataPathAssertion },
+
+    #[account(0, name = "target_account", desc = "Target account to be asserted")]
+    AssertAccountDataPathMulti { log_level: LogLevel, assertions: AccountDataPathAssertions },
+
+    #[account(0, name = "program_id", desc = "Lighthouse program")]
+    #[account(1, name = "system_program", desc = "System program")]
+    #[account(2, name = "payer", desc = "Payer account", signer, writable)]
+    #[account(3, name = "memory", desc = "Memory account, grows by at most 10 KiB per instruction", writable)]
+    MemoryWriteMulti {
+        memory_id: u8,
+        memory_bump: u8,
+        writes: MemoryWriteEntries,
+    },
+
+    #[account(0, name = "program_id", desc = "Lighthouse program")]
+    #[account(1, name = "payer", desc = "Payer account", signer, writable)]
+    AssertMemoryDeltaAndClose {
+        log_level: LogLevel,
+        memories: MemoryAccounts,
+        assertions: MemoryDeltaAssertions,
+    },
+
+    #[account(0, name = "account_a", desc = "Memory account holding the token account snapshot")]
+    #[account(1, name = "account_b", desc = "Token account where the delta is calculated to")]
+    AssertTokenAccountDelta {
+        log_level: LogLevel,
+        snapshot_offset: CompactU64,
+        assertion: TokenAccountDeltaAssertion,
+    },
+
+    #[account(0, name = "account_a", desc = "Memory account holding the mint account snapshot")]
+    #[account(1, name = "account_b", desc = "Mint account where the delta is calculated to")]
+    AssertMintAccountDelta {
+        log_level: LogLevel,
+        snapshot_offset: CompactU64,
+        assertion: MintAccountDeltaAssertion,
+    },
+
+    #[account(0, name = "program_id", desc = "Lighthouse program")]
+    #[account(1, name = "system_program", desc = "System program")]
+    #[account(2, name = "payer", desc = "Payer account", signer, writable)]
+    #[account(3, name = "memory", desc = "Memory account, grows by at most 10 KiB per instruction", writable)]
+    #[account(4, name = "source_account", desc = "Account to be written to memory")]
+    MemoryWriteKeyed {
+        memory_key: MemoryKey,
+        memory_bump: u8,
+        write_offset: CompactU64,
+        write_type: WriteType,
+    },
+
+    #[account(0, name = "program_id", desc = "Lighthouse program")]
+    #[account(1, name = "system_program", desc = "System program")]
+    #[account(2, name = "payer", desc = "Payer account", signer, writable)]
+    #[account(3, name = "memory", desc = "Memory account, grows by at most 10 KiB per instruction", writable)]
+    MemoryWriteMultiKeyed {
+        memory_key: MemoryKey,
+        memory_bump: u8,
+        writes: MemoryWriteEntries,
+    },
+
+    #[account(0, name = "program_id", desc = "Lighthouse program")]
+    #[account(1, name = "payer", desc = "Payer account", signer, writable)]
+    #[account(2, name = "memory", desc = "Memory account", writable)]
+    MemoryCloseKeyed { memory_key: MemoryKey, memory_bump: u8 },
+
+    // No accounts
+    AssertReturnData { log_level: LogLevel, assertion: ReturnDataAssertion },
 }
 
 impl LighthouseInstruction {
@@ -120,6 +195,18 @@ impl LighthouseInstruction {
                 "AssertBubblegumTreeConfigAccount"
             }
             LighthouseInstruction::AssertMerkleTreeAccount { .. } => "AssertMerkleTreeAccount",
+            LighthouseInstruction::AssertAccountDataPath { .. } => "AssertAccountDataPath",
+            LighthouseInstruction::AssertAccountDataPathMulti { .. } => {
+                "AssertAccountDataPathMulti"
+            }
+            LighthouseInstruction::MemoryWriteMulti { .. } => "MemoryWriteMulti",
+            LighthouseInstruction::AssertMemoryDeltaAndClose { .. } => "AssertMemoryDeltaAndClose",
+            LighthouseInstruction::AssertTokenAccountDelta { .. } => "AssertTokenAccountDelta",
+            LighthouseInstruction::AssertMintAccountDelta { .. } => "AssertMintAccountDelta",
+            LighthouseInstruction::MemoryWriteKeyed { .. } => "MemoryWriteKeyed",
+            LighthouseInstruction::MemoryWriteMultiKeyed { .. } => "MemoryWriteMultiKeyed",
+            LighthouseInstruction::MemoryCloseKeyed { .. } => "MemoryCloseKeyed",
+            LighthouseInstruction::AssertReturnData { .. } => "AssertReturnData",
         }
     }
 
@@ -145,6 +232,16 @@ impl LighthouseInstruction {
             LighthouseInstruction::AssertBubblegumTreeConfigAccount { log_level, .. } => *log_level,
             LighthouseInstruction::AssertSysvarClock { log_level, .. } => *log_level,
             LighthouseInstruction::AssertMerkleTreeAccount { log_level, .. } => *log_level,
+            LighthouseInstruction::AssertAccountDataPath { log_level, .. } => *log_level,
+            LighthouseInstruction::AssertAccountDataPathMulti { log_level, .. } => *log_level,
+            LighthouseInstruction::MemoryWriteMulti { .. } => LogLevel::Silent,
+            LighthouseInstruction::AssertMemoryDeltaAndClose { log_level, .. } => *log_level,
+            LighthouseInstruction::AssertTokenAccountDelta { log_level, .. } => *log_level,
+            LighthouseInstruction::AssertMintAccountDelta { log_level, .. } => *log_level,
+            LighthouseInstruction::MemoryWriteKeyed { .. } => LogLevel::Silent,
+            LighthouseInstruction::MemoryWriteMultiKeyed { .. } => LogLevel::Silent,
+            LighthouseInstruction::MemoryCloseKeyed { .. } => LogLevel::Silent,
+            LighthouseInstruction::AssertReturnData { log_level, .. } => *log_level,
         }
     }
 }
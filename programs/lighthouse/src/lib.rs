@@ -28,6 +28,8 @@ pub mod lighthouse {
     use crate::processor;
     use crate::processor::*;
     use crate::types::assert::LogLevel;
+    use crate::types::write::WriteTypeTag;
+    use crate::validation::MemoryId;
     use crate::{error::LighthouseError, instruction::LighthouseInstruction};
     use borsh::BorshDeserialize;
     use solana_program::{
@@ -61,10 +63,9 @@ pub mod lighthouse {
 
                 let ctx = MemoryWriteContext::load(
                     &mut accounts.iter(),
-                    memory_id,
-                    write_offset,
+                    MemoryId::Index(memory_id),
                     memory_bump,
-                    &write_type,
+                    write_type.required_space(write_offset),
                 )?;
                 processor::memory_write(&ctx, write_offset, &write_type)?;
             }
@@ -72,7 +73,11 @@ pub mod lighthouse {
                 memory_id,
                 memory_bump,
             } => {
-                let ctx = MemoryCloseContext::load(&mut accounts.iter(), memory_id, memory_bump)?;
+                let ctx = MemoryCloseContext::load(
+                    &mut accounts.iter(),
+                    MemoryId::Index(memory_id),
+                    memory_bump,
+                )?;
                 processor::memory_close(&ctx)?;
             }
             LighthouseInstruction::AssertAccountData {
@@ -186,6 +191,119 @@ pub mod lighthouse {
                 let ctx = AssertBubblegumTreeConfigAccountContext::load(&mut accounts.iter())?;
                 processor::assert_bubblegum_tree_config_account(&ctx, &assertion, log_level)?;
             }
+            LighthouseInstruction::AssertAccountDataPath {
+                log_level,
+                assertion,
+            } => {
+                let ctx = AssertTargetAccountContext::load(&mut accounts.iter())?;
+                processor::assert_target_account(ctx, &assertion, log_level)?;
+            }
+            LighthouseInstruction::AssertAccountDataPathMulti {
+                log_level,
+                assertions,
+            } => {
+                let ctx = AssertTargetAccountContext::load(&mut accounts.iter())?;
+                processor::assert_target_account_multi(ctx, &assertions, log_level)?;
+            }
+            LighthouseInstruction::MemoryWriteMulti {
+                memory_id,
+                memory_bump,
+                writes,
+            } => {
+                let ctx = MemoryWriteContext::load(
+                    &mut accounts.iter(),
+                    MemoryId::Index(memory_id),
+                    memory_bump,
+                    processor::required_space(&writes),
+                )?;
+                processor::memory_write_multi(&ctx, &writes)?;
+            }
+            LighthouseInstruction::AssertMemoryDeltaAndClose {
+                log_level,
+                memories,
+                assertions,
+            } => {
+                let ctx = AssertMemoryDeltaAndCloseContext::load(&mut accounts.iter(), &memories)?;
+                processor::assert_memory_delta_and_close(&ctx, &assertions, log_level)?;
+            }
+            LighthouseInstruction::AssertTokenAccountDelta {
+                log_level,
+                snapshot_offset,
+                assertion,
+            } => {
+                let ctx = AssertTokenDeltaContext::load::<spl_token_2022::state::Account>(
+                    &mut accounts.iter(),
+                )?;
+                processor::assert_token_delta(
+                    &ctx,
+                    *snapshot_offset,
+                    WriteTypeTag::TokenAccountSnapshot,
+                    &assertion,
+                    log_level,
+                )?;
+            }
+            LighthouseInstruction::AssertMintAccountDelta {
+                log_level,
+                snapshot_offset,
+                assertion,
+            } => {
+                let ctx = AssertTokenDeltaContext::load::<spl_token_2022::state::Mint>(
+                    &mut accounts.iter(),
+                )?;
+                processor::assert_token_delta(
+                    &ctx,
+                    *snapshot_offset,
+                    WriteTypeTag::MintAccountSnapshot,
+                    &assertion,
+                    log_level,
+                )?;
+            }
+            LighthouseInstruction::MemoryWriteKeyed {
+                memory_key,
+                memory_bump,
+                write_offset,
+                write_type,
+            } => {
+                let write_offset = *write_offset;
+
+                let ctx = MemoryWriteContext::load(
+                    &mut accounts.iter(),
+                    MemoryId::Key(&memory_key),
+                    memory_bump,
+                    write_type.required_space(write_offset),
+                )?;
+                processor::memory_write(&ctx, write_offset, &write_type)?;
+            }
+            LighthouseInstruction::MemoryWriteMultiKeyed {
+                memory_key,
+                memory_bump,
+                writes,
+            } => {
+                let ctx = MemoryWriteContext::load(
+                    &mut accounts.iter(),
+                    MemoryId::Key(&memory_key),
+                    memory_bump,
+                    processor::required_space(&writes),
+                )?;
+                processor::memory_write_multi(&ctx, &writes)?;
+            }
+            LighthouseInstruction::MemoryCloseKeyed {
+                memory_key,
+                memory_bump,
+            } => {
+                let ctx = MemoryCloseContext::load(
+                    &mut accounts.iter(),
+                    MemoryId::Key(&memory_key),
+                    memory_bump,
+                )?;
+                processor::memory_close(&ctx)?;
+            }
+            LighthouseInstruction::AssertReturnData {
+                log_level,
+                assertion,
+            } => {
+                processor::assert_return_data(&assertion, log_level)?;
+            }
         }
 
         Ok(())
@@ -2,7 +2,7 @@
 macro_rules! generate_asserts_borsh {
     ($assertion:ident, $enum:ident, $data:ident, $log_level:ident,
         standard_cases: [ $( ($variant:ident, $type:tt, $offset:expr) ),* ],
-        custom_cases: [ $( ($variant_simple:ident, $func:expr) ),* ]) => {
+        custom_cases: [ $( ($variant_simple:ident { $($field:ident),* }, $func:expr) ),* ]) => {
         match $assertion {
             $(
                 $enum::$variant { value, operator } => {
@@ -11,8 +11,8 @@ macro_rules! generate_asserts_borsh {
                 }
             )*
             $(
-                $enum::$variant_simple { value, operator } => {
-                    $func(value, operator)
+                $enum::$variant_simple { $($field),* } => {
+                    $func($($field),*)
                 }
             )*
         }
@@ -24,6 +24,26 @@ macro_rules! generate_asserts_borsh {
     (@unpack ([u8]), $data:ident, $offset:expr, $value:ident) => {
         $crate::utils::checked_get_slice(&$data, $offset, $value.len())?
     };
+    (@unpack [Pubkey], $data:ident, $offset:expr, $value:ident) => {
+        *bytemuck::from_bytes::<Pubkey>($crate::utils::checked_get_slice(&$data, $offset, 32)?)
+    };
+    (@unpack (BigEndian $type:ty), $data:ident, $offset:expr, $value:ident) => {
+        <$type>::from_be_bytes(
+            $crate::utils::checked_get_slice(&$data, $offset, std::mem::size_of::<$type>())?
+                .try_into()
+                .unwrap(),
+        )
+    };
+    (@unpack (FixedPoint $wrapper:ident $type:ty), $data:ident, $offset:expr, $value:ident) => {
+        $wrapper($crate::utils::try_from_slice::<$type>(&$data, $offset)?)
+    };
+    // Floats are read directly as borsh rejects NaN.
+    (@unpack f32, $data:ident, $offset:expr, $value:ident) => {
+        f32::from_le_bytes($crate::utils::checked_get_slice(&$data, $offset, 4)?.try_into().unwrap())
+    };
+    (@unpack f64, $data:ident, $offset:expr, $value:ident) => {
+        f64::from_le_bytes($crate::utils::checked_get_slice(&$data, $offset, 8)?.try_into().unwrap())
+    };
     (@unpack $type:tt, $data:ident, $offset:expr, $value:ident) => {
         $crate::utils::try_from_slice::<$type>(&$data, $offset)?
     };
@@ -37,6 +57,18 @@ macro_rules! generate_asserts_borsh {
         <Option<&u64>>::evaluate(&$actual_value.as_ref(), &$value.as_ref(), $operator, $log_level)
     };
 
+    (@evaluate [$type:ty], $actual_value:ident, $value:ident, $operator:ident, $log_level:ident) => {
+        <$type>::evaluate_set(&$actual_value, $value, $operator, $log_level)
+    };
+
+    (@evaluate (BigEndian $type:ty), $actual_value:ident, $value:ident, $operator:ident, $log_level:ident) => {
+        <$type>::evaluate(&$actual_value, $value, $operator, $log_level)
+    };
+
+    (@evaluate (FixedPoint $wrapper:ident $type:ty), $actual_value:ident, $value:ident, $operator:ident, $log_level:ident) => {
+        <$wrapper>::evaluate(&$actual_value, &$wrapper(*$value), $operator, $log_level)
+    };
+
     (@evaluate $type:tt, $actual_value:ident, $value:ident, $operator:ident, $log_level:ident) => {
         #[allow(unused_parens)]
         <$type>::evaluate(&$actual_value, $value, $operator, $log_level)
@@ -45,7 +77,7 @@ macro_rules! generate_asserts_borsh {
 
 #[macro_export]
 macro_rules! generate_asserts_c {
-    ($self:ident, $enum:ident, $data:ident, $log_level:ident, $( ($variant:ident, $type:tt, $offset:expr) ),* $( (custom, $variant_simple:ident, $impl:block) ),*) => {
+    ($self:ident, $enum:ident, $data:ident, $log_level:ident, $( ($variant:ident, $type:tt, $offset:expr) ),* $( (custom, $variant_simple:ident $({ $($field:ident),* })?, $impl:block) ),*) => {
         match $self {
             $(
                 $enum::$variant { value, operator } => {
@@ -54,7 +86,7 @@ macro_rules! generate_asserts_c {
                 }
             )*
             $(
-                $enum::$variant_simple => {
+                $enum::$variant_simple $({ $($field),* })? => {
                     $impl
                 }
             )*
@@ -68,6 +100,9 @@ macro_rules! generate_asserts_c {
     (@unpack (Option<u64>), $data:ident, $offset:expr) => {
         $crate::utils::unpack_coption_u64(&$data, $offset)?
     };
+    (@unpack [Option<Pubkey>], $data:ident, $offset:expr) => {
+        $crate::utils::unpack_coption::<Pubkey>(&$data, $offset)?.copied()
+    };
     (@unpack (Pubkey), $data:ident, $offset:expr) => {
         bytemuck::from_bytes::<Pubkey>($crate::utils::checked_get_slice(&$data, $offset, 32)?)
     };
@@ -84,6 +119,10 @@ macro_rules! generate_asserts_c {
         <Option<&u64>>::evaluate(&$actual_value.as_ref(), &$value.as_ref(), $operator, $log_level)
     };
 
+    (@evaluate [$type:ty], $actual_value:ident, $value:ident, $operator:ident, $log_level:ident) => {
+        <$type>::evaluate_set(&$actual_value, $value, $operator, $log_level)
+    };
+
     (@evaluate $type:tt, $actual_value:ident, $value:ident, $operator:ident, $log_level:ident) => {
         #[allow(unused_parens)]
         <$type>::evaluate(&$actual_value, $value, $operator, $log_level)
@@ -9,38 +9,51 @@ use std::{fmt::Debug, slice::Iter};
 #[derive(Clone)]
 pub(crate) struct AssertMintAccountContext<'a, 'info> {
     pub(crate) mint_account: &'a AccountInfo<'info>,
+    pub(crate) accounts: &'a [AccountInfo<'info>],
 }
 
 impl<'a, 'info> AssertMintAccountContext<'a, 'info> {
     pub(crate) fn load(account_iter: &mut Iter<'a, AccountInfo<'info>>) -> Result<Self> {
+        let accounts = account_iter.as_slice();
         let mint_account = next_account_info(account_iter)?;
 
         if keys_equal(mint_account.owner, &spl_token::ID)
             || keys_equal(mint_account.owner, &spl_token_2022::ID)
         {
-            Ok(Self { mint_account })
+            Ok(Self {
+                mint_account,
+                accounts,
+            })
         } else {
             Err(LighthouseError::AccountOwnerMismatch.into())
         }
     }
 }
 
-pub(crate) fn assert_mint_account<'a, 'info, T: Assert<&'a AccountInfo<'info>> + Debug>(
+pub(crate) fn assert_mint_account<
+    'a,
+    'info,
+    T: Assert<(&'a AccountInfo<'info>, &'a [AccountInfo<'info>])> + Debug,
+>(
     ctx: AssertMintAccountContext<'a, 'info>,
     assertion: &T,
     log_level: LogLevel,
 ) -> Result<()> {
-    assertion.evaluate(ctx.mint_account, log_level)
+    assertion.evaluate((ctx.mint_account, ctx.accounts), log_level)
 }
 
-pub(crate) fn assert_mint_account_multi<'a, 'info, T: Assert<&'a AccountInfo<'info>> + Debug>(
+pub(crate) fn assert_mint_account_multi<
+    'a,
+    'info,
+    T: Assert<(&'a AccountInfo<'info>, &'a [AccountInfo<'info>])> + Debug,
+>(
     ctx: AssertMintAccountContext<'a, 'info>,
     assertions: &[T],
     log_level: LogLevel,
 ) -> Result<()> {
     for (i, assertion) in assertions.iter().enumerate() {
         assertion
-            .evaluate(ctx.mint_account, log_level)
+            .evaluate((ctx.mint_account, ctx.accounts), log_level)
             .map_err(|e| LighthouseError::map_multi_err(e, i as u32))?;
     }
 
@@ -0,0 +1,12 @@
+use crate::{
+    types::assert::{Assert, LogLevel},
+    utils::Result,
+};
+use std::fmt::Debug;
+
+pub(crate) fn assert_return_data<T: Assert<()> + Debug>(
+    assertion: &T,
+    log_level: LogLevel,
+) -> Result<()> {
+    assertion.evaluate((), log_level)
+}
@@ -13,21 +13,30 @@ use std::{fmt::Debug, slice::Iter};
 #[derive(Clone)]
 pub(crate) struct AssertStakeAccountContext<'a, 'info> {
     pub(crate) stake_account: &'a AccountInfo<'info>,
+    pub(crate) accounts: &'a [AccountInfo<'info>],
 }
 
 impl<'a, 'info> AssertStakeAccountContext<'a, 'info> {
     pub(crate) fn load(account_iter: &mut Iter<'a, AccountInfo<'info>>) -> Result<Self> {
+        let accounts = account_iter.as_slice();
         let stake_account = next_account_info(account_iter)?;
 
         if !keys_equal(stake_account.owner, &solana_program::stake::program::ID) {
             return Err(LighthouseError::AccountOwnerMismatch.into());
         }
 
-        Ok(Self { stake_account })
+        Ok(Self {
+            stake_account,
+            accounts,
+        })
     }
 }
 
-pub(crate) fn assert_stake_account<'a, 'info, T: for<'b> Assert<&'b StakeStateV2> + Debug>(
+pub(crate) fn assert_stake_account<
+    'a,
+    'info,
+    T: for<'b> Assert<(&'b StakeStateV2, &'a [AccountInfo<'info>])> + Debug,
+>(
     ctx: AssertStakeAccountContext<'a, 'info>,
     assertion: T,
     log_level: LogLevel,
@@ -40,10 +49,14 @@ pub(crate) fn assert_stake_account<'a, 'info, T: for<'b> Assert<&'b StakeStateV2
     let stake_account =
         StakeStateV2::deserialize(&mut data.as_ref()).map_err(LighthouseError::stake_deser_err)?;
 
-    assertion.evaluate(&stake_account, log_level)
+    assertion.evaluate((&stake_account, ctx.accounts), log_level)
 }
 
-pub(crate) fn assert_stake_account_multi<'a, 'info, T: for<'b> Assert<&'b StakeStateV2> + Debug>(
+pub(crate) fn assert_stake_account_multi<
+    'a,
+    'info,
+    T: for<'b> Assert<(&'b StakeStateV2, &'a [AccountInfo<'info>])> + Debug,
+>(
     ctx: AssertStakeAccountContext<'a, 'info>,
     assertions: &[T],
     log_level: LogLevel,
@@ -58,7 +71,7 @@ pub(crate) fn assert_stake_account_multi<'a, 'info, T: for<'b> Assert<&'b StakeS
 
     for (i, assertion) in assertions.iter().enumerate() {
         assertion
-            .evaluate(&stake_account, log_level)
+            .evaluate((&stake_account, ctx.accounts), log_level)
             .map_err(|e| LighthouseError::map_multi_err(e, i as u32))?;
     }
 
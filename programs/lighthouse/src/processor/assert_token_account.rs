@@ -9,38 +9,51 @@ use std::{fmt::Debug, slice::Iter};
 #[derive(Clone)]
 pub(crate) struct AssertTokenAccountContext<'a, 'info> {
     pub(crate) token_account: &'a AccountInfo<'info>,
+    pub(crate) accounts: &'a [AccountInfo<'info>],
 }
 
 impl<'a, 'info> AssertTokenAccountContext<'a, 'info> {
     pub(crate) fn load(account_iter: &mut Iter<'a, AccountInfo<'info>>) -> Result<Self> {
+        let accounts = account_iter.as_slice();
         let token_account = next_account_info(account_iter)?;
 
         if keys_equal(token_account.owner, &spl_token::ID)
             || keys_equal(token_account.owner, &spl_token_2022::ID)
         {
-            Ok(Self { token_account })
+            Ok(Self {
+                token_account,
+                accounts,
+            })
         } else {
             Err(LighthouseError::AccountOwnerMismatch.into())
         }
     }
 }
 
-pub(crate) fn assert_token_account<'a, 'info, T: Assert<&'a AccountInfo<'info>> + Debug>(
+pub(crate) fn assert_token_account<
+    'a,
+    'info,
+    T: Assert<(&'a AccountInfo<'info>, &'a [AccountInfo<'info>])> + Debug,
+>(
     ctx: AssertTokenAccountContext<'a, 'info>,
     assertion: &T,
     log_level: LogLevel,
 ) -> Result<()> {
-    assertion.evaluate(ctx.token_account, log_level)
+    assertion.evaluate((ctx.token_account, ctx.accounts), log_level)
 }
 
-pub(crate) fn assert_token_account_multi<'a, 'info, T: Assert<&'a AccountInfo<'info>> + Debug>(
+pub(crate) fn assert_token_account_multi<
+    'a,
+    'info,
+    T: Assert<(&'a AccountInfo<'info>, &'a [AccountInfo<'info>])> + Debug,
+>(
     ctx: AssertTokenAccountContext<'a, 'info>,
     assertions: &[T],
     log_level: LogLevel,
 ) -> Result<()> {
     for (i, assertion) in assertions.iter().enumerate() {
         assertion
-            .evaluate(ctx.token_account, log_level)
+            .evaluate((ctx.token_account, ctx.accounts), log_level)
             .map_err(|e| LighthouseError::map_multi_err(e, i as u32))?;
     }
 
@@ -0,0 +1,95 @@
+use crate::{
+    error::LighthouseError,
+    types::{
+        assert::{Assert, LogLevel},
+        write::{MemoryHeader, WriteTypeTag},
+    },
+    utils::{checked_get_slice, keys_equal, validate_token_state, Result},
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    msg,
+    program_pack::Pack,
+    pubkey::{Pubkey, PUBKEY_BYTES},
+};
+use spl_token_2022::extension::BaseState;
+use std::slice::Iter;
+
+pub(crate) struct AssertTokenDeltaContext<'a, 'info> {
+    pub(crate) snapshot_account: &'a AccountInfo<'info>,
+    pub(crate) target_account: &'a AccountInfo<'info>,
+    pub(crate) accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> AssertTokenDeltaContext<'a, 'info> {
+    // `S` is the token program state the target account must hold.
+    pub(crate) fn load<S: BaseState + Pack>(
+        account_iter: &mut Iter<'a, AccountInfo<'info>>,
+    ) -> Result<Self> {
+        let accounts = account_iter.as_slice();
+        let snapshot_account = next_account_info(account_iter)?;
+        let target_account = next_account_info(account_iter)?;
+
+        validate_token_state::<S>(target_account)?;
+
+        Ok(Self {
+            snapshot_account,
+            target_account,
+            accounts,
+        })
+    }
+}
+
+// Snapshots start with the key of the account they were taken from, followed by its base state.
+// Only snapshots the program recorded in the header of a memory account as `snapshot_tag` writes
+// are trusted.
+pub(crate) fn assert_token_delta<
+    'info,
+    T: for<'b> Assert<(&'b [u8], &'b AccountInfo<'info>, &'b [AccountInfo<'info>])>,
+>(
+    ctx: &AssertTokenDeltaContext<'_, 'info>,
+    snapshot_offset: u64,
+    snapshot_tag: WriteTypeTag,
+    assertion: &T,
+    log_level: LogLevel,
+) -> Result<()> {
+    let header = MemoryHeader::read_account(ctx.snapshot_account)?;
+    let tag = header.expect_tag(snapshot_offset)?.tag;
+
+    if tag != snapshot_tag {
+        msg!(
+            "Expected a {:?} write at memory offset {}, found {:?}",
+            snapshot_tag,
+            snapshot_offset,
+            tag
+        );
+        return Err(LighthouseError::AssertionFailed.into());
+    }
+
+    let snapshot_data = ctx
+        .snapshot_account
+        .try_borrow_data()
+        .map_err(LighthouseError::failed_borrow_err)?;
+
+    let snapshot_offset = snapshot_offset as usize;
+    let source_key = Pubkey::new_from_array(
+        checked_get_slice(&snapshot_data, snapshot_offset, PUBKEY_BYTES)?
+            .try_into()
+            .unwrap(),
+    );
+
+    if !keys_equal(&source_key, ctx.target_account.key) {
+        msg!(
+            "Snapshot was taken from {}, expected {}",
+            source_key,
+            ctx.target_account.key
+        );
+        return Err(LighthouseError::AccountKeyMismatch.into());
+    }
+
+    let snapshot = snapshot_data
+        .get(snapshot_offset + PUBKEY_BYTES..)
+        .ok_or(LighthouseError::RangeOutOfBounds)?;
+
+    assertion.evaluate((snapshot, ctx.target_account, ctx.accounts), log_level)
+}
@@ -35,7 +35,7 @@ impl<'a, 'info> AssertUpgradeableLoaderStateContext<'a, 'info> {
 pub(crate) fn assert_upgradeable_loader_state<
     'a,
     'info,
-    T: for<'b> Assert<&'b UpgradeableLoaderState> + Debug,
+    T: for<'b> Assert<(&'b UpgradeableLoaderState, &'b [u8])> + Debug,
 >(
     ctx: AssertUpgradeableLoaderStateContext<'a, 'info>,
     assertion: &T,
@@ -51,13 +51,13 @@ pub(crate) fn assert_upgradeable_loader_state<
         err!(LighthouseError::AccountBorrowFailed)
     })?;
 
-    assertion.evaluate(&state, log_level)
+    assertion.evaluate((&state, &data), log_level)
 }
 
 pub(crate) fn assert_upgradeable_loader_state_multi<
     'a,
     'info,
-    T: for<'b> Assert<&'b UpgradeableLoaderState> + Debug,
+    T: for<'b> Assert<(&'b UpgradeableLoaderState, &'b [u8])> + Debug,
 >(
     ctx: AssertUpgradeableLoaderStateContext<'a, 'info>,
     assertions: &[T],
@@ -75,7 +75,7 @@ pub(crate) fn assert_upgradeable_loader_state_multi<
 
     for (i, assertion) in assertions.iter().enumerate() {
         assertion
-            .evaluate(&state, log_level)
+            .evaluate((&state, &data), log_level)
             .map_err(|e| LighthouseError::map_multi_err(e, i as u32))?;
     }
 
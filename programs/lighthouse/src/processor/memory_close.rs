@@ -1,7 +1,10 @@
-use crate::utils::{close, Result};
+use crate::error::LighthouseError;
+use crate::types::assert::{Assert, LogLevel, MemoryDeltaAssertion};
+use crate::types::write::MemoryAccount;
+use crate::utils::{close, get_account, Result};
 use crate::validation::{
-    AccountValidation, CheckedAccount, DerivedAddress, LighthouseProgram, Memory, MemorySeeds,
-    Program, Signer,
+    AccountValidation, CheckedAccount, DerivedAddress, LighthouseProgram, Memory, MemoryId,
+    MemorySeeds, Program, Signer,
 };
 use solana_program::account_info::{next_account_info, AccountInfo};
 use std::slice::Iter;
@@ -16,7 +19,7 @@ pub(crate) struct MemoryCloseContext<'a, 'info> {
 impl<'a, 'info> MemoryCloseContext<'a, 'info> {
     pub(crate) fn load(
         account_iter: &mut Iter<'a, AccountInfo<'info>>,
-        memory_id: u8,
+        memory_id: MemoryId,
         memory_bump: u8,
     ) -> Result<Self> {
         let lighthouse_program = Program::new_checked(next_account_info(account_iter)?, None)?;
@@ -25,23 +28,12 @@ impl<'a, 'info> MemoryCloseContext<'a, 'info> {
             Some(&vec![AccountValidation::IsWritable]),
         )?;
 
-        let seeds = &Memory::get_seeds(MemorySeeds {
-            payer: payer.key,
+        let memory = load_memory(
+            account_iter,
+            &lighthouse_program,
+            &payer,
             memory_id,
-            bump: Some(memory_bump),
-        });
-
-        let memory = Memory::new_checked(
-            next_account_info(account_iter)?,
-            Some(&vec![
-                AccountValidation::IsWritable,
-                AccountValidation::IsProgramDerivedAddress {
-                    seeds,
-                    program_id: lighthouse_program.key,
-                    find_bump: false,
-                },
-                AccountValidation::IsProgramOwned(crate::ID),
-            ]),
+            memory_bump,
         )?;
 
         Ok(Self {
@@ -55,3 +47,109 @@ impl<'a, 'info> MemoryCloseContext<'a, 'info> {
 pub(crate) fn memory_close(ctx: &MemoryCloseContext) -> Result<()> {
     close(ctx.memory.info, ctx.payer.info)
 }
+
+#[allow(dead_code)]
+pub(crate) struct AssertMemoryDeltaAndCloseContext<'a, 'info> {
+    pub lighthouse_program: Program<'a, 'info, LighthouseProgram>,
+    pub payer: Signer<'a, 'info>,
+    pub memories: Vec<Memory<'a, 'info>>,
+    pub accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> AssertMemoryDeltaAndCloseContext<'a, 'info> {
+    pub(crate) fn load(
+        account_iter: &mut Iter<'a, AccountInfo<'info>>,
+        memories: &[MemoryAccount],
+    ) -> Result<Self> {
+        let lighthouse_program = Program::new_checked(next_account_info(account_iter)?, None)?;
+        let payer = Signer::new_checked(
+            next_account_info(account_iter)?,
+            Some(&vec![AccountValidation::IsWritable]),
+        )?;
+
+        let memories = memories
+            .iter()
+            .map(|memory| {
+                let (memory_id, memory_bump) = match memory {
+                    MemoryAccount::Index {
+                        memory_id,
+                        memory_bump,
+                    } => (MemoryId::Index(*memory_id), *memory_bump),
+                    MemoryAccount::Key {
+                        memory_key,
+                        memory_bump,
+                    } => (MemoryId::Key(memory_key), *memory_bump),
+                };
+
+                load_memory(
+                    account_iter,
+                    &lighthouse_program,
+                    &payer,
+                    memory_id,
+                    memory_bump,
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            lighthouse_program,
+            payer,
+            memories,
+            accounts: account_iter.as_slice(),
+        })
+    }
+}
+
+// Evaluates every assertion before closing any memory account, so a failed assertion leaves the
+// memory untouched.
+pub(crate) fn assert_memory_delta_and_close(
+    ctx: &AssertMemoryDeltaAndCloseContext,
+    assertions: &[MemoryDeltaAssertion],
+    log_level: LogLevel,
+) -> Result<()> {
+    for (i, entry) in assertions.iter().enumerate() {
+        let memory = ctx
+            .memories
+            .get(entry.memory_index as usize)
+            .ok_or(LighthouseError::NotEnoughAccounts)?;
+        let account = get_account(ctx.accounts, entry.account_index)?;
+
+        entry
+            .assertion
+            .evaluate((memory.info, account), log_level)
+            .map_err(|e| LighthouseError::map_multi_err(e, i as u32))?;
+    }
+
+    for memory in ctx.memories.iter() {
+        close(memory.info, ctx.payer.info)?;
+    }
+
+    Ok(())
+}
+
+fn load_memory<'a, 'info>(
+    account_iter: &mut Iter<'a, AccountInfo<'info>>,
+    lighthouse_program: &Program<'a, 'info, LighthouseProgram>,
+    payer: &Signer<'a, 'info>,
+    memory_id: MemoryId,
+    memory_bump: u8,
+) -> Result<Memory<'a, 'info>> {
+    let seeds = &Memory::get_seeds(MemorySeeds {
+        payer: payer.key,
+        memory_id,
+        bump: Some(memory_bump),
+    });
+
+    Memory::new_checked(
+        next_account_info(account_iter)?,
+        Some(&vec![
+            AccountValidation::IsWritable,
+            AccountValidation::IsProgramDerivedAddress {
+                seeds,
+                program_id: lighthouse_program.key,
+                find_bump: false,
+            },
+            AccountValidation::IsProgramOwned(crate::ID),
+        ]),
+    )
+}
@@ -1,19 +1,30 @@
 use crate::error::LighthouseError;
-use crate::types::write::{AccountInfoField, ClockField, DataValue, WriteType};
-use crate::utils::Result;
+use crate::types::assert::{load_new_rate_activation_epoch, load_stake_history};
+use crate::types::write::{
+    AccountInfoField, ClockField, DataValue, EpochScheduleField, MemoryHeader, MemoryWriteEntry,
+    WriteType,
+};
+use crate::utils::{get_account, keys_equal, validate_token_state, Result};
 use crate::validation::{
     AccountValidation, CheckedAccount, DerivedAddress, InitializeType, LighthouseProgram, Memory,
-    MemorySeeds, Program, Signer, SystemProgram,
+    MemoryId, MemorySeeds, Program, Signer, SystemProgram,
 };
 use crate::{err, err_msg};
-use borsh::BorshSerialize;
+use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     clock::Clock,
+    entrypoint::MAX_PERMITTED_DATA_INCREASE,
+    epoch_schedule::EpochSchedule,
     instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT},
     msg,
+    program::get_return_data,
     program_error::ProgramError,
-    sysvar::Sysvar,
+    program_pack::Pack,
+    pubkey::PUBKEY_BYTES,
+    rent::Rent,
+    stake::state::StakeStateV2,
+    sysvar::{instructions::load_current_index_checked, Sysvar},
 };
 
 use std::slice::Iter;
@@ -24,16 +35,15 @@ pub(crate) struct MemoryWriteContext<'a, 'info> {
     pub system_program: Program<'a, 'info, SystemProgram>,
     pub payer: Signer<'a, 'info>,
     pub memory: Memory<'a, 'info>,
-    pub source_account: &'a AccountInfo<'info>,
+    pub source_accounts: &'a [AccountInfo<'info>],
 }
 
 impl<'a, 'info> MemoryWriteContext<'a, 'info> {
     pub(crate) fn load(
         account_iter: &mut Iter<'a, AccountInfo<'info>>,
-        memory_id: u8,
-        write_offset: u64,
+        memory_id: MemoryId,
         memory_bump: u8,
-        write_type: &WriteType,
+        required_space: u64,
     ) -> Result<Self> {
         let lighthouse_program = Program::new_checked(next_account_info(account_iter)?, None)?;
         let system_program = Program::new_checked(next_account_info(account_iter).unwrap(), None)?;
@@ -48,10 +58,24 @@ impl<'a, 'info> MemoryWriteContext<'a, 'info> {
             bump: Some(memory_bump),
         });
 
-        let required_space = write_offset + write_type.data_length();
-
         let memory_info = next_account_info(account_iter)?;
-        let memory = if memory_info.try_data_len()? < required_space as usize {
+        let memory_space = memory_info.try_data_len()?;
+
+        // The runtime limits account growth per instruction, memory needing more is grown by the
+        // limit so `Resize` writes can grow it across several instructions. Other writes past the
+        // grown memory fail.
+        let growth_limit = (memory_space as u64).saturating_add(MAX_PERMITTED_DATA_INCREASE as u64);
+        if required_space > growth_limit {
+            msg!(
+                "Memory can grow by at most {} bytes per instruction, {} bytes required with {} allocated",
+                MAX_PERMITTED_DATA_INCREASE,
+                required_space,
+                memory_space
+            );
+        }
+        let required_space = required_space.min(growth_limit);
+
+        let memory = if memory_space < required_space as usize {
             Memory::new_init_checked(
                 memory_info,
                 InitializeType::InitOrReallocIfNeeded {
@@ -90,7 +114,7 @@ impl<'a, 'info> MemoryWriteContext<'a, 'info> {
             lighthouse_program,
             payer,
             memory,
-            source_account: next_account_info(account_iter)?,
+            source_accounts: account_iter.as_slice(),
         })
     }
 }
@@ -105,11 +129,94 @@ pub(crate) fn memory_write(
         return Err(LighthouseError::CrossProgramInvokeViolation.into());
     }
 
-    let memory = ctx.memory.clone();
-    let source_account = ctx.source_account;
+    let source_account = next_account_info(&mut ctx.source_accounts.iter())?;
+    let memory_ref = &mut ctx.memory.info.try_borrow_mut_data()?;
+
+    write_memory(
+        memory_ref,
+        source_account,
+        ctx.source_accounts,
+        offset,
+        write_type,
+    )?;
+    stamp_memory_header(memory_ref, offset, write_type)
+}
+
+pub(crate) fn memory_write_multi(
+    ctx: &MemoryWriteContext,
+    writes: &[MemoryWriteEntry],
+) -> Result<()> {
+    if get_stack_height() > TRANSACTION_LEVEL_STACK_HEIGHT {
+        msg!("Cross-program invocation violation");
+        return Err(LighthouseError::CrossProgramInvokeViolation.into());
+    }
+
+    let memory_ref = &mut ctx.memory.info.try_borrow_mut_data()?;
+
+    for write in writes {
+        let source_account = get_account(ctx.source_accounts, write.source_account_index)?;
+
+        write_memory(
+            memory_ref,
+            source_account,
+            ctx.source_accounts,
+            *write.write_offset,
+            &write.write_type,
+        )?;
+        stamp_memory_header(memory_ref, *write.write_offset, &write.write_type)?;
+    }
+
+    Ok(())
+}
+
+// Memory must cover the furthest write of the batch.
+pub(crate) fn required_space(writes: &[MemoryWriteEntry]) -> u64 {
+    writes
+        .iter()
+        .map(|write| write.write_type.required_space(*write.write_offset))
+        .max()
+        .unwrap_or(0)
+}
+
+// Restamps the slot of a memory header and records the slot and type of the write at its offset.
+fn stamp_memory_header(memory_ref: &mut [u8], offset: u64, write_type: &WriteType) -> Result<()> {
+    if matches!(
+        write_type,
+        WriteType::MemoryHeader { .. } | WriteType::Resize { .. }
+    ) || !MemoryHeader::is_present(memory_ref)
+    {
+        return Ok(());
+    }
+
+    let slot = Clock::get()?.slot;
+    let mut header = MemoryHeader::read(memory_ref)?;
+    header.slot = slot;
+    header.record(offset, slot, write_type.type_tag())?;
+    header.write(memory_ref)
+}
 
+fn write_memory(
+    memory_ref: &mut [u8],
+    source_account: &AccountInfo,
+    accounts: &[AccountInfo],
+    offset: u64,
+    write_type: &WriteType,
+) -> Result<()> {
     let write_offset = offset as usize;
-    let memory_ref = &mut memory.info.try_borrow_mut_data()?;
+    let memory_length = memory_ref.len();
+
+    if !matches!(
+        write_type,
+        WriteType::MemoryHeader { .. } | WriteType::Resize { .. }
+    ) && write_offset < MemoryHeader::LEN
+        && MemoryHeader::is_present(memory_ref)
+    {
+        msg!(
+            "Write at offset {} overlaps the memory header",
+            write_offset
+        );
+        return Err(LighthouseError::RangeOutOfBounds.into());
+    }
 
     match write_type {
         WriteType::DataValue(data_value) => {
@@ -143,7 +250,7 @@ pub(crate) fn memory_write(
                         msg!(
                             "DataValue write - range out of bounds {:?} write length {:?}",
                             memory_write_range,
-                            memory.info.data_len()
+                            memory_length
                         );
                         LighthouseError::RangeOutOfBounds
                     })?;
@@ -152,35 +259,20 @@ pub(crate) fn memory_write(
         }
         WriteType::AccountData {
             offset: data_offset,
-            data_length: _,
+            data_length,
         } => {
-            let data_offset = *data_offset as usize;
-            let data_length = write_type.data_length() as usize;
-
             let data = source_account.try_borrow_data().map_err(|err| {
                 msg!("Failed to borrow target account: {:?}", err);
                 LighthouseError::AccountBorrowFailed
             })?;
 
-            let data_range = data_offset..(data_offset + data_length);
-            let data_slice = data.get(data_range.clone()).ok_or_else(|| {
-                msg!("AccountData - read range out of bounds {:?}", data_range);
-                LighthouseError::RangeOutOfBounds
-            })?;
-
-            let memory_write_range = write_offset..(write_offset + data_length);
-            let memory_write_slice =
-                memory_ref
-                    .get_mut(memory_write_range.clone())
-                    .ok_or_else(|| {
-                        msg!(
-                            "AccountData - write range out of bounds {:?}",
-                            memory_write_range
-                        );
-                        LighthouseError::RangeOutOfBounds
-                    })?;
-
-            memory_write_slice.copy_from_slice(data_slice);
+            copy_account_data(
+                memory_ref,
+                write_offset,
+                &data,
+                **data_offset as usize,
+                **data_length as usize,
+            )?;
         }
         WriteType::AccountInfoField(field) => {
             let bytes = match field {
@@ -280,7 +372,250 @@ pub(crate) fn memory_write(
 
             memory_write_slice.copy_from_slice(&bytes);
         }
+        WriteType::AccountHash { algorithm, scope } => {
+            let bytes = scope.hash(source_account, algorithm)?;
+
+            let data_length = write_type.data_length() as usize;
+            let memory_write_range = write_offset..(write_offset + data_length);
+            let memory_write_slice =
+                memory_ref
+                    .get_mut(memory_write_range.clone())
+                    .ok_or_else(|| {
+                        msg!(
+                            "AccountHash write - range out of bounds {:?}",
+                            memory_write_range
+                        );
+                        LighthouseError::RangeOutOfBounds
+                    })?;
+
+            memory_write_slice.copy_from_slice(&bytes);
+        }
+        WriteType::AccountDataRanges { ranges } => {
+            let data = source_account.try_borrow_data().map_err(|err| {
+                msg!("Failed to borrow target account: {:?}", err);
+                LighthouseError::AccountBorrowFailed
+            })?;
+
+            let mut write_offset = write_offset;
+            for range in ranges.iter() {
+                let data_length = *range.length as usize;
+
+                copy_account_data(
+                    memory_ref,
+                    write_offset,
+                    &data,
+                    *range.offset as usize,
+                    data_length,
+                )?;
+
+                write_offset += data_length;
+            }
+        }
+        WriteType::Arithmetic {
+            mode,
+            integer_type,
+            source,
+        } => {
+            let source = source.to_write_type(integer_type);
+            let mut value = vec![0u8; source.data_length() as usize];
+            write_memory(&mut value, source_account, accounts, 0, &source)?;
+
+            let memory_write_range = write_offset..(write_offset + integer_type.width());
+            let memory_write_slice =
+                memory_ref
+                    .get_mut(memory_write_range.clone())
+                    .ok_or_else(|| {
+                        msg!(
+                            "Arithmetic write - range out of bounds {:?}",
+                            memory_write_range
+                        );
+                        LighthouseError::RangeOutOfBounds
+                    })?;
+
+            let bytes = mode.combine(integer_type, memory_write_slice, &value)?;
+            memory_write_slice.copy_from_slice(&bytes);
+        }
+        WriteType::MemoryHeader { nonce } => {
+            if write_offset != 0 {
+                msg!("MemoryHeader must be written at offset 0");
+                return Err(LighthouseError::InvalidInstructionData.into());
+            }
+
+            MemoryHeader::new(Clock::get()?.slot, *nonce).write(memory_ref)?;
+        }
+        WriteType::TokenAccountSnapshot | WriteType::MintAccountSnapshot => {
+            let state_length = if matches!(write_type, WriteType::TokenAccountSnapshot) {
+                validate_token_state::<spl_token_2022::state::Account>(source_account)?;
+                spl_token::state::Account::LEN
+            } else {
+                validate_token_state::<spl_token_2022::state::Mint>(source_account)?;
+                spl_token::state::Mint::LEN
+            };
+
+            let data = source_account.try_borrow_data().map_err(|err| {
+                msg!("Failed to borrow target account: {:?}", err);
+                LighthouseError::AccountBorrowFailed
+            })?;
+
+            copy_account_data(
+                memory_ref,
+                write_offset,
+                source_account.key.as_ref(),
+                0,
+                PUBKEY_BYTES,
+            )?;
+            copy_account_data(
+                memory_ref,
+                write_offset + PUBKEY_BYTES,
+                &data,
+                0,
+                state_length,
+            )?;
+        }
+        WriteType::ReturnData {
+            program_id,
+            offset,
+            length,
+        } => {
+            let (return_program_id, data) = get_return_data().ok_or_else(|| {
+                msg!("ReturnData write - no return data set");
+                LighthouseError::ReturnDataMismatch
+            })?;
+
+            if !keys_equal(&return_program_id, program_id) {
+                msg!(
+                    "ReturnData write - return data set by {:?}, expected {:?}",
+                    return_program_id,
+                    program_id
+                );
+                return Err(LighthouseError::ReturnDataMismatch.into());
+            }
+
+            copy_account_data(
+                memory_ref,
+                write_offset,
+                &data,
+                **offset as usize,
+                **length as usize,
+            )?;
+        }
+        WriteType::RentMinimumBalance { data_length } => {
+            let minimum_balance = Rent::get()?.minimum_balance(**data_length as usize);
+
+            copy_account_data(
+                memory_ref,
+                write_offset,
+                &minimum_balance.to_le_bytes(),
+                0,
+                write_type.data_length() as usize,
+            )?;
+        }
+        WriteType::EpochSchedule(field) => {
+            let epoch_schedule = EpochSchedule::get()?;
+
+            let bytes = match field {
+                EpochScheduleField::SlotsPerEpoch => {
+                    epoch_schedule.slots_per_epoch.to_le_bytes().to_vec()
+                }
+                EpochScheduleField::LeaderScheduleSlotOffset => epoch_schedule
+                    .leader_schedule_slot_offset
+                    .to_le_bytes()
+                    .to_vec(),
+                EpochScheduleField::Warmup => vec![epoch_schedule.warmup as u8],
+                EpochScheduleField::FirstNormalEpoch => {
+                    epoch_schedule.first_normal_epoch.to_le_bytes().to_vec()
+                }
+                EpochScheduleField::FirstNormalSlot => {
+                    epoch_schedule.first_normal_slot.to_le_bytes().to_vec()
+                }
+            };
+
+            copy_account_data(memory_ref, write_offset, &bytes, 0, bytes.len())?;
+        }
+        WriteType::InstructionIndex => {
+            let index = load_current_index_checked(source_account).map_err(|err| {
+                err_msg!("Failed to load current index from Instructions sysvar", err);
+                LighthouseError::AccountKeyMismatch
+            })?;
+
+            copy_account_data(
+                memory_ref,
+                write_offset,
+                &index.to_le_bytes(),
+                0,
+                write_type.data_length() as usize,
+            )?;
+        }
+        WriteType::StakeActivation(field) => {
+            if !keys_equal(source_account.owner, &solana_program::stake::program::ID) {
+                return Err(LighthouseError::AccountOwnerMismatch.into());
+            }
+
+            let data = source_account
+                .try_borrow_data()
+                .map_err(LighthouseError::failed_borrow_err)?;
+            let stake = match StakeStateV2::deserialize(&mut data.as_ref())
+                .map_err(LighthouseError::stake_deser_err)?
+            {
+                StakeStateV2::Stake(_, stake, _) => stake,
+                _ => {
+                    msg!("Stake account is not delegated");
+                    return Err(LighthouseError::AccountValidationFailed.into());
+                }
+            };
+
+            let stake_history = load_stake_history(accounts, stake.delegation.activation_epoch)?;
+            let value = field.read(
+                &stake,
+                Clock::get()?.epoch,
+                &stake_history,
+                load_new_rate_activation_epoch(accounts)?,
+            );
+
+            copy_account_data(
+                memory_ref,
+                write_offset,
+                &value.to_le_bytes(),
+                0,
+                write_type.data_length() as usize,
+            )?;
+        }
+        WriteType::Resize { .. } => {
+            if write_offset != 0 {
+                msg!("Resize must be written at offset 0");
+                return Err(LighthouseError::InvalidInstructionData.into());
+            }
+        }
     };
 
     Ok(())
 }
+
+fn copy_account_data(
+    memory_ref: &mut [u8],
+    write_offset: usize,
+    data: &[u8],
+    data_offset: usize,
+    data_length: usize,
+) -> Result<()> {
+    let data_range = data_offset..data_offset.saturating_add(data_length);
+    let data_slice = data.get(data_range.clone()).ok_or_else(|| {
+        msg!("AccountData - read range out of bounds {:?}", data_range);
+        LighthouseError::RangeOutOfBounds
+    })?;
+
+    let memory_write_range = write_offset..write_offset.saturating_add(data_length);
+    let memory_write_slice = memory_ref
+        .get_mut(memory_write_range.clone())
+        .ok_or_else(|| {
+            msg!(
+                "AccountData - write range out of bounds {:?}",
+                memory_write_range
+            );
+            LighthouseError::RangeOutOfBounds
+        })?;
+
+    memory_write_slice.copy_from_slice(data_slice);
+
+    Ok(())
+}
@@ -3,9 +3,11 @@ pub mod assert_bubblegum_tree_config_account;
 pub mod assert_clock;
 pub mod assert_merkle_tree_account;
 pub mod assert_mint_account;
+pub mod assert_return_data;
 pub mod assert_stake_account;
 pub mod assert_target_account;
 pub mod assert_token_account;
+pub mod assert_token_delta;
 pub mod assert_upgradeable_loader_state;
 pub mod memory_close;
 pub mod memory_write;
@@ -15,9 +17,11 @@ pub(crate) use assert_bubblegum_tree_config_account::*;
 pub(crate) use assert_clock::*;
 pub(crate) use assert_merkle_tree_account::*;
 pub(crate) use assert_mint_account::*;
+pub(crate) use assert_return_data::*;
 pub(crate) use assert_stake_account::*;
 pub(crate) use assert_target_account::*;
 pub(crate) use assert_token_account::*;
+pub(crate) use assert_token_delta::*;
 pub(crate) use assert_upgradeable_loader_state::*;
 pub(crate) use memory_close::*;
 pub(crate) use memory_write::*;
@@ -1,6 +1,12 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_sdk::{
-    program_error::ProgramError, pubkey::Pubkey, signature::Keypair, signer::EncodableKeypair,
+    clock::Epoch,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::EncodableKeypair,
+    stake::state::{Delegation, Stake},
+    stake_history::{StakeHistory, StakeHistoryEntry},
 };
 
 #[derive(Debug, BorshDeserialize, BorshSerialize)]
@@ -50,6 +56,34 @@ pub fn create_test_account() -> TestAccountV1 {
     }
 }
 
+// Stake history for epochs 0 to 19 with a constant warmup/cooldown of 1_000 lamports per epoch.
+pub fn create_test_stake_history(effective: impl Fn(Epoch) -> u64) -> StakeHistory {
+    let mut stake_history = StakeHistory::default();
+    for epoch in 0..20 {
+        stake_history.add(
+            epoch,
+            StakeHistoryEntry {
+                effective: effective(epoch),
+                activating: 1_000,
+                deactivating: 1_000,
+            },
+        );
+    }
+
+    stake_history
+}
+
+// 1_000 lamports delegated in epoch 5 and deactivated in epoch 10.
+pub fn create_test_stake() -> Stake {
+    let mut delegation = Delegation::new(&Pubkey::new_unique(), 1_000, 5);
+    delegation.deactivation_epoch = 10;
+
+    Stake {
+        delegation,
+        credits_observed: 0,
+    }
+}
+
 pub fn assert_is_program_error(err: ProgramError, expected_error: ProgramError) {
     assert_eq!(err, expected_error);
 }
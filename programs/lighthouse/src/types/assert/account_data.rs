@@ -1,14 +1,21 @@
-use super::{Assert, EquatableOperator, IntegerOperator, LogLevel};
+use super::{
+    Assert, AssertionResult, BytesOperator, EquatableOperator, IntegerOperator, LogLevel,
+    OffsetPath, RangeOperator, Ray, SetOperator, StringOperator, Wad, I80F48, U64F64,
+};
 use crate::{
     err, err_msg,
     error::LighthouseError,
     generate_asserts_borsh,
-    types::{assert::evaluate::Evaluate, CompactBytes},
-    utils::Result,
+    types::{
+        assert::evaluate::{Evaluate, EvaluateSet},
+        CompactBytes, CompactPubkeys,
+    },
+    utils::{checked_get_slice, get_account_key, read_unsigned, Result},
 };
 use borsh::{BorshDeserialize, BorshSerialize};
 use lighthouse_common::CompactU64;
-use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+use solana_program::{account_info::AccountInfo, msg, pubkey::Pubkey};
+use std::{cmp::Ordering, slice};
 
 #[derive(BorshDeserialize, BorshSerialize, Debug)]
 pub struct AccountDataAssertion {
@@ -16,6 +23,46 @@ pub struct AccountDataAssertion {
     pub assertion: DataValueAssertion,
 }
 
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub struct AccountDataPathAssertion {
+    pub path: OffsetPath,
+    pub assertion: DataValueAssertion,
+}
+
+/// How a string is stored in account data.
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub enum StringLayout {
+    /// Borsh `String`, a u32 length prefix followed by the bytes.
+    Borsh,
+    /// Fixed size byte array, trailing null and space padding is stripped.
+    Fixed { length: CompactU64 },
+}
+
+impl StringLayout {
+    pub fn read<'a>(&self, data: &'a [u8], offset: usize) -> Result<&'a [u8]> {
+        match self {
+            StringLayout::Borsh => {
+                let length =
+                    u32::from_le_bytes(checked_get_slice(data, offset, 4)?.try_into().unwrap());
+                let value_offset = offset
+                    .checked_add(4)
+                    .ok_or(LighthouseError::RangeOutOfBounds)?;
+
+                checked_get_slice(data, value_offset, length as usize)
+            }
+            StringLayout::Fixed { length } => {
+                let raw_value = checked_get_slice(data, offset, **length as usize)?;
+                let end = raw_value
+                    .iter()
+                    .rposition(|b| *b != 0 && *b != b' ')
+                    .map_or(0, |i| i + 1);
+
+                Ok(&raw_value[..end])
+            }
+        }
+    }
+}
+
 #[derive(BorshDeserialize, BorshSerialize, Debug)]
 pub enum DataValueAssertion {
     Bool {
@@ -70,13 +117,175 @@ pub enum DataValueAssertion {
         value: Pubkey,
         operator: EquatableOperator,
     },
+    PubkeyIn {
+        value: CompactPubkeys,
+        operator: SetOperator,
+    },
+    /// Compares `data & mask` against `value & mask`, `mask` must be the same length as `value`.
+    BytesMasked {
+        value: CompactBytes,
+        mask: CompactBytes,
+        operator: EquatableOperator,
+    },
+    /// Matches `value` against the `length` bytes at the offset, or the rest of the account data
+    /// if no length is given. Contains/DoesNotContain search the whole range so an offset of 0 and
+    /// no length searches the entire account.
+    BytesPattern {
+        value: CompactBytes,
+        length: Option<CompactU64>,
+        operator: BytesOperator,
+    },
+    I80F48 {
+        value: i128,
+        operator: IntegerOperator,
+    },
+    U64F64 {
+        value: u128,
+        operator: IntegerOperator,
+    },
+    Wad {
+        value: u128,
+        operator: IntegerOperator,
+    },
+    Ray {
+        value: u128,
+        operator: IntegerOperator,
+    },
+    /// Compared with IEEE 754 semantics, range and contains operators are not supported.
+    F32 {
+        value: f32,
+        operator: IntegerOperator,
+    },
+    F64 {
+        value: f64,
+        operator: IntegerOperator,
+    },
+    U16BE {
+        value: u16,
+        operator: IntegerOperator,
+    },
+    I16BE {
+        value: i16,
+        operator: IntegerOperator,
+    },
+    U32BE {
+        value: u32,
+        operator: IntegerOperator,
+    },
+    I32BE {
+        value: i32,
+        operator: IntegerOperator,
+    },
+    U64BE {
+        value: u64,
+        operator: IntegerOperator,
+    },
+    I64BE {
+        value: i64,
+        operator: IntegerOperator,
+    },
+    U128BE {
+        value: u128,
+        operator: IntegerOperator,
+    },
+    I128BE {
+        value: i128,
+        operator: IntegerOperator,
+    },
+    /// Unsigned integer stored in `byte_width` bytes (1 to 16).
+    UnsignedInt {
+        value: u128,
+        byte_width: u8,
+        big_endian: bool,
+        operator: IntegerOperator,
+    },
+    /// Reads `byte_width` bytes as an unsigned integer and compares the `bit_width` bits starting
+    /// `shift` bits from the least significant bit.
+    BitField {
+        value: u128,
+        byte_width: u8,
+        big_endian: bool,
+        shift: u8,
+        bit_width: u8,
+        operator: IntegerOperator,
+    },
+    String {
+        value: CompactBytes,
+        layout: StringLayout,
+        operator: StringOperator,
+    },
+    /// Byte length of the string after padding is stripped.
+    StringLength {
+        value: u32,
+        layout: StringLayout,
+        operator: IntegerOperator,
+    },
+    /// Compares against the key of the instruction account at `account_index`.
+    PubkeyAccountRef {
+        account_index: u8,
+        operator: EquatableOperator,
+    },
+    /// Integer stored in `byte_width` bytes (1 to 16) compared against `[lower, upper]`, or
+    /// `(lower, upper)` for the exclusive operators. Signed values are sign extended.
+    IntegerRange {
+        lower: i128,
+        upper: i128,
+        byte_width: u8,
+        signed: bool,
+        big_endian: bool,
+        operator: RangeOperator,
+    },
 }
 
 impl Assert<&AccountInfo<'_>> for AccountDataAssertion {
     fn evaluate(&self, account: &AccountInfo<'_>, log_level: LogLevel) -> Result<()> {
-        let offset = *self.offset as usize;
-        let assertion = &self.assertion;
+        self.evaluate((account, slice::from_ref(account)), log_level)
+    }
+}
+
+impl Assert<(&AccountInfo<'_>, &[AccountInfo<'_>])> for AccountDataAssertion {
+    fn evaluate(
+        &self,
+        (account, accounts): (&AccountInfo<'_>, &[AccountInfo<'_>]),
+        log_level: LogLevel,
+    ) -> Result<()> {
+        self.assertion
+            .evaluate((account, *self.offset as usize, accounts), log_level)
+    }
+}
+
+impl Assert<&AccountInfo<'_>> for AccountDataPathAssertion {
+    fn evaluate(&self, account: &AccountInfo<'_>, log_level: LogLevel) -> Result<()> {
+        self.evaluate((account, slice::from_ref(account)), log_level)
+    }
+}
+
+impl Assert<(&AccountInfo<'_>, &[AccountInfo<'_>])> for AccountDataPathAssertion {
+    fn evaluate(
+        &self,
+        (account, accounts): (&AccountInfo<'_>, &[AccountInfo<'_>]),
+        log_level: LogLevel,
+    ) -> Result<()> {
+        let offset = {
+            let data = account.try_borrow_data().map_err(|e| {
+                err_msg!("Cannot borrow data for target account", e);
+                err!(LighthouseError::AccountBorrowFailed)
+            })?;
+
+            self.path.resolve(&data)?
+        };
+
+        self.assertion
+            .evaluate((account, offset, accounts), log_level)
+    }
+}
 
+impl Assert<(&AccountInfo<'_>, usize, &[AccountInfo<'_>])> for DataValueAssertion {
+    fn evaluate(
+        &self,
+        (account, offset, accounts): (&AccountInfo<'_>, usize, &[AccountInfo<'_>]),
+        log_level: LogLevel,
+    ) -> Result<()> {
         let data = account.try_borrow_data().map_err(|e| {
             err_msg!("Cannot borrow data for target account", e);
             err!(LighthouseError::AccountBorrowFailed)
@@ -86,6 +295,18 @@ impl Assert<&AccountInfo<'_>> for AccountDataAssertion {
             return Err(LighthouseError::AccountNotInitialized.into());
         }
 
+        self.evaluate((&data[..], offset, accounts), log_level)
+    }
+}
+
+impl Assert<(&[u8], usize, &[AccountInfo<'_>])> for DataValueAssertion {
+    fn evaluate(
+        &self,
+        (data, offset, accounts): (&[u8], usize, &[AccountInfo<'_>]),
+        log_level: LogLevel,
+    ) -> Result<()> {
+        let assertion = self;
+
         generate_asserts_borsh!(
             assertion,
             DataValueAssertion,
@@ -104,13 +325,191 @@ impl Assert<&AccountInfo<'_>> for AccountDataAssertion {
                 (U128, u128, offset),
                 (I128, i128, offset),
                 (Pubkey, (Pubkey), offset),
-                (Bytes, ([u8]), offset)
+                (Bytes, ([u8]), offset),
+                (PubkeyIn, [Pubkey], offset),
+                (I80F48, (FixedPoint I80F48 i128), offset),
+                (U64F64, (FixedPoint U64F64 u128), offset),
+                (Wad, (FixedPoint Wad u128), offset),
+                (Ray, (FixedPoint Ray u128), offset),
+                (F32, f32, offset),
+                (F64, f64, offset),
+                (U16BE, (BigEndian u16), offset),
+                (I16BE, (BigEndian i16), offset),
+                (U32BE, (BigEndian u32), offset),
+                (I32BE, (BigEndian i32), offset),
+                (U64BE, (BigEndian u64), offset),
+                (I64BE, (BigEndian i64), offset),
+                (U128BE, (BigEndian u128), offset),
+                (I128BE, (BigEndian i128), offset)
             ],
-            custom_cases: []
+            custom_cases: [
+                (BytesMasked { value, mask, operator }, |value, mask, operator| {
+                    evaluate_masked_bytes(data, offset, value, mask, operator, log_level)
+                }),
+                (BytesPattern { value, length, operator }, |value, length, operator| {
+                    evaluate_bytes_pattern(data, offset, value, length, operator, log_level)
+                }),
+                (UnsignedInt { value, byte_width, big_endian, operator }, |value, byte_width: &u8, big_endian: &bool, operator| {
+                    let actual_value = read_unsigned(data, offset, *byte_width, *big_endian)?;
+
+                    u128::evaluate(&actual_value, value, operator, log_level)
+                }),
+                (BitField { value, byte_width, big_endian, shift, bit_width, operator }, |value, byte_width: &u8, big_endian: &bool, shift: &u8, bit_width: &u8, operator| {
+                    evaluate_bit_field(
+                        data,
+                        offset,
+                        value,
+                        (*byte_width, *big_endian),
+                        (*shift, *bit_width),
+                        operator,
+                        log_level,
+                    )
+                }),
+                (String { value, layout, operator }, |value: &CompactBytes, layout: &StringLayout, operator| {
+                    <[u8]>::evaluate(layout.read(data, offset)?, value, operator, log_level)
+                }),
+                (StringLength { value, layout, operator }, |value, layout: &StringLayout, operator| {
+                    let actual_value = layout.read(data, offset)?.len() as u32;
+
+                    u32::evaluate(&actual_value, value, operator, log_level)
+                }),
+                (PubkeyAccountRef { account_index, operator }, |account_index: &u8, operator| {
+                    let actual_value =
+                        bytemuck::from_bytes::<Pubkey>(checked_get_slice(data, offset, 32)?);
+                    let value = get_account_key(accounts, *account_index)?;
+
+                    Pubkey::evaluate(actual_value, value, operator, log_level)
+                }),
+                (IntegerRange { lower, upper, byte_width, signed, big_endian, operator }, |lower: &i128, upper: &i128, byte_width: &u8, signed: &bool, big_endian: &bool, operator| {
+                    evaluate_integer_range(
+                        data,
+                        offset,
+                        (*lower, *upper),
+                        (*byte_width, *signed, *big_endian),
+                        operator,
+                        log_level,
+                    )
+                })
+            ]
         )
     }
 }
 
+fn evaluate_masked_bytes(
+    data: &[u8],
+    offset: usize,
+    value: &CompactBytes,
+    mask: &CompactBytes,
+    operator: &EquatableOperator,
+    log_level: LogLevel,
+) -> Result<()> {
+    if mask.len() != value.len() {
+        msg!(
+            "Mask length {} does not match value length {}",
+            mask.len(),
+            value.len()
+        );
+        return Err(LighthouseError::InvalidInstructionData.into());
+    }
+
+    let actual_value = checked_get_slice(data, offset, value.len())?;
+    let masked_actual_value = actual_value
+        .iter()
+        .zip(mask.iter())
+        .map(|(a, m)| a & m)
+        .collect::<Vec<u8>>();
+    let masked_assertion_value = value
+        .iter()
+        .zip(mask.iter())
+        .map(|(v, m)| v & m)
+        .collect::<Vec<u8>>();
+
+    <[u8]>::evaluate(
+        &masked_actual_value,
+        &masked_assertion_value,
+        operator,
+        log_level,
+    )
+}
+
+fn evaluate_bytes_pattern(
+    data: &[u8],
+    offset: usize,
+    value: &CompactBytes,
+    length: &Option<CompactU64>,
+    operator: &BytesOperator,
+    log_level: LogLevel,
+) -> Result<()> {
+    let actual_value = match length {
+        Some(length) => checked_get_slice(data, offset, **length as usize)?,
+        None => data
+            .get(offset..)
+            .ok_or_else(|| LighthouseError::oob_err(offset..data.len()))?,
+    };
+
+    <[u8]>::evaluate(actual_value, value, operator, log_level)
+}
+
+fn evaluate_bit_field(
+    data: &[u8],
+    offset: usize,
+    value: &u128,
+    (byte_width, big_endian): (u8, bool),
+    (shift, bit_width): (u8, u8),
+    operator: &IntegerOperator,
+    log_level: LogLevel,
+) -> Result<()> {
+    if bit_width == 0 || shift as u32 + bit_width as u32 > byte_width as u32 * 8 {
+        msg!(
+            "Bit field of width {} at shift {} does not fit in {} bytes",
+            bit_width,
+            shift,
+            byte_width
+        );
+        return Err(LighthouseError::InvalidInstructionData.into());
+    }
+
+    let raw_value = read_unsigned(data, offset, byte_width, big_endian)?;
+    let mask = u128::MAX >> (128 - bit_width as u32);
+    let actual_value = (raw_value >> shift) & mask;
+
+    u128::evaluate(&actual_value, value, operator, log_level)
+}
+
+fn evaluate_integer_range(
+    data: &[u8],
+    offset: usize,
+    (lower, upper): (i128, i128),
+    (byte_width, signed, big_endian): (u8, bool, bool),
+    operator: &RangeOperator,
+    log_level: LogLevel,
+) -> Result<()> {
+    let raw_value = read_unsigned(data, offset, byte_width, big_endian)?;
+
+    // Only unsigned values above i128::MAX fail to widen, which are greater than any bound.
+    let actual_value = if signed {
+        let shift = 128 - byte_width as u32 * 8;
+        Some(((raw_value << shift) as i128) >> shift)
+    } else {
+        i128::try_from(raw_value).ok()
+    };
+
+    let passed = operator.evaluate(lower, upper, |bound| {
+        actual_value.map_or(Ordering::Greater, |value| value.cmp(bound))
+    });
+
+    if log_level != LogLevel::Silent {
+        AssertionResult::Range(actual_value, lower, upper, u8::from(*operator), passed)
+            .log(log_level)?;
+    }
+
+    if passed {
+        Ok(())
+    } else {
+        Err(LighthouseError::AssertionFailed.into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::DataValueAssertion;
@@ -118,7 +517,8 @@ mod tests {
         error::LighthouseError,
         test_utils::{assert_failed, assert_passed, create_test_account},
         types::assert::{
-            AccountDataAssertion, Assert, EquatableOperator, IntegerOperator, LogLevel,
+            AccountDataAssertion, Assert, BytesOperator, EquatableOperator, IntegerOperator,
+            LogLevel, RangeOperator, SetOperator, StringLayout, StringOperator,
         },
     };
     use borsh::BorshSerialize;
@@ -1063,4 +1463,1011 @@ mod tests {
             .evaluate(&account_info, LogLevel::PlaintextMessage),
         );
     }
+
+    #[test]
+    fn evaluate_range_operators() {
+        let pubkey = Pubkey::new_from_array([255; 32]);
+        let lamports = &mut 0;
+        let data: &mut [u8] = &mut [0u8; 64];
+        data[0..8].copy_from_slice(&(-5i64).to_le_bytes());
+        data[8..24].copy_from_slice(&u128::MAX.to_le_bytes());
+
+        let account_info =
+            AccountInfo::new(&pubkey, false, false, lamports, data, &pubkey, false, 0);
+
+        let assertions = vec![
+            (
+                0u8,
+                IntegerOperator::Between {
+                    lower: -5,
+                    upper: 5,
+                },
+                true,
+            ),
+            (
+                0u8,
+                IntegerOperator::Between {
+                    lower: -4,
+                    upper: 5,
+                },
+                false,
+            ),
+            (
+                0u8,
+                IntegerOperator::NotBetween {
+                    lower: -4,
+                    upper: 5,
+                },
+                true,
+            ),
+            (
+                0u8,
+                IntegerOperator::NotBetween {
+                    lower: -5,
+                    upper: 5,
+                },
+                false,
+            ),
+            (
+                0u8,
+                IntegerOperator::BetweenExclusive {
+                    lower: -6,
+                    upper: 0,
+                },
+                true,
+            ),
+            (
+                0u8,
+                IntegerOperator::BetweenExclusive {
+                    lower: -5,
+                    upper: 0,
+                },
+                false,
+            ),
+            (
+                0u8,
+                IntegerOperator::NotBetweenExclusive {
+                    lower: -5,
+                    upper: 0,
+                },
+                true,
+            ),
+            (
+                0u8,
+                IntegerOperator::NotBetweenExclusive {
+                    lower: -6,
+                    upper: 0,
+                },
+                false,
+            ),
+            // Inverted bounds never contain a value.
+            (
+                0u8,
+                IntegerOperator::Between {
+                    lower: 5,
+                    upper: -5,
+                },
+                false,
+            ),
+        ];
+
+        for (offset, operator, should_pass) in assertions {
+            let result = AccountDataAssertion {
+                offset: offset.into(),
+                assertion: DataValueAssertion::I64 { value: 0, operator },
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage);
+
+            if should_pass {
+                assert_passed(result);
+            } else {
+                assert_failed(result);
+            }
+        }
+
+        // u128 values above i128::MAX are greater than every bound.
+        assert_failed(
+            AccountDataAssertion {
+                offset: 8u8.into(),
+                assertion: DataValueAssertion::U128 {
+                    value: 0,
+                    operator: IntegerOperator::Between {
+                        lower: 0,
+                        upper: i128::MAX,
+                    },
+                },
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage),
+        );
+
+        assert_passed(
+            AccountDataAssertion {
+                offset: 8u8.into(),
+                assertion: DataValueAssertion::U128 {
+                    value: 0,
+                    operator: IntegerOperator::NotBetween {
+                        lower: 0,
+                        upper: i128::MAX,
+                    },
+                },
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage),
+        );
+    }
+
+    #[test]
+    fn evaluate_integer_range() {
+        let pubkey = Pubkey::new_from_array([255; 32]);
+        let lamports = &mut 0;
+        let data: &mut [u8] = &mut [0u8; 64];
+        data[0..8].copy_from_slice(&(-5i64).to_le_bytes());
+        data[8..24].copy_from_slice(&u128::MAX.to_le_bytes());
+        data[24..27].copy_from_slice(&[0x01, 0x00, 0xff]);
+
+        let account_info =
+            AccountInfo::new(&pubkey, false, false, lamports, data, &pubkey, false, 0);
+
+        let assertions = vec![
+            (0u8, 8, true, false, RangeOperator::Between, (-5, 5), true),
+            (
+                0u8,
+                8,
+                true,
+                false,
+                RangeOperator::BetweenExclusive,
+                (-5, 5),
+                false,
+            ),
+            (
+                0u8,
+                8,
+                true,
+                false,
+                RangeOperator::NotBetween,
+                (-4, 5),
+                true,
+            ),
+            (
+                0u8,
+                8,
+                true,
+                false,
+                RangeOperator::NotBetweenExclusive,
+                (-6, 0),
+                false,
+            ),
+            // Read as unsigned, the same bytes are far above the bounds.
+            (0u8, 8, false, false, RangeOperator::Between, (-5, 5), false),
+            // u128 values above i128::MAX are greater than every bound.
+            (
+                8u8,
+                16,
+                false,
+                false,
+                RangeOperator::NotBetween,
+                (0, i128::MAX),
+                true,
+            ),
+            (8u8, 16, true, false, RangeOperator::Between, (-1, -1), true),
+            // 0x0100ff as a big endian 24-bit integer.
+            (
+                24u8,
+                3,
+                false,
+                true,
+                RangeOperator::Between,
+                (0x0100ff, 0x0100ff),
+                true,
+            ),
+            (
+                24u8,
+                3,
+                true,
+                false,
+                RangeOperator::Between,
+                (-0xffff, -0xffff),
+                true,
+            ),
+            // Inverted bounds never contain a value.
+            (0u8, 8, true, false, RangeOperator::Between, (5, -5), false),
+        ];
+
+        for (offset, byte_width, signed, big_endian, operator, (lower, upper), should_pass) in
+            assertions
+        {
+            let result = AccountDataAssertion {
+                offset: offset.into(),
+                assertion: DataValueAssertion::IntegerRange {
+                    lower,
+                    upper,
+                    byte_width,
+                    signed,
+                    big_endian,
+                    operator,
+                },
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage);
+
+            if should_pass {
+                assert_passed(result);
+            } else {
+                assert_failed(result);
+            }
+        }
+
+        for code in 0u8..8 {
+            let operator = IntegerOperator::try_from(code).unwrap();
+            assert_eq!(u8::from(operator), code);
+        }
+        for code in 8u8..12 {
+            assert!(IntegerOperator::try_from(code).is_err());
+            let operator = RangeOperator::try_from(code).unwrap();
+            assert_eq!(u8::from(operator), code);
+        }
+        assert!(IntegerOperator::try_from(12u8).is_err());
+        assert!(RangeOperator::try_from(12u8).is_err());
+    }
+
+    #[test]
+    fn evaluate_pubkey_in() {
+        let pubkey = Pubkey::new_from_array([255; 32]);
+        let other = Pubkey::new_unique();
+        let lamports = &mut 0;
+        let data: &mut [u8] = &mut [0u8; 64];
+        data[32..64].copy_from_slice(pubkey.as_ref());
+
+        let account_info =
+            AccountInfo::new(&pubkey, false, false, lamports, data, &pubkey, false, 0);
+
+        let assertions = vec![
+            (vec![other, pubkey], SetOperator::In, true),
+            (vec![other], SetOperator::In, false),
+            (vec![], SetOperator::In, false),
+            (vec![other], SetOperator::NotIn, true),
+            (vec![pubkey, other], SetOperator::NotIn, false),
+        ];
+
+        for (candidates, operator, should_pass) in assertions {
+            let result = AccountDataAssertion {
+                offset: 32u8.into(),
+                assertion: DataValueAssertion::PubkeyIn {
+                    value: candidates.into(),
+                    operator,
+                },
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage);
+
+            if should_pass {
+                assert_passed(result);
+            } else {
+                assert_failed(result);
+            }
+        }
+    }
+
+    #[test]
+    fn evaluate_bytes_masked() {
+        let pubkey = Pubkey::new_from_array([255; 32]);
+        let lamports = &mut 0;
+        let data: &mut [u8] = &mut [0u8; 64];
+        data[8..11].copy_from_slice(&[0b1010_0001, 0b0000_1111, 0xff]);
+
+        let account_info =
+            AccountInfo::new(&pubkey, false, false, lamports, data, &pubkey, false, 0);
+
+        let assertions = vec![
+            (
+                vec![0b0000_0001, 0b0000_1000, 0x00],
+                vec![0b0000_0001, 0b0000_1000, 0x00],
+                EquatableOperator::Equal,
+                true,
+            ),
+            (
+                vec![0b0000_0001, 0b1000_0000, 0x00],
+                vec![0b0000_0001, 0b1000_0000, 0x00],
+                EquatableOperator::Equal,
+                false,
+            ),
+            (
+                vec![0b0000_0001, 0b1000_0000, 0x00],
+                vec![0b0000_0001, 0b1000_0000, 0x00],
+                EquatableOperator::NotEqual,
+                true,
+            ),
+            // Bits outside of the mask are ignored.
+            (
+                vec![0x00, 0x00, 0x00],
+                vec![0x00, 0x00, 0x00],
+                EquatableOperator::Equal,
+                true,
+            ),
+        ];
+
+        for (value, mask, operator, should_pass) in assertions {
+            let result = AccountDataAssertion {
+                offset: 8u8.into(),
+                assertion: DataValueAssertion::BytesMasked {
+                    value: value.into(),
+                    mask: mask.into(),
+                    operator,
+                },
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage);
+
+            if should_pass {
+                assert_passed(result);
+            } else {
+                assert_failed(result);
+            }
+        }
+
+        let result = AccountDataAssertion {
+            offset: 8u8.into(),
+            assertion: DataValueAssertion::BytesMasked {
+                value: vec![0x00, 0x00].into(),
+                mask: vec![0xff].into(),
+                operator: EquatableOperator::Equal,
+            },
+        }
+        .evaluate(&account_info, LogLevel::PlaintextMessage);
+
+        assert_eq!(
+            result.err().unwrap(),
+            LighthouseError::InvalidInstructionData.into()
+        );
+    }
+
+    #[test]
+    fn evaluate_bytes_pattern() {
+        let key = Pubkey::new_from_array([255; 32]);
+        let allowed = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let lamports = &mut 0;
+
+        // Anchor style account with a `Vec<Pubkey>` allowlist after the discriminator.
+        let mut account_data = vec![7u8; 8];
+        account_data.extend((allowed.len() as u32).to_le_bytes());
+        allowed
+            .iter()
+            .for_each(|a| account_data.extend_from_slice(a.as_ref()));
+        let data: &mut [u8] = &mut account_data;
+
+        let account_info = AccountInfo::new(&key, false, false, lamports, data, &key, false, 0);
+
+        let assertions = vec![
+            (
+                0u8,
+                None,
+                allowed[1].to_bytes().to_vec(),
+                BytesOperator::Contains,
+                true,
+            ),
+            (
+                12u8,
+                Some(32u8),
+                allowed[1].to_bytes().to_vec(),
+                BytesOperator::Contains,
+                false,
+            ),
+            (
+                12u8,
+                Some(32u8),
+                allowed[1].to_bytes().to_vec(),
+                BytesOperator::DoesNotContain,
+                true,
+            ),
+            (
+                0u8,
+                None,
+                key.to_bytes().to_vec(),
+                BytesOperator::Contains,
+                false,
+            ),
+            (
+                0u8,
+                None,
+                key.to_bytes().to_vec(),
+                BytesOperator::DoesNotContain,
+                true,
+            ),
+            (0u8, None, vec![7u8; 8], BytesOperator::StartsWith, true),
+            (
+                12u8,
+                None,
+                allowed[0].to_bytes().to_vec(),
+                BytesOperator::StartsWith,
+                true,
+            ),
+            (
+                12u8,
+                None,
+                allowed[1].to_bytes().to_vec(),
+                BytesOperator::StartsWith,
+                false,
+            ),
+            // Patterns longer than the range never match.
+            (
+                12u8,
+                Some(8u8),
+                allowed[0].to_bytes().to_vec(),
+                BytesOperator::StartsWith,
+                false,
+            ),
+            (
+                12u8,
+                Some(8u8),
+                allowed[0].to_bytes().to_vec(),
+                BytesOperator::Contains,
+                false,
+            ),
+        ];
+
+        for (offset, length, value, operator, should_pass) in assertions {
+            let result = AccountDataAssertion {
+                offset: offset.into(),
+                assertion: DataValueAssertion::BytesPattern {
+                    value: value.into(),
+                    length: length.map(|l| l.into()),
+                    operator,
+                },
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage);
+
+            if should_pass {
+                assert_passed(result);
+            } else {
+                assert_failed(result);
+            }
+        }
+
+        // Ranges past the end of the account data are an error rather than a failed assertion.
+        let result = AccountDataAssertion {
+            offset: 12u8.into(),
+            assertion: DataValueAssertion::BytesPattern {
+                value: vec![0].into(),
+                length: Some(128u8.into()),
+                operator: BytesOperator::Contains,
+            },
+        }
+        .evaluate(&account_info, LogLevel::PlaintextMessage);
+
+        assert_eq!(
+            result.err().unwrap(),
+            LighthouseError::RangeOutOfBounds.into()
+        );
+    }
+
+    #[test]
+    fn evaluate_fixed_point() {
+        let pubkey = Pubkey::new_from_array([255; 32]);
+        let lamports = &mut 0;
+        let data: &mut [u8] = &mut [0u8; 64];
+
+        // -1.5 as I80F48 followed by 2.25 as WAD.
+        data[0..16].copy_from_slice(&(-(3i128 << 47)).to_le_bytes());
+        data[16..32].copy_from_slice(&2_250_000_000_000_000_000u128.to_le_bytes());
+
+        let account_info =
+            AccountInfo::new(&pubkey, false, false, lamports, data, &pubkey, false, 0);
+
+        let assertions = vec![
+            (
+                DataValueAssertion::I80F48 {
+                    value: -(3 << 47),
+                    operator: IntegerOperator::Equal,
+                },
+                0u8,
+                true,
+            ),
+            (
+                DataValueAssertion::I80F48 {
+                    value: -(1 << 48),
+                    operator: IntegerOperator::LessThan,
+                },
+                0u8,
+                true,
+            ),
+            (
+                DataValueAssertion::I80F48 {
+                    value: 0,
+                    operator: IntegerOperator::GreaterThan,
+                },
+                0u8,
+                false,
+            ),
+            (
+                DataValueAssertion::I80F48 {
+                    value: 0,
+                    operator: IntegerOperator::Between {
+                        lower: -(2 << 48),
+                        upper: -(1 << 48),
+                    },
+                },
+                0u8,
+                true,
+            ),
+            (
+                DataValueAssertion::Wad {
+                    value: 2 * 10u128.pow(18),
+                    operator: IntegerOperator::GreaterThan,
+                },
+                16u8,
+                true,
+            ),
+            (
+                DataValueAssertion::Ray {
+                    value: 2_250_000_000_000_000_000,
+                    operator: IntegerOperator::Equal,
+                },
+                16u8,
+                true,
+            ),
+            (
+                DataValueAssertion::U64F64 {
+                    value: 1 << 64,
+                    operator: IntegerOperator::GreaterThanOrEqual,
+                },
+                16u8,
+                false,
+            ),
+        ];
+
+        for (assertion, offset, should_pass) in assertions {
+            let result = AccountDataAssertion {
+                offset: offset.into(),
+                assertion,
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage);
+
+            if should_pass {
+                assert_passed(result);
+            } else {
+                assert_failed(result);
+            }
+        }
+    }
+
+    #[test]
+    fn evaluate_float() {
+        let pubkey = Pubkey::new_from_array([255; 32]);
+        let lamports = &mut 0;
+        let data: &mut [u8] = &mut [0u8; 64];
+
+        data[0..4].copy_from_slice(&(-1.5f32).to_le_bytes());
+        data[4..8].copy_from_slice(&f32::NAN.to_le_bytes());
+        data[8..16].copy_from_slice(&f64::INFINITY.to_le_bytes());
+        data[16..24].copy_from_slice(&(-0.0f64).to_le_bytes());
+
+        let account_info =
+            AccountInfo::new(&pubkey, false, false, lamports, data, &pubkey, false, 0);
+
+        let assertions = vec![
+            (
+                DataValueAssertion::F32 {
+                    value: -1.5,
+                    operator: IntegerOperator::Equal,
+                },
+                0u8,
+                true,
+            ),
+            (
+                DataValueAssertion::F32 {
+                    value: -2.0,
+                    operator: IntegerOperator::GreaterThan,
+                },
+                0u8,
+                true,
+            ),
+            (
+                DataValueAssertion::F32 {
+                    value: 0.0,
+                    operator: IntegerOperator::GreaterThanOrEqual,
+                },
+                0u8,
+                false,
+            ),
+            // NaN is unordered, only NotEqual passes.
+            (
+                DataValueAssertion::F32 {
+                    value: 0.0,
+                    operator: IntegerOperator::LessThan,
+                },
+                4u8,
+                false,
+            ),
+            (
+                DataValueAssertion::F32 {
+                    value: f32::NAN,
+                    operator: IntegerOperator::Equal,
+                },
+                4u8,
+                false,
+            ),
+            (
+                DataValueAssertion::F32 {
+                    value: f32::NAN,
+                    operator: IntegerOperator::NotEqual,
+                },
+                4u8,
+                true,
+            ),
+            (
+                DataValueAssertion::F64 {
+                    value: f64::MAX,
+                    operator: IntegerOperator::GreaterThan,
+                },
+                8u8,
+                true,
+            ),
+            (
+                DataValueAssertion::F64 {
+                    value: f64::INFINITY,
+                    operator: IntegerOperator::Equal,
+                },
+                8u8,
+                true,
+            ),
+            // -0.0 and 0.0 compare equal.
+            (
+                DataValueAssertion::F64 {
+                    value: 0.0,
+                    operator: IntegerOperator::Equal,
+                },
+                16u8,
+                true,
+            ),
+        ];
+
+        for (assertion, offset, should_pass) in assertions {
+            let result = AccountDataAssertion {
+                offset: offset.into(),
+                assertion,
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage);
+
+            if should_pass {
+                assert_passed(result);
+            } else {
+                assert_failed(result);
+            }
+        }
+
+        let result = AccountDataAssertion {
+            offset: 0u8.into(),
+            assertion: DataValueAssertion::F32 {
+                value: 0.0,
+                operator: IntegerOperator::Between {
+                    lower: -2,
+                    upper: 2,
+                },
+            },
+        }
+        .evaluate(&account_info, LogLevel::PlaintextMessage);
+
+        assert_eq!(
+            result.err().unwrap(),
+            LighthouseError::InvalidInstructionData.into()
+        );
+    }
+
+    #[test]
+    fn evaluate_big_endian_and_packed() {
+        let pubkey = Pubkey::new_from_array([255; 32]);
+        let lamports = &mut 0;
+        let data: &mut [u8] = &mut [0u8; 64];
+
+        data[0..4].copy_from_slice(&(-2i32).to_be_bytes());
+        data[4..12].copy_from_slice(&0x0102_0304_0506_0708u64.to_be_bytes());
+        // 3 byte little-endian and 5 byte big-endian integers.
+        data[12..15].copy_from_slice(&[0x03, 0x02, 0x01]);
+        data[15..20].copy_from_slice(&[0x01, 0x02, 0x03, 0x04, 0x05]);
+        // Flag in bit 0, 4 bit state in bits 1..5 and a counter in bits 8..40.
+        let packed: u64 = 1 | (0b1010 << 1) | (1234 << 8);
+        data[20..28].copy_from_slice(&packed.to_le_bytes());
+
+        let account_info =
+            AccountInfo::new(&pubkey, false, false, lamports, data, &pubkey, false, 0);
+
+        let assertions = vec![
+            (
+                DataValueAssertion::I32BE {
+                    value: -2,
+                    operator: IntegerOperator::Equal,
+                },
+                0u8,
+                true,
+            ),
+            (
+                DataValueAssertion::I32 {
+                    value: -2,
+                    operator: IntegerOperator::Equal,
+                },
+                0u8,
+                false,
+            ),
+            (
+                DataValueAssertion::U64BE {
+                    value: 0x0102_0304_0506_0708,
+                    operator: IntegerOperator::Equal,
+                },
+                4u8,
+                true,
+            ),
+            (
+                DataValueAssertion::U16BE {
+                    value: 0x0102,
+                    operator: IntegerOperator::Equal,
+                },
+                4u8,
+                true,
+            ),
+            (
+                DataValueAssertion::UnsignedInt {
+                    value: 0x01_0203,
+                    byte_width: 3,
+                    big_endian: false,
+                    operator: IntegerOperator::Equal,
+                },
+                12u8,
+                true,
+            ),
+            (
+                DataValueAssertion::UnsignedInt {
+                    value: 0x01_0203_0405,
+                    byte_width: 5,
+                    big_endian: true,
+                    operator: IntegerOperator::Equal,
+                },
+                15u8,
+                true,
+            ),
+            (
+                DataValueAssertion::UnsignedInt {
+                    value: 0x01_0203_0405,
+                    byte_width: 5,
+                    big_endian: false,
+                    operator: IntegerOperator::Equal,
+                },
+                15u8,
+                false,
+            ),
+            (
+                DataValueAssertion::BitField {
+                    value: 1,
+                    byte_width: 8,
+                    big_endian: false,
+                    shift: 0,
+                    bit_width: 1,
+                    operator: IntegerOperator::Equal,
+                },
+                20u8,
+                true,
+            ),
+            (
+                DataValueAssertion::BitField {
+                    value: 0b1010,
+                    byte_width: 8,
+                    big_endian: false,
+                    shift: 1,
+                    bit_width: 4,
+                    operator: IntegerOperator::Equal,
+                },
+                20u8,
+                true,
+            ),
+            (
+                DataValueAssertion::BitField {
+                    value: 1000,
+                    byte_width: 8,
+                    big_endian: false,
+                    shift: 8,
+                    bit_width: 32,
+                    operator: IntegerOperator::GreaterThan,
+                },
+                20u8,
+                true,
+            ),
+        ];
+
+        for (assertion, offset, should_pass) in assertions {
+            let result = AccountDataAssertion {
+                offset: offset.into(),
+                assertion,
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage);
+
+            if should_pass {
+                assert_passed(result);
+            } else {
+                assert_failed(result);
+            }
+        }
+
+        for assertion in [
+            DataValueAssertion::UnsignedInt {
+                value: 0,
+                byte_width: 17,
+                big_endian: false,
+                operator: IntegerOperator::Equal,
+            },
+            DataValueAssertion::BitField {
+                value: 0,
+                byte_width: 1,
+                big_endian: false,
+                shift: 4,
+                bit_width: 5,
+                operator: IntegerOperator::Equal,
+            },
+        ] {
+            let result = AccountDataAssertion {
+                offset: 0u8.into(),
+                assertion,
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage);
+
+            assert_eq!(
+                result.err().unwrap(),
+                LighthouseError::InvalidInstructionData.into()
+            );
+        }
+    }
+
+    #[test]
+    fn evaluate_string() {
+        let pubkey = Pubkey::new_from_array([255; 32]);
+        let lamports = &mut 0;
+
+        // Metaplex style null padded Borsh string followed by a space padded fixed array.
+        let mut account_data = 32u32.to_le_bytes().to_vec();
+        let mut uri = b"https://arweave.net/abc".to_vec();
+        uri.resize(32, 0);
+        account_data.extend(uri);
+        let mut name = b"Lighthouse".to_vec();
+        name.resize(16, b' ');
+        account_data.extend(name);
+        let data: &mut [u8] = &mut account_data;
+
+        let account_info =
+            AccountInfo::new(&pubkey, false, false, lamports, data, &pubkey, false, 0);
+
+        let mut padded_uri = b"https://arweave.net/abc".to_vec();
+        padded_uri.resize(32, 0);
+
+        // Only fixed size strings have their padding stripped.
+        let assertions = vec![
+            (
+                padded_uri,
+                StringLayout::Borsh,
+                0u8,
+                StringOperator::Equal,
+                true,
+            ),
+            (
+                b"https://arweave.net/abc".to_vec(),
+                StringLayout::Borsh,
+                0u8,
+                StringOperator::Equal,
+                false,
+            ),
+            (
+                b"https://arweave.net/".to_vec(),
+                StringLayout::Borsh,
+                0u8,
+                StringOperator::StartsWith,
+                true,
+            ),
+            (
+                b"https://arweave.net/".to_vec(),
+                StringLayout::Borsh,
+                0u8,
+                StringOperator::Equal,
+                false,
+            ),
+            (
+                b"/abc".to_vec(),
+                StringLayout::Borsh,
+                0u8,
+                StringOperator::EndsWith,
+                false,
+            ),
+            (
+                b"Lighthouse".to_vec(),
+                StringLayout::Fixed {
+                    length: 16u8.into(),
+                },
+                36u8,
+                StringOperator::Equal,
+                true,
+            ),
+            (
+                b"Lighthouse".to_vec(),
+                StringLayout::Fixed {
+                    length: 16u8.into(),
+                },
+                36u8,
+                StringOperator::NotEqual,
+                false,
+            ),
+            (
+                b"house ".to_vec(),
+                StringLayout::Fixed {
+                    length: 16u8.into(),
+                },
+                36u8,
+                StringOperator::EndsWith,
+                false,
+            ),
+        ];
+
+        for (value, layout, offset, operator, should_pass) in assertions {
+            let result = AccountDataAssertion {
+                offset: offset.into(),
+                assertion: DataValueAssertion::String {
+                    value: value.into(),
+                    layout,
+                    operator,
+                },
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage);
+
+            if should_pass {
+                assert_passed(result);
+            } else {
+                assert_failed(result);
+            }
+        }
+
+        let assertions = vec![
+            (StringLayout::Borsh, 0u8, 32, IntegerOperator::Equal, true),
+            (
+                StringLayout::Borsh,
+                0u8,
+                32,
+                IntegerOperator::LessThanOrEqual,
+                true,
+            ),
+            (
+                StringLayout::Fixed {
+                    length: 16u8.into(),
+                },
+                36u8,
+                8,
+                IntegerOperator::LessThanOrEqual,
+                false,
+            ),
+        ];
+
+        for (layout, offset, value, operator, should_pass) in assertions {
+            let result = AccountDataAssertion {
+                offset: offset.into(),
+                assertion: DataValueAssertion::StringLength {
+                    value,
+                    layout,
+                    operator,
+                },
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage);
+
+            if should_pass {
+                assert_passed(result);
+            } else {
+                assert_failed(result);
+            }
+        }
+
+        // Length prefix points past the end of the account.
+        let result = AccountDataAssertion {
+            offset: 36u8.into(),
+            assertion: DataValueAssertion::String {
+                value: vec![].into(),
+                layout: StringLayout::Borsh,
+                operator: StringOperator::Equal,
+            },
+        }
+        .evaluate(&account_info, LogLevel::PlaintextMessage);
+
+        assert_eq!(
+            result.err().unwrap(),
+            LighthouseError::RangeOutOfBounds.into()
+        );
+    }
 }
@@ -1,12 +1,18 @@
-use super::{Assert, EquatableOperator, IntegerOperator, LogLevel};
+use super::{Assert, AssertionResult, EquatableOperator, IntegerOperator, LogLevel, Operator};
 use crate::{
     error::LighthouseError,
-    types::assert::evaluate::Evaluate,
+    types::{
+        assert::evaluate::Evaluate,
+        write::{AccountHashScope, HashAlgorithm, MemoryHeader, WriteTypeTag},
+    },
     utils::{checked_get_slice, try_from_slice, Result},
 };
 use borsh::{BorshDeserialize, BorshSerialize};
 use lighthouse_common::CompactU64;
-use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, msg, pubkey::Pubkey, sysvar::Sysvar,
+};
+use std::cmp::Ordering;
 
 #[derive(BorshDeserialize, BorshSerialize)]
 pub enum AccountDeltaAssertion {
@@ -19,6 +25,74 @@ pub enum AccountDeltaAssertion {
         b_offset: CompactU64,
         assertion: DataValueDeltaAssertion,
     },
+    // Same as `Data`/`AccountInfo`, but the memory value of account a at `a_offset` must first pass
+    // the memory guard.
+    GuardedData {
+        guard: MemoryGuard,
+        a_offset: CompactU64,
+        b_offset: CompactU64,
+        assertion: DataValueDeltaAssertion,
+    },
+    GuardedAccountInfo {
+        guard: MemoryGuard,
+        a_offset: CompactU64,
+        assertion: AccountInfoDeltaAssertion,
+    },
+}
+
+// An entry of `AssertMemoryDeltaAndClose`, `memory_index` indexes the memory accounts of the
+// instruction and `account_index` the accounts following them.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct MemoryDeltaAssertion {
+    pub memory_index: u8,
+    pub account_index: u8,
+    pub assertion: AccountDeltaAssertion,
+}
+
+// Checks the `MemoryHeader` of a Lighthouse memory account, rejecting a value that was not written
+// in the current slot, under a different transaction nonce or by an unexpected type of write. The
+// slot and type are those the program recorded for the write at the guarded offset.
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub struct MemoryGuard {
+    pub current_slot: bool,
+    pub nonce: Option<u64>,
+    pub type_tag: Option<WriteTypeTag>,
+}
+
+impl<'a, 'info> Assert<(&'a AccountInfo<'info>, u64)> for MemoryGuard {
+    fn evaluate(
+        &self,
+        (memory, offset): (&'a AccountInfo<'info>, u64),
+        log_level: LogLevel,
+    ) -> Result<()> {
+        let header = MemoryHeader::read_account(memory)?;
+
+        if let Some(nonce) = self.nonce {
+            u64::evaluate(&header.nonce, &nonce, &IntegerOperator::Equal, log_level)?;
+        }
+
+        if !self.current_slot && self.type_tag.is_none() {
+            return Ok(());
+        }
+
+        let tag = header.expect_tag(offset)?;
+
+        if self.current_slot {
+            let slot = Clock::get()?.slot;
+            u64::evaluate(&tag.slot, &slot, &IntegerOperator::Equal, log_level)?;
+        }
+
+        if let Some(type_tag) = self.type_tag {
+            u8::evaluate(
+                &(tag.tag as u8),
+                &(type_tag as u8),
+                &IntegerOperator::Equal,
+                log_level,
+            )?;
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(BorshDeserialize, BorshSerialize, Debug)]
@@ -59,6 +133,179 @@ pub enum DataValueDeltaAssertion {
         length: u16,
         operator: EquatableOperator,
     },
+    U8Bps {
+        value: i32,
+        absolute: bool,
+        operator: IntegerOperator,
+    },
+    I8Bps {
+        value: i32,
+        absolute: bool,
+        operator: IntegerOperator,
+    },
+    U16Bps {
+        value: i32,
+        absolute: bool,
+        operator: IntegerOperator,
+    },
+    I16Bps {
+        value: i32,
+        absolute: bool,
+        operator: IntegerOperator,
+    },
+    U32Bps {
+        value: i32,
+        absolute: bool,
+        operator: IntegerOperator,
+    },
+    I32Bps {
+        value: i32,
+        absolute: bool,
+        operator: IntegerOperator,
+    },
+    U64Bps {
+        value: i32,
+        absolute: bool,
+        operator: IntegerOperator,
+    },
+    I64Bps {
+        value: i32,
+        absolute: bool,
+        operator: IntegerOperator,
+    },
+    U128 {
+        value: i128,
+        operator: IntegerOperator,
+    },
+    I128 {
+        value: i128,
+        operator: IntegerOperator,
+    },
+    Pubkey {
+        operator: EquatableOperator,
+    },
+}
+
+pub const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Evaluates the delta `b - a`, or `|b - a|` if `absolute` is set, against `value` basis points
+/// of `|a|`. The absolute form bounds a change in either direction with a single assertion.
+///
+/// Both sides are scaled instead of dividing, so the comparison performed is
+/// `(b - a) * 10_000 <operator> |a| * value`. For values up to 64 bits and an i32 basis point
+/// value neither intermediate can overflow an i128. Range operator bounds are also given in basis
+/// points and are scaled the same way, saturating bounds still order correctly against the scaled
+/// delta as it never exceeds 2^79. `value` is not used by range operators and must be zero.
+///
+/// The bitmask operators have no meaning for a scaled delta and are rejected.
+pub fn evaluate_bps_delta(
+    a_value: i128,
+    b_value: i128,
+    value: i32,
+    absolute: bool,
+    operator: &IntegerOperator,
+    log_level: LogLevel,
+) -> Result<()> {
+    if matches!(
+        operator,
+        IntegerOperator::Contains | IntegerOperator::DoesNotContain
+    ) {
+        msg!(
+            "Operator {} is not supported for basis point deltas",
+            operator.format()
+        );
+        return Err(LighthouseError::InvalidInstructionData.into());
+    }
+
+    if operator.bounds().is_some() && value != 0 {
+        msg!(
+            "Basis point value {} must be zero for range operator {}",
+            value,
+            operator.format()
+        );
+        return Err(LighthouseError::InvalidInstructionData.into());
+    }
+
+    let diff = if absolute {
+        (b_value - a_value).abs()
+    } else {
+        b_value - a_value
+    };
+    let scaled_diff = diff * BPS_DENOMINATOR;
+    let scaled_tolerance = a_value.abs() * value as i128;
+    let scaled_operator = operator.map_bounds(|bound| bound.saturating_mul(a_value.abs()));
+
+    i128::evaluate(&scaled_diff, &scaled_tolerance, &scaled_operator, log_level)
+}
+
+/// Evaluates a 128-bit delta given as a sign and magnitude.
+///
+/// `b - a` for 128-bit values spans `(-2^128, 2^128)` which does not fit in an i128, so the
+/// delta is compared against the assertion value by sign and then magnitude. The bitmask
+/// operators are evaluated against the low 128 bits of the delta.
+pub fn evaluate_wide_delta(
+    is_negative: bool,
+    magnitude: u128,
+    assertion_value: &i128,
+    operator: &IntegerOperator,
+    log_level: LogLevel,
+) -> Result<()> {
+    let cmp = |bound: &i128| match (is_negative, bound.is_negative()) {
+        (false, true) => Ordering::Greater,
+        (true, false) => Ordering::Less,
+        (false, false) => magnitude.cmp(&bound.unsigned_abs()),
+        (true, true) => bound.unsigned_abs().cmp(&magnitude),
+    };
+    let ordering = cmp(assertion_value);
+
+    let wrapped_diff = if is_negative {
+        magnitude.wrapping_neg() as i128
+    } else {
+        magnitude as i128
+    };
+
+    let passed = match operator {
+        IntegerOperator::Equal => ordering == Ordering::Equal,
+        IntegerOperator::NotEqual => ordering != Ordering::Equal,
+        IntegerOperator::GreaterThan => ordering == Ordering::Greater,
+        IntegerOperator::LessThan => ordering == Ordering::Less,
+        IntegerOperator::GreaterThanOrEqual => ordering != Ordering::Less,
+        IntegerOperator::LessThanOrEqual => ordering != Ordering::Greater,
+        IntegerOperator::Contains => wrapped_diff & assertion_value == *assertion_value,
+        IntegerOperator::DoesNotContain => wrapped_diff & assertion_value == 0,
+        IntegerOperator::Between { .. }
+        | IntegerOperator::NotBetween { .. }
+        | IntegerOperator::BetweenExclusive { .. }
+        | IntegerOperator::NotBetweenExclusive { .. } => operator.evaluate_range(cmp),
+    };
+
+    if log_level != LogLevel::Silent {
+        // Deltas outside of the i128 range are logged as None.
+        let actual_value = if is_negative {
+            0i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        };
+
+        match operator.bounds() {
+            Some((lower, upper)) => {
+                AssertionResult::Range(actual_value, lower, upper, u8::from(*operator), passed)
+            }
+            None => AssertionResult::I128(
+                actual_value,
+                Some(*assertion_value),
+                u8::from(*operator),
+                passed,
+            ),
+        }
+        .log(log_level)?;
+    }
+
+    if passed {
+        Ok(())
+    } else {
+        Err(LighthouseError::AssertionFailed.into())
+    }
 }
 
 impl<'a, 'info> Assert<(&'a AccountInfo<'info>, &'a AccountInfo<'info>)> for AccountDeltaAssertion {
@@ -72,164 +319,412 @@ impl<'a, 'info> Assert<(&'a AccountInfo<'info>, &'a AccountInfo<'info>)> for Acc
                 a_offset,
                 b_offset,
                 assertion,
+            } => evaluate_data_delta(
+                accounts,
+                **a_offset as usize,
+                **b_offset as usize,
+                assertion,
+                log_level,
+            ),
+            AccountDeltaAssertion::AccountInfo {
+                a_offset,
+                assertion,
+            } => evaluate_account_info_delta(accounts, **a_offset as usize, assertion, log_level),
+            AccountDeltaAssertion::GuardedData {
+                guard,
+                a_offset,
+                b_offset,
+                assertion,
             } => {
-                let a_offset = **a_offset as usize;
-                let b_offset = **b_offset as usize;
-
-                let (a_account, b_account) = accounts;
-
-                let a_account_data = a_account
-                    .try_borrow_data()
-                    .map_err(LighthouseError::failed_borrow_err)?;
-                let b_account_data = b_account
-                    .try_borrow_data()
-                    .map_err(LighthouseError::failed_borrow_err)?;
-
-                if a_account_data.is_empty() || b_account_data.is_empty() {
-                    return Err(LighthouseError::AccountNotInitialized.into());
-                }
-
-                match assertion {
-                    DataValueDeltaAssertion::U8 {
-                        value: assertion_value,
-                        operator,
-                    } => {
-                        let a_value = try_from_slice::<u8>(&a_account_data, a_offset)?;
-                        let b_value = try_from_slice::<u8>(&b_account_data, b_offset)?;
-                        let diff_value = b_value as i16 - a_value as i16;
-
-                        i16::evaluate(&diff_value, assertion_value, operator, log_level)
-                    }
-                    DataValueDeltaAssertion::I8 {
-                        value: assertion_value,
-                        operator,
-                    } => {
-                        let a_value = try_from_slice::<i8>(&a_account_data, a_offset)?;
-                        let b_value = try_from_slice::<i8>(&b_account_data, b_offset)?;
-                        let diff_value = b_value as i16 - a_value as i16;
-
-                        i16::evaluate(&diff_value, assertion_value, operator, log_level)
-                    }
-                    DataValueDeltaAssertion::U16 {
-                        value: assertion_value,
-                        operator,
-                    } => {
-                        let a_value = try_from_slice::<u16>(&a_account_data, a_offset)?;
-                        let b_value = try_from_slice::<u16>(&b_account_data, b_offset)?;
-                        let diff_value = b_value as i32 - a_value as i32;
-
-                        i32::evaluate(&diff_value, assertion_value, operator, log_level)
-                    }
-                    DataValueDeltaAssertion::I16 {
-                        value: assertion_value,
-                        operator,
-                    } => {
-                        let a_value = try_from_slice::<i16>(&a_account_data, a_offset)?;
-                        let b_value = try_from_slice::<i16>(&b_account_data, b_offset)?;
-                        let diff_value = b_value as i32 - a_value as i32;
-
-                        i32::evaluate(&diff_value, assertion_value, operator, log_level)
-                    }
-                    DataValueDeltaAssertion::U32 {
-                        value: assertion_value,
-                        operator,
-                    } => {
-                        let a_value = try_from_slice::<u32>(&a_account_data, a_offset)?;
-                        let b_value = try_from_slice::<u32>(&b_account_data, b_offset)?;
-                        let diff_value = b_value as i64 - a_value as i64;
-
-                        i64::evaluate(&diff_value, assertion_value, operator, log_level)
-                    }
-                    DataValueDeltaAssertion::I32 {
-                        value: assertion_value,
-                        operator,
-                    } => {
-                        let a_value = try_from_slice::<i32>(&a_account_data, a_offset)?;
-                        let b_value = try_from_slice::<i32>(&b_account_data, b_offset)?;
-                        let diff_value = b_value as i64 - a_value as i64;
-
-                        i64::evaluate(&diff_value, assertion_value, operator, log_level)
-                    }
-                    DataValueDeltaAssertion::U64 {
-                        value: assertion_value,
-                        operator,
-                    } => {
-                        let a_value = try_from_slice::<u64>(&a_account_data, a_offset)?;
-                        let b_value = try_from_slice::<u64>(&b_account_data, b_offset)?;
-                        let diff_value = b_value as i128 - a_value as i128;
-
-                        i128::evaluate(&diff_value, assertion_value, operator, log_level)
-                    }
-                    DataValueDeltaAssertion::I64 {
-                        value: assertion_value,
-                        operator,
-                    } => {
-                        let a_value = try_from_slice::<i64>(&a_account_data, a_offset)?;
-                        let b_value = try_from_slice::<i64>(&b_account_data, b_offset)?;
-                        let diff_value = b_value as i128 - a_value as i128;
-
-                        i128::evaluate(&diff_value, assertion_value, operator, log_level)
-                    }
-                    DataValueDeltaAssertion::Bytes { operator, length } => {
-                        let a_value =
-                            checked_get_slice(&a_account_data, a_offset, *length as usize)?;
-
-                        let b_value =
-                            checked_get_slice(&b_account_data, b_offset, *length as usize)?;
-
-                        <[u8]>::evaluate(a_value, b_value, operator, log_level)
-                    }
-                }
+                guard.evaluate((accounts.0, **a_offset), log_level)?;
+
+                evaluate_data_delta(
+                    accounts,
+                    **a_offset as usize,
+                    **b_offset as usize,
+                    assertion,
+                    log_level,
+                )
             }
-            AccountDeltaAssertion::AccountInfo {
+            AccountDeltaAssertion::GuardedAccountInfo {
+                guard,
                 a_offset,
                 assertion,
             } => {
-                let (a_account, b_account) = accounts;
-
-                if a_account.data_is_empty() {
-                    return Err(LighthouseError::AccountNotInitialized.into());
-                }
-
-                let a_account_data = a_account
-                    .try_borrow_data()
-                    .map_err(LighthouseError::failed_borrow_err)?;
-
-                let a_offset = **a_offset as usize;
-
-                match assertion {
-                    AccountInfoDeltaAssertion::Lamports { value, operator } => {
-                        let a_lamports = try_from_slice::<u64>(&a_account_data, a_offset)?;
-                        let b_lamports = b_account.lamports();
-                        let diff_value = b_lamports as i128 - a_lamports as i128;
-
-                        i128::evaluate(&diff_value, value, operator, log_level)
-                    }
-                    AccountInfoDeltaAssertion::DataLength { value, operator } => {
-                        let a_data_len = try_from_slice::<u64>(&a_account_data, a_offset)?;
-                        let b_data_len = b_account.data_len() as i128;
-                        let diff_value = b_data_len - a_data_len as i128;
-
-                        i128::evaluate(&diff_value, value, operator, log_level)
-                    }
-                    AccountInfoDeltaAssertion::Owner { operator } => {
-                        let a_owner = try_from_slice::<Pubkey>(&a_account_data, a_offset)?;
-
-                        Pubkey::evaluate(&a_owner, b_account.owner, operator, log_level)
-                    }
-                    AccountInfoDeltaAssertion::RentEpoch { value, operator } => {
-                        let a_rent_epoch = try_from_slice::<u64>(&a_account_data, a_offset)?;
-                        let b_rent_epoch = b_account.rent_epoch;
-                        let diff_value = b_rent_epoch as i128 - a_rent_epoch as i128;
-
-                        i128::evaluate(&diff_value, value, operator, log_level)
-                    }
-                }
+                guard.evaluate((accounts.0, **a_offset), log_level)?;
+
+                evaluate_account_info_delta(accounts, **a_offset as usize, assertion, log_level)
             }
         }
     }
 }
 
+fn evaluate_data_delta(
+    accounts: (&AccountInfo, &AccountInfo),
+    a_offset: usize,
+    b_offset: usize,
+    assertion: &DataValueDeltaAssertion,
+    log_level: LogLevel,
+) -> Result<()> {
+    let (a_account, b_account) = accounts;
+
+    let a_account_data = a_account
+        .try_borrow_data()
+        .map_err(LighthouseError::failed_borrow_err)?;
+    let b_account_data = b_account
+        .try_borrow_data()
+        .map_err(LighthouseError::failed_borrow_err)?;
+
+    if a_account_data.is_empty() || b_account_data.is_empty() {
+        return Err(LighthouseError::AccountNotInitialized.into());
+    }
+
+    match assertion {
+        DataValueDeltaAssertion::U8 {
+            value: assertion_value,
+            operator,
+        } => {
+            let a_value = try_from_slice::<u8>(&a_account_data, a_offset)?;
+            let b_value = try_from_slice::<u8>(&b_account_data, b_offset)?;
+            let diff_value = b_value as i16 - a_value as i16;
+
+            i16::evaluate(&diff_value, assertion_value, operator, log_level)
+        }
+        DataValueDeltaAssertion::I8 {
+            value: assertion_value,
+            operator,
+        } => {
+            let a_value = try_from_slice::<i8>(&a_account_data, a_offset)?;
+            let b_value = try_from_slice::<i8>(&b_account_data, b_offset)?;
+            let diff_value = b_value as i16 - a_value as i16;
+
+            i16::evaluate(&diff_value, assertion_value, operator, log_level)
+        }
+        DataValueDeltaAssertion::U16 {
+            value: assertion_value,
+            operator,
+        } => {
+            let a_value = try_from_slice::<u16>(&a_account_data, a_offset)?;
+            let b_value = try_from_slice::<u16>(&b_account_data, b_offset)?;
+            let diff_value = b_value as i32 - a_value as i32;
+
+            i32::evaluate(&diff_value, assertion_value, operator, log_level)
+        }
+        DataValueDeltaAssertion::I16 {
+            value: assertion_value,
+            operator,
+        } => {
+            let a_value = try_from_slice::<i16>(&a_account_data, a_offset)?;
+            let b_value = try_from_slice::<i16>(&b_account_data, b_offset)?;
+            let diff_value = b_value as i32 - a_value as i32;
+
+            i32::evaluate(&diff_value, assertion_value, operator, log_level)
+        }
+        DataValueDeltaAssertion::U32 {
+            value: assertion_value,
+            operator,
+        } => {
+            let a_value = try_from_slice::<u32>(&a_account_data, a_offset)?;
+            let b_value = try_from_slice::<u32>(&b_account_data, b_offset)?;
+            let diff_value = b_value as i64 - a_value as i64;
+
+            i64::evaluate(&diff_value, assertion_value, operator, log_level)
+        }
+        DataValueDeltaAssertion::I32 {
+            value: assertion_value,
+            operator,
+        } => {
+            let a_value = try_from_slice::<i32>(&a_account_data, a_offset)?;
+            let b_value = try_from_slice::<i32>(&b_account_data, b_offset)?;
+            let diff_value = b_value as i64 - a_value as i64;
+
+            i64::evaluate(&diff_value, assertion_value, operator, log_level)
+        }
+        DataValueDeltaAssertion::U64 {
+            value: assertion_value,
+            operator,
+        } => {
+            let a_value = try_from_slice::<u64>(&a_account_data, a_offset)?;
+            let b_value = try_from_slice::<u64>(&b_account_data, b_offset)?;
+            let diff_value = b_value as i128 - a_value as i128;
+
+            i128::evaluate(&diff_value, assertion_value, operator, log_level)
+        }
+        DataValueDeltaAssertion::I64 {
+            value: assertion_value,
+            operator,
+        } => {
+            let a_value = try_from_slice::<i64>(&a_account_data, a_offset)?;
+            let b_value = try_from_slice::<i64>(&b_account_data, b_offset)?;
+            let diff_value = b_value as i128 - a_value as i128;
+
+            i128::evaluate(&diff_value, assertion_value, operator, log_level)
+        }
+        DataValueDeltaAssertion::Bytes { operator, length } => {
+            let a_value = checked_get_slice(&a_account_data, a_offset, *length as usize)?;
+
+            let b_value = checked_get_slice(&b_account_data, b_offset, *length as usize)?;
+
+            <[u8]>::evaluate(a_value, b_value, operator, log_level)
+        }
+        DataValueDeltaAssertion::U8Bps {
+            value,
+            absolute,
+            operator,
+        } => {
+            let a_value = try_from_slice::<u8>(&a_account_data, a_offset)?;
+            let b_value = try_from_slice::<u8>(&b_account_data, b_offset)?;
+
+            evaluate_bps_delta(
+                a_value as i128,
+                b_value as i128,
+                *value,
+                *absolute,
+                operator,
+                log_level,
+            )
+        }
+        DataValueDeltaAssertion::I8Bps {
+            value,
+            absolute,
+            operator,
+        } => {
+            let a_value = try_from_slice::<i8>(&a_account_data, a_offset)?;
+            let b_value = try_from_slice::<i8>(&b_account_data, b_offset)?;
+
+            evaluate_bps_delta(
+                a_value as i128,
+                b_value as i128,
+                *value,
+                *absolute,
+                operator,
+                log_level,
+            )
+        }
+        DataValueDeltaAssertion::U16Bps {
+            value,
+            absolute,
+            operator,
+        } => {
+            let a_value = try_from_slice::<u16>(&a_account_data, a_offset)?;
+            let b_value = try_from_slice::<u16>(&b_account_data, b_offset)?;
+
+            evaluate_bps_delta(
+                a_value as i128,
+                b_value as i128,
+                *value,
+                *absolute,
+                operator,
+                log_level,
+            )
+        }
+        DataValueDeltaAssertion::I16Bps {
+            value,
+            absolute,
+            operator,
+        } => {
+            let a_value = try_from_slice::<i16>(&a_account_data, a_offset)?;
+            let b_value = try_from_slice::<i16>(&b_account_data, b_offset)?;
+
+            evaluate_bps_delta(
+                a_value as i128,
+                b_value as i128,
+                *value,
+                *absolute,
+                operator,
+                log_level,
+            )
+        }
+        DataValueDeltaAssertion::U32Bps {
+            value,
+            absolute,
+            operator,
+        } => {
+            let a_value = try_from_slice::<u32>(&a_account_data, a_offset)?;
+            let b_value = try_from_slice::<u32>(&b_account_data, b_offset)?;
+
+            evaluate_bps_delta(
+                a_value as i128,
+                b_value as i128,
+                *value,
+                *absolute,
+                operator,
+                log_level,
+            )
+        }
+        DataValueDeltaAssertion::I32Bps {
+            value,
+            absolute,
+            operator,
+        } => {
+            let a_value = try_from_slice::<i32>(&a_account_data, a_offset)?;
+            let b_value = try_from_slice::<i32>(&b_account_data, b_offset)?;
+
+            evaluate_bps_delta(
+                a_value as i128,
+                b_value as i128,
+                *value,
+                *absolute,
+                operator,
+                log_level,
+            )
+        }
+        DataValueDeltaAssertion::U64Bps {
+            value,
+            absolute,
+            operator,
+        } => {
+            let a_value = try_from_slice::<u64>(&a_account_data, a_offset)?;
+            let b_value = try_from_slice::<u64>(&b_account_data, b_offset)?;
+
+            evaluate_bps_delta(
+                a_value as i128,
+                b_value as i128,
+                *value,
+                *absolute,
+                operator,
+                log_level,
+            )
+        }
+        DataValueDeltaAssertion::I64Bps {
+            value,
+            absolute,
+            operator,
+        } => {
+            let a_value = try_from_slice::<i64>(&a_account_data, a_offset)?;
+            let b_value = try_from_slice::<i64>(&b_account_data, b_offset)?;
+
+            evaluate_bps_delta(
+                a_value as i128,
+                b_value as i128,
+                *value,
+                *absolute,
+                operator,
+                log_level,
+            )
+        }
+        DataValueDeltaAssertion::U128 {
+            value: assertion_value,
+            operator,
+        } => {
+            let a_value = try_from_slice::<u128>(&a_account_data, a_offset)?;
+            let b_value = try_from_slice::<u128>(&b_account_data, b_offset)?;
+
+            evaluate_wide_delta(
+                b_value < a_value,
+                b_value.abs_diff(a_value),
+                assertion_value,
+                operator,
+                log_level,
+            )
+        }
+        DataValueDeltaAssertion::I128 {
+            value: assertion_value,
+            operator,
+        } => {
+            let a_value = try_from_slice::<i128>(&a_account_data, a_offset)?;
+            let b_value = try_from_slice::<i128>(&b_account_data, b_offset)?;
+
+            evaluate_wide_delta(
+                b_value < a_value,
+                b_value.abs_diff(a_value),
+                assertion_value,
+                operator,
+                log_level,
+            )
+        }
+        DataValueDeltaAssertion::Pubkey { operator } => {
+            let a_value = try_from_slice::<Pubkey>(&a_account_data, a_offset)?;
+            let b_value = try_from_slice::<Pubkey>(&b_account_data, b_offset)?;
+
+            Pubkey::evaluate(&a_value, &b_value, operator, log_level)
+        }
+    }
+}
+
+fn evaluate_account_info_delta(
+    accounts: (&AccountInfo, &AccountInfo),
+    a_offset: usize,
+    assertion: &AccountInfoDeltaAssertion,
+    log_level: LogLevel,
+) -> Result<()> {
+    let (a_account, b_account) = accounts;
+
+    if a_account.data_is_empty() {
+        return Err(LighthouseError::AccountNotInitialized.into());
+    }
+
+    let a_account_data = a_account
+        .try_borrow_data()
+        .map_err(LighthouseError::failed_borrow_err)?;
+
+    match assertion {
+        AccountInfoDeltaAssertion::Lamports { value, operator } => {
+            let a_lamports = try_from_slice::<u64>(&a_account_data, a_offset)?;
+            let b_lamports = b_account.lamports();
+            let diff_value = b_lamports as i128 - a_lamports as i128;
+
+            i128::evaluate(&diff_value, value, operator, log_level)
+        }
+        AccountInfoDeltaAssertion::LamportsBps {
+            value,
+            absolute,
+            operator,
+        } => {
+            let a_lamports = try_from_slice::<u64>(&a_account_data, a_offset)?;
+            let b_lamports = b_account.lamports();
+
+            evaluate_bps_delta(
+                a_lamports as i128,
+                b_lamports as i128,
+                *value,
+                *absolute,
+                operator,
+                log_level,
+            )
+        }
+        AccountInfoDeltaAssertion::DataLength { value, operator } => {
+            let a_data_len = try_from_slice::<u64>(&a_account_data, a_offset)?;
+            let b_data_len = b_account.data_len() as i128;
+            let diff_value = b_data_len - a_data_len as i128;
+
+            i128::evaluate(&diff_value, value, operator, log_level)
+        }
+        AccountInfoDeltaAssertion::Owner { operator } => {
+            let a_owner = try_from_slice::<Pubkey>(&a_account_data, a_offset)?;
+
+            Pubkey::evaluate(&a_owner, b_account.owner, operator, log_level)
+        }
+        AccountInfoDeltaAssertion::RentEpoch { value, operator } => {
+            let a_rent_epoch = try_from_slice::<u64>(&a_account_data, a_offset)?;
+            let b_rent_epoch = b_account.rent_epoch;
+            let diff_value = b_rent_epoch as i128 - a_rent_epoch as i128;
+
+            i128::evaluate(&diff_value, value, operator, log_level)
+        }
+        AccountInfoDeltaAssertion::Executable { operator } => {
+            let a_executable = try_from_slice::<bool>(&a_account_data, a_offset)?;
+
+            bool::evaluate(&a_executable, &b_account.executable, operator, log_level)
+        }
+        AccountInfoDeltaAssertion::Key { operator } => {
+            let a_key = try_from_slice::<Pubkey>(&a_account_data, a_offset)?;
+
+            Pubkey::evaluate(&a_key, b_account.key, operator, log_level)
+        }
+        AccountInfoDeltaAssertion::Hash {
+            algorithm,
+            scope,
+            operator,
+        } => {
+            let a_hash = checked_get_slice(&a_account_data, a_offset, 32)?;
+            let b_hash = scope.hash(b_account, algorithm)?;
+
+            <[u8]>::evaluate(a_hash, &b_hash, operator, log_level)
+        }
+    }
+}
+
 #[derive(BorshDeserialize, BorshSerialize)]
 pub enum AccountInfoDeltaAssertion {
     Lamports {
@@ -247,6 +742,23 @@ pub enum AccountInfoDeltaAssertion {
         value: i128,
         operator: IntegerOperator,
     },
+    LamportsBps {
+        value: i32,
+        absolute: bool,
+        operator: IntegerOperator,
+    },
+    Executable {
+        operator: EquatableOperator,
+    },
+    Key {
+        operator: EquatableOperator,
+    },
+    // Compares the hash stored at `a_offset` against the current hash of account b.
+    Hash {
+        algorithm: HashAlgorithm,
+        scope: AccountHashScope,
+        operator: EquatableOperator,
+    },
 }
 
 ///
@@ -258,10 +770,14 @@ pub enum AccountInfoDeltaAssertion {
 #[cfg(test)]
 mod tests {
     use crate::{
-        test_utils::{assert_passed, create_test_account},
-        types::assert::{
-            AccountDeltaAssertion, AccountInfoDeltaAssertion, Assert, DataValueDeltaAssertion,
-            EquatableOperator, IntegerOperator, LogLevel,
+        error::LighthouseError,
+        test_utils::{assert_failed, assert_passed, create_test_account},
+        types::{
+            assert::{
+                AccountDeltaAssertion, AccountInfoDeltaAssertion, Assert, DataValueDeltaAssertion,
+                EquatableOperator, IntegerOperator, LogLevel, MemoryGuard,
+            },
+            write::{AccountHashScope, HashAlgorithm, MemoryHeader, WriteTypeTag},
         },
     };
     use borsh::BorshSerialize;
@@ -629,4 +1145,602 @@ mod tests {
 
         assert_passed(result);
     }
+    #[test]
+    fn evaluate_diff_u64_bps() {
+        let key = system_program::id();
+        let (lamports_b, lamports_a) = (&mut 0, &mut 0);
+        let a_data: &mut [u8] = &mut [0u8; 8];
+        a_data.copy_from_slice(1_000_000u64.to_le_bytes().as_ref());
+        let a_account_info =
+            AccountInfo::new(&key, false, false, lamports_a, a_data, &key, false, 0);
+
+        // Balance decreased by exactly 50 bps
+        let b_data: &mut [u8] = &mut [0u8; 8];
+        b_data.copy_from_slice(995_000u64.to_le_bytes().as_ref());
+        let b_account_info =
+            AccountInfo::new(&key, false, false, lamports_b, b_data, &key, false, 0);
+
+        let assertion = AccountDeltaAssertion::Data {
+            a_offset: CompactU64(0),
+            b_offset: CompactU64(0),
+            assertion: DataValueDeltaAssertion::U64Bps {
+                value: -50,
+                absolute: false,
+                operator: IntegerOperator::GreaterThanOrEqual,
+            },
+        };
+
+        let result = assertion.evaluate(
+            (&a_account_info, &b_account_info),
+            LogLevel::PlaintextMessage,
+        );
+
+        assert_passed(result);
+
+        let assertion = AccountDeltaAssertion::Data {
+            a_offset: CompactU64(0),
+            b_offset: CompactU64(0),
+            assertion: DataValueDeltaAssertion::U64Bps {
+                value: -49,
+                absolute: false,
+                operator: IntegerOperator::GreaterThanOrEqual,
+            },
+        };
+
+        let result = assertion.evaluate(
+            (&a_account_info, &b_account_info),
+            LogLevel::PlaintextMessage,
+        );
+
+        assert_failed(result);
+
+        // Range bounds are given in basis points
+        for (lower, upper, should_pass) in [(-60, -40, true), (-40, 0, false)] {
+            let assertion = AccountDeltaAssertion::Data {
+                a_offset: CompactU64(0),
+                b_offset: CompactU64(0),
+                assertion: DataValueDeltaAssertion::U64Bps {
+                    value: 0,
+                    absolute: false,
+                    operator: IntegerOperator::Between { lower, upper },
+                },
+            };
+
+            let result = assertion.evaluate(
+                (&a_account_info, &b_account_info),
+                LogLevel::PlaintextMessage,
+            );
+
+            if should_pass {
+                assert_passed(result);
+            } else {
+                assert_failed(result);
+            }
+        }
+
+        // The absolute change is bounded in either direction by one assertion
+        for (value, should_pass) in [(50, true), (49, false)] {
+            let assertion = AccountDeltaAssertion::Data {
+                a_offset: CompactU64(0),
+                b_offset: CompactU64(0),
+                assertion: DataValueDeltaAssertion::U64Bps {
+                    value,
+                    absolute: true,
+                    operator: IntegerOperator::LessThanOrEqual,
+                },
+            };
+
+            let result = assertion.evaluate(
+                (&a_account_info, &b_account_info),
+                LogLevel::PlaintextMessage,
+            );
+
+            if should_pass {
+                assert_passed(result);
+            } else {
+                assert_failed(result);
+            }
+        }
+
+        // Bitmask operators are rejected
+        for operator in [IntegerOperator::Contains, IntegerOperator::DoesNotContain] {
+            let assertion = AccountDeltaAssertion::Data {
+                a_offset: CompactU64(0),
+                b_offset: CompactU64(0),
+                assertion: DataValueDeltaAssertion::U64Bps {
+                    value: 50,
+                    absolute: false,
+                    operator,
+                },
+            };
+
+            let result = assertion.evaluate(
+                (&a_account_info, &b_account_info),
+                LogLevel::PlaintextMessage,
+            );
+
+            assert_eq!(
+                result.err().unwrap(),
+                LighthouseError::InvalidInstructionData.into()
+            );
+        }
+
+        // Range operators take their bounds from the operator, a non-zero value is rejected
+        let assertion = AccountDeltaAssertion::Data {
+            a_offset: CompactU64(0),
+            b_offset: CompactU64(0),
+            assertion: DataValueDeltaAssertion::U64Bps {
+                value: -50,
+                absolute: false,
+                operator: IntegerOperator::Between {
+                    lower: -60,
+                    upper: -40,
+                },
+            },
+        };
+
+        let result = assertion.evaluate(
+            (&a_account_info, &b_account_info),
+            LogLevel::PlaintextMessage,
+        );
+
+        assert_eq!(
+            result.err().unwrap(),
+            LighthouseError::InvalidInstructionData.into()
+        );
+
+        // Extreme values must not overflow
+        let a_data: &mut [u8] = &mut [0u8; 8];
+        a_data.copy_from_slice(i64::MIN.to_le_bytes().as_ref());
+        let a_account_info =
+            AccountInfo::new(&key, false, false, lamports_a, a_data, &key, false, 0);
+
+        let b_data: &mut [u8] = &mut [0u8; 8];
+        b_data.copy_from_slice(i64::MAX.to_le_bytes().as_ref());
+        let b_account_info =
+            AccountInfo::new(&key, false, false, lamports_b, b_data, &key, false, 0);
+
+        let assertion = AccountDeltaAssertion::Data {
+            a_offset: CompactU64(0),
+            b_offset: CompactU64(0),
+            assertion: DataValueDeltaAssertion::I64Bps {
+                value: i32::MAX,
+                absolute: false,
+                operator: IntegerOperator::LessThanOrEqual,
+            },
+        };
+
+        let result = assertion.evaluate(
+            (&a_account_info, &b_account_info),
+            LogLevel::PlaintextMessage,
+        );
+
+        assert_passed(result);
+    }
+
+    #[test]
+    fn evaluate_lamport_bps_delta() {
+        let key = system_program::id();
+        let (lamports_b, lamports_a) = (&mut 0, &mut 0);
+        let a_data: &mut [u8] = &mut [0u8; 8];
+        a_data.copy_from_slice(200_000u64.to_le_bytes().as_ref());
+        let a_account_info =
+            AccountInfo::new(&key, false, false, lamports_a, a_data, &key, false, 0);
+
+        let b_data: &mut [u8] = &mut [0u8; 0];
+        let b_account_info =
+            AccountInfo::new(&key, false, false, lamports_b, b_data, &key, false, 0);
+
+        // Lamports changed by 1% (200_000 -> 202_000)
+        let mut mut_ref = b_account_info.try_borrow_mut_lamports().unwrap();
+        **mut_ref = 202_000;
+        drop(mut_ref);
+
+        let assertion = AccountDeltaAssertion::AccountInfo {
+            a_offset: CompactU64(0),
+            assertion: AccountInfoDeltaAssertion::LamportsBps {
+                value: 100,
+                absolute: false,
+                operator: IntegerOperator::LessThanOrEqual,
+            },
+        };
+
+        let result = assertion.evaluate(
+            (&a_account_info, &b_account_info),
+            LogLevel::PlaintextMessage,
+        );
+
+        assert_passed(result);
+
+        let assertion = AccountDeltaAssertion::AccountInfo {
+            a_offset: CompactU64(0),
+            assertion: AccountInfoDeltaAssertion::LamportsBps {
+                value: 99,
+                absolute: false,
+                operator: IntegerOperator::LessThanOrEqual,
+            },
+        };
+
+        let result = assertion.evaluate(
+            (&a_account_info, &b_account_info),
+            LogLevel::PlaintextMessage,
+        );
+
+        assert_failed(result);
+    }
+
+    #[test]
+    fn evaluate_diff_u128() {
+        let key = system_program::id();
+        let (lamports_b, lamports_a) = (&mut 0, &mut 0);
+        let a_data: &mut [u8] = &mut [0u8; 16];
+        let a_account_info =
+            AccountInfo::new(&key, false, false, lamports_a, a_data, &key, false, 0);
+
+        let b_data: &mut [u8] = &mut [0u8; 16];
+        b_data.copy_from_slice(u128::MAX.to_le_bytes().as_ref());
+        let b_account_info =
+            AccountInfo::new(&key, false, false, lamports_b, b_data, &key, false, 0);
+
+        // Delta of u128::MAX is larger than any i128 assertion value
+        let assertion = AccountDeltaAssertion::Data {
+            a_offset: CompactU64(0),
+            b_offset: CompactU64(0),
+            assertion: DataValueDeltaAssertion::U128 {
+                value: i128::MAX,
+                operator: IntegerOperator::GreaterThan,
+            },
+        };
+
+        let result = assertion.evaluate(
+            (&a_account_info, &b_account_info),
+            LogLevel::PlaintextMessage,
+        );
+
+        assert_passed(result);
+
+        let reverse_assertion = AccountDeltaAssertion::Data {
+            a_offset: CompactU64(0),
+            b_offset: CompactU64(0),
+            assertion: DataValueDeltaAssertion::U128 {
+                value: i128::MIN,
+                operator: IntegerOperator::LessThan,
+            },
+        };
+
+        let result = reverse_assertion.evaluate(
+            (&b_account_info, &a_account_info),
+            LogLevel::PlaintextMessage,
+        );
+
+        assert_passed(result);
+
+        let reverse_assertion = AccountDeltaAssertion::Data {
+            a_offset: CompactU64(0),
+            b_offset: CompactU64(0),
+            assertion: DataValueDeltaAssertion::U128 {
+                value: i128::MIN,
+                operator: IntegerOperator::GreaterThanOrEqual,
+            },
+        };
+
+        let result = reverse_assertion.evaluate(
+            (&b_account_info, &a_account_info),
+            LogLevel::PlaintextMessage,
+        );
+
+        assert_failed(result);
+    }
+
+    #[test]
+    fn evaluate_diff_i128() {
+        let key = system_program::id();
+        let (lamports_b, lamports_a) = (&mut 0, &mut 0);
+        let a_data: &mut [u8] = &mut [0u8; 171];
+        let test_account = create_test_account();
+        a_data.copy_from_slice(test_account.try_to_vec().unwrap().as_ref());
+        let a_account_info =
+            AccountInfo::new(&key, false, false, lamports_a, a_data, &key, false, 0);
+
+        let b_data: &mut [u8] = &mut [0u8; 16];
+        b_data.copy_from_slice(0i128.to_le_bytes().as_ref());
+        let b_account_info =
+            AccountInfo::new(&key, false, false, lamports_b, b_data, &key, false, 0);
+
+        let assertion = AccountDeltaAssertion::Data {
+            a_offset: CompactU64(46),
+            b_offset: CompactU64(0),
+            assertion: DataValueDeltaAssertion::I128 {
+                value: -test_account.i128,
+                operator: IntegerOperator::Equal,
+            },
+        };
+
+        let result = assertion.evaluate(
+            (&a_account_info, &b_account_info),
+            LogLevel::PlaintextMessage,
+        );
+
+        assert_passed(result);
+
+        let reverse_assertion = AccountDeltaAssertion::Data {
+            a_offset: CompactU64(0),
+            b_offset: CompactU64(46),
+            assertion: DataValueDeltaAssertion::I128 {
+                value: test_account.i128,
+                operator: IntegerOperator::Equal,
+            },
+        };
+
+        let result = reverse_assertion.evaluate(
+            (&b_account_info, &a_account_info),
+            LogLevel::PlaintextMessage,
+        );
+
+        assert_passed(result);
+    }
+
+    #[test]
+    fn evaluate_diff_pubkey() {
+        let key = system_program::id();
+        let (lamports_b, lamports_a) = (&mut 0, &mut 0);
+        let authority = Keypair::new().encodable_pubkey();
+
+        let a_data: &mut [u8] = &mut [0u8; 32];
+        a_data.copy_from_slice(authority.as_ref());
+        let a_account_info =
+            AccountInfo::new(&key, false, false, lamports_a, a_data, &key, false, 0);
+
+        let b_data: &mut [u8] = &mut [0u8; 40];
+        b_data[8..].copy_from_slice(authority.as_ref());
+        let b_account_info =
+            AccountInfo::new(&key, false, false, lamports_b, b_data, &key, false, 0);
+
+        let assertion = AccountDeltaAssertion::Data {
+            a_offset: CompactU64(0),
+            b_offset: CompactU64(8),
+            assertion: DataValueDeltaAssertion::Pubkey {
+                operator: EquatableOperator::Equal,
+            },
+        };
+
+        let result = assertion.evaluate(
+            (&a_account_info, &b_account_info),
+            LogLevel::PlaintextMessage,
+        );
+
+        assert_passed(result);
+
+        let assertion = AccountDeltaAssertion::Data {
+            a_offset: CompactU64(0),
+            b_offset: CompactU64(0),
+            assertion: DataValueDeltaAssertion::Pubkey {
+                operator: EquatableOperator::Equal,
+            },
+        };
+
+        let result = assertion.evaluate(
+            (&a_account_info, &b_account_info),
+            LogLevel::PlaintextMessage,
+        );
+
+        assert_failed(result);
+    }
+
+    #[test]
+    fn evaluate_key_and_executable_delta() {
+        let key = Keypair::new().encodable_pubkey();
+        let owner = system_program::id();
+
+        let (lamports_b, lamports_a) = (&mut 0, &mut 0);
+        let a_data: &mut [u8] = &mut [0u8; 33];
+        a_data[..32].copy_from_slice(key.as_ref());
+        a_data[32] = 1;
+        let a_account_info =
+            AccountInfo::new(&owner, false, false, lamports_a, a_data, &owner, false, 0);
+
+        let b_data: &mut [u8] = &mut [0u8; 0];
+        let b_account_info =
+            AccountInfo::new(&key, false, false, lamports_b, b_data, &owner, true, 0);
+
+        let assertion = AccountDeltaAssertion::AccountInfo {
+            a_offset: CompactU64(0),
+            assertion: AccountInfoDeltaAssertion::Key {
+                operator: EquatableOperator::Equal,
+            },
+        };
+
+        let result = assertion.evaluate(
+            (&a_account_info, &b_account_info),
+            LogLevel::PlaintextMessage,
+        );
+
+        assert_passed(result);
+
+        let assertion = AccountDeltaAssertion::AccountInfo {
+            a_offset: CompactU64(32),
+            assertion: AccountInfoDeltaAssertion::Executable {
+                operator: EquatableOperator::Equal,
+            },
+        };
+
+        let result = assertion.evaluate(
+            (&a_account_info, &b_account_info),
+            LogLevel::PlaintextMessage,
+        );
+
+        assert_passed(result);
+
+        let assertion = AccountDeltaAssertion::AccountInfo {
+            a_offset: CompactU64(32),
+            assertion: AccountInfoDeltaAssertion::Executable {
+                operator: EquatableOperator::NotEqual,
+            },
+        };
+
+        let result = assertion.evaluate(
+            (&a_account_info, &b_account_info),
+            LogLevel::PlaintextMessage,
+        );
+
+        assert_failed(result);
+    }
+
+    #[test]
+    fn evaluate_hash_delta() {
+        let key = system_program::id();
+
+        let (lamports_a, lamports_b) = (&mut 0, &mut 100);
+        let a_data: &mut [u8] = &mut [0u8; 64];
+        let a_account_info =
+            AccountInfo::new(&key, false, false, lamports_a, a_data, &key, false, 0);
+
+        let b_data: &mut [u8] = &mut [1, 2, 3, 4];
+        let b_account_info =
+            AccountInfo::new(&key, false, false, lamports_b, b_data, &key, false, 0);
+
+        let hash = AccountHashScope::Account
+            .hash(&b_account_info, &HashAlgorithm::Sha256)
+            .unwrap();
+        a_account_info.try_borrow_mut_data().unwrap()[16..48].copy_from_slice(&hash);
+
+        let assertion = |operator| AccountDeltaAssertion::AccountInfo {
+            a_offset: CompactU64(16),
+            assertion: AccountInfoDeltaAssertion::Hash {
+                algorithm: HashAlgorithm::Sha256,
+                scope: AccountHashScope::Account,
+                operator,
+            },
+        };
+
+        assert_passed(assertion(EquatableOperator::Equal).evaluate(
+            (&a_account_info, &b_account_info),
+            LogLevel::PlaintextMessage,
+        ));
+
+        b_account_info.try_borrow_mut_data().unwrap()[3] = 5;
+
+        assert_failed(assertion(EquatableOperator::Equal).evaluate(
+            (&a_account_info, &b_account_info),
+            LogLevel::PlaintextMessage,
+        ));
+        assert_passed(assertion(EquatableOperator::NotEqual).evaluate(
+            (&a_account_info, &b_account_info),
+            LogLevel::PlaintextMessage,
+        ));
+
+        // The stored hash must fit in account a.
+        let result = AccountDeltaAssertion::AccountInfo {
+            a_offset: CompactU64(48),
+            assertion: AccountInfoDeltaAssertion::Hash {
+                algorithm: HashAlgorithm::Sha256,
+                scope: AccountHashScope::Account,
+                operator: EquatableOperator::Equal,
+            },
+        }
+        .evaluate(
+            (&a_account_info, &b_account_info),
+            LogLevel::PlaintextMessage,
+        );
+
+        assert_eq!(
+            result.err().unwrap(),
+            LighthouseError::RangeOutOfBounds.into()
+        );
+    }
+
+    #[test]
+    fn evaluate_guarded_delta() {
+        let key = system_program::id();
+
+        let (lamports_a, lamports_b) = (&mut 0, &mut 0);
+        let a_data: &mut [u8] = &mut [0u8; MemoryHeader::LEN + 16];
+        let a_account_info =
+            AccountInfo::new(&key, false, false, lamports_a, a_data, &crate::ID, false, 0);
+
+        let b_data: &mut [u8] = &mut [0u8; 8];
+        b_data.copy_from_slice(&10u64.to_le_bytes());
+        let b_account_info =
+            AccountInfo::new(&key, false, false, lamports_b, b_data, &key, false, 0);
+
+        let data_offset = MemoryHeader::LEN as u64;
+        let assertion = |nonce, a_offset, tag| AccountDeltaAssertion::GuardedData {
+            guard: MemoryGuard {
+                current_slot: false,
+                nonce: Some(nonce),
+                type_tag: Some(tag),
+            },
+            a_offset: CompactU64(a_offset),
+            b_offset: CompactU64(0),
+            assertion: DataValueDeltaAssertion::U64 {
+                value: 6,
+                operator: IntegerOperator::Equal,
+            },
+        };
+
+        // Memory without a header is rejected.
+        let result = assertion(7, data_offset, WriteTypeTag::DataValue).evaluate(
+            (&a_account_info, &b_account_info),
+            LogLevel::PlaintextMessage,
+        );
+        assert_eq!(
+            result.err().unwrap(),
+            LighthouseError::AccountDiscriminatorValidationFailed.into()
+        );
+
+        {
+            let mut a_data = a_account_info.try_borrow_mut_data().unwrap();
+            let mut header = MemoryHeader::new(1, 7);
+            header
+                .record(data_offset, 1, WriteTypeTag::DataValue)
+                .unwrap();
+            header.write(&mut a_data).unwrap();
+            a_data[MemoryHeader::LEN..MemoryHeader::LEN + 8].copy_from_slice(&4u64.to_le_bytes());
+            a_data[MemoryHeader::LEN + 8..].copy_from_slice(&4u64.to_le_bytes());
+        }
+
+        assert_passed(assertion(7, data_offset, WriteTypeTag::DataValue).evaluate(
+            (&a_account_info, &b_account_info),
+            LogLevel::PlaintextMessage,
+        ));
+
+        // Stale nonce
+        assert_failed(assertion(8, data_offset, WriteTypeTag::DataValue).evaluate(
+            (&a_account_info, &b_account_info),
+            LogLevel::PlaintextMessage,
+        ));
+
+        // Unexpected type tag
+        assert_failed(
+            assertion(7, data_offset, WriteTypeTag::TokenAccountSnapshot).evaluate(
+                (&a_account_info, &b_account_info),
+                LogLevel::PlaintextMessage,
+            ),
+        );
+
+        // No write recorded at the guarded offset
+        assert_failed(
+            assertion(7, data_offset + 8, WriteTypeTag::DataValue).evaluate(
+                (&a_account_info, &b_account_info),
+                LogLevel::PlaintextMessage,
+            ),
+        );
+
+        // A header in an account not owned by Lighthouse is not trusted.
+        let (lamports_c, c_data) = (
+            &mut 0,
+            &mut a_account_info.try_borrow_data().unwrap().to_vec(),
+        );
+        let c_account_info =
+            AccountInfo::new(&key, false, false, lamports_c, c_data, &key, false, 0);
+
+        let result = assertion(7, data_offset, WriteTypeTag::DataValue).evaluate(
+            (&c_account_info, &b_account_info),
+            LogLevel::PlaintextMessage,
+        );
+        assert_eq!(
+            result.err().unwrap(),
+            LighthouseError::AccountOwnerMismatch.into()
+        );
+    }
 }
@@ -1,11 +1,20 @@
-use super::{Assert, EquatableOperator, IntegerOperator, KnownProgram, LogLevel};
+use super::{
+    derive_program_address, Assert, EquatableOperator, IntegerOperator, KnownProgram, LogLevel,
+    SetOperator,
+};
 use crate::{
-    types::assert::evaluate::Evaluate,
-    utils::{checked_get_slice, Result},
+    types::{
+        assert::evaluate::{Evaluate, EvaluateSet},
+        CompactKnownPrograms, CompactPdaSeeds, CompactPubkeys,
+    },
+    utils::{checked_get_slice, get_account_key, keys_equal, Result},
 };
 use borsh::{BorshDeserialize, BorshSerialize};
 use lighthouse_common::CompactU64;
-use solana_program::{account_info::AccountInfo, keccak, pubkey::Pubkey};
+use solana_program::{
+    account_info::AccountInfo, keccak, pubkey::Pubkey, rent::Rent, system_program, sysvar::Sysvar,
+};
+use std::slice;
 
 #[derive(BorshDeserialize, BorshSerialize, Debug)]
 pub enum AccountInfoAssertion {
@@ -46,17 +55,85 @@ pub enum AccountInfoAssertion {
         start: CompactU64,
         length: CompactU64,
     },
+    OwnerIn {
+        value: CompactPubkeys,
+        operator: SetOperator,
+    },
+    KnownOwnerIn {
+        value: CompactKnownPrograms,
+        operator: SetOperator,
+    },
+    /// Compares the owner against the key of the instruction account at `account_index`.
+    OwnerAccountRef {
+        account_index: u8,
+        operator: EquatableOperator,
+    },
+    /// Whether the lamports cover the rent-exempt minimum for the current data length.
+    IsRentExempt {
+        value: bool,
+        operator: EquatableOperator,
+    },
+    /// An account does not exist if it has no lamports, no data and is owned by the system program.
+    Exists {
+        value: bool,
+        operator: EquatableOperator,
+    },
+    /// System program owned with no data and not executable.
+    IsWallet {
+        value: bool,
+        operator: EquatableOperator,
+    },
+    /// Lamports above the rent-exempt minimum for the current data length, negative if below it.
+    LamportsAboveRentMinimum {
+        value: i128,
+        operator: IntegerOperator,
+    },
+    /// Compares the key against the program address derived from `seeds`, the canonical bump is
+    /// searched for if no bump is given.
+    /// Seeds and a bump with no valid program address match no key.
+    KeyIsDerived {
+        seeds: CompactPdaSeeds,
+        program_id: Pubkey,
+        bump: Option<u8>,
+        operator: EquatableOperator,
+    },
 }
 
 impl Assert<&AccountInfo<'_>> for AccountInfoAssertion {
     fn evaluate(&self, account: &AccountInfo<'_>, log_level: LogLevel) -> Result<()> {
+        self.evaluate((account, slice::from_ref(account)), log_level)
+    }
+}
+
+impl Assert<(&AccountInfo<'_>, &[AccountInfo<'_>])> for AccountInfoAssertion {
+    fn evaluate(
+        &self,
+        (account, accounts): (&AccountInfo<'_>, &[AccountInfo<'_>]),
+        log_level: LogLevel,
+    ) -> Result<()> {
         match self {
             AccountInfoAssertion::Owner { value, operator } => {
                 Pubkey::evaluate(account.owner, value, operator, log_level)
             }
+            AccountInfoAssertion::OwnerAccountRef {
+                account_index,
+                operator,
+            } => {
+                let value = get_account_key(accounts, *account_index)?;
+
+                Pubkey::evaluate(account.owner, value, operator, log_level)
+            }
             AccountInfoAssertion::KnownOwner { value, operator } => {
                 Pubkey::evaluate(account.owner, &value.to_pubkey(), operator, log_level)
             }
+            AccountInfoAssertion::OwnerIn { value, operator } => {
+                Pubkey::evaluate_set(account.owner, value, operator, log_level)
+            }
+            AccountInfoAssertion::KnownOwnerIn { value, operator } => {
+                let candidates = value.iter().map(|v| v.to_pubkey()).collect::<Vec<_>>();
+
+                Pubkey::evaluate_set(account.owner, &candidates, operator, log_level)
+            }
             AccountInfoAssertion::Lamports { value, operator } => {
                 u64::evaluate(&account.try_lamports()?, value, operator, log_level)
             }
@@ -75,6 +152,47 @@ impl Assert<&AccountInfo<'_>> for AccountInfoAssertion {
             AccountInfoAssertion::RentEpoch { value, operator } => {
                 u64::evaluate(&account.rent_epoch as &u64, value, operator, log_level)
             }
+            AccountInfoAssertion::IsRentExempt { value, operator } => {
+                let is_rent_exempt =
+                    Rent::get()?.is_exempt(account.try_lamports()?, account.data_len());
+
+                bool::evaluate(&is_rent_exempt, value, operator, log_level)
+            }
+            AccountInfoAssertion::Exists { value, operator } => {
+                let exists = account.try_lamports()? != 0
+                    || !account.data_is_empty()
+                    || !keys_equal(account.owner, &system_program::ID);
+
+                bool::evaluate(&exists, value, operator, log_level)
+            }
+            AccountInfoAssertion::IsWallet { value, operator } => {
+                let is_wallet = keys_equal(account.owner, &system_program::ID)
+                    && account.data_is_empty()
+                    && !account.executable;
+
+                bool::evaluate(&is_wallet, value, operator, log_level)
+            }
+            AccountInfoAssertion::LamportsAboveRentMinimum { value, operator } => {
+                let minimum_balance = Rent::get()?.minimum_balance(account.data_len());
+                let actual_value = account.try_lamports()? as i128 - minimum_balance as i128;
+
+                i128::evaluate(&actual_value, value, operator, log_level)
+            }
+            AccountInfoAssertion::KeyIsDerived {
+                seeds,
+                program_id,
+                bump,
+                operator,
+            } => {
+                let expected_key = derive_program_address(seeds, program_id, *bump, accounts)?;
+
+                Option::<&Pubkey>::evaluate(
+                    &Some(account.key),
+                    &expected_key.as_ref(),
+                    operator,
+                    log_level,
+                )
+            }
             AccountInfoAssertion::VerifyDatahash {
                 expected_hash,
                 start,
@@ -1,4 +1,7 @@
-use super::{EquatableOperator, IntegerOperator, LogLevel};
+use super::{
+    format_range, BytesOperator, EquatableOperator, IntegerOperator, LogLevel, Ray, SetOperator,
+    StringOperator, Wad, I80F48, U64F64,
+};
 use crate::{error::LighthouseError, types::assert::Operator, validation::SPL_NOOP_ID, Result};
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
@@ -7,7 +10,7 @@ use solana_program::{
 use std::fmt::Debug;
 
 macro_rules! log_cases {
-    ($self:ident, $($type:ident),+) => {
+    ($self:ident, [$($type:ident),+], [$(($formatted_type:ident, $format:expr)),+]) => {
         match $self {
             $(
                 AssertionResult::$type(actual, assertion, operator, passed) => {
@@ -22,6 +25,19 @@ macro_rules! log_cases {
                     );
                 }
             )+
+            $(
+                AssertionResult::$formatted_type(actual, assertion, operator, passed) => {
+                    let operator = IntegerOperator::try_from(*operator).unwrap();
+
+                    msg!(
+                        "Result ({}): {} {} {}",
+                        if *passed { "Passed" } else { "Failed" },
+                        actual.map_or("None".to_string(), $format),
+                        operator.format(),
+                        assertion.map_or("None".to_string(), $format),
+                    );
+                }
+            )+
             // You can include special cases directly within the macro or outside as needed
             AssertionResult::Pubkey(actual, assertion, operator, passed) => {
                 let operator = EquatableOperator::try_from(*operator).unwrap();
@@ -50,6 +66,53 @@ macro_rules! log_cases {
                     }
                 }
             },
+            AssertionResult::Range(actual, lower, upper, operator, passed) => {
+                msg!(
+                    "Result ({}): {:?} {}",
+                    if *passed { "Passed" } else { "Failed" },
+                    actual,
+                    format_range(*operator, *lower, *upper),
+                );
+            },
+            AssertionResult::PubkeySet(actual, candidates, operator, passed) => {
+                let operator = SetOperator::try_from(*operator).unwrap();
+
+                msg!("Result ({}): ", if *passed { "Passed" } else { "Failed" });
+                match actual {
+                    Some(actual_value) => actual_value.log(),
+                    None => msg!("None"),
+                }
+                msg!(operator.format());
+                for candidate in candidates {
+                    match candidate {
+                        Some(candidate_value) => candidate_value.log(),
+                        None => msg!("None"),
+                    }
+                }
+            },
+            AssertionResult::BytesPattern(match_offset, length, pattern, operator, passed) => {
+                let operator = BytesOperator::try_from(*operator).unwrap();
+
+                msg!(
+                    "Result ({}): {} bytes {} {:?}, first match at {:?}",
+                    if *passed { "Passed" } else { "Failed" },
+                    length,
+                    operator.format(),
+                    pattern,
+                    match_offset,
+                );
+            },
+            AssertionResult::String(actual, assertion, operator, passed) => {
+                let operator = StringOperator::try_from(*operator).unwrap();
+
+                msg!(
+                    "Result ({}): {:?} {} {:?}",
+                    if *passed { "Passed" } else { "Failed" },
+                    String::from_utf8_lossy(actual),
+                    operator.format(),
+                    String::from_utf8_lossy(assertion),
+                );
+            },
         }
     };
 }
@@ -69,6 +132,17 @@ pub enum AssertionResult {
     Pubkey(Option<Pubkey>, Option<Pubkey>, u8, bool),
     Bytes(Vec<u8>, Vec<u8>, u8, bool),
     Bool(Option<bool>, Option<bool>, u8, bool),
+    Range(Option<i128>, i128, i128, u8, bool),
+    PubkeySet(Option<Pubkey>, Vec<Option<Pubkey>>, u8, bool),
+    // Offset of the first match and length of the searched bytes rather than the bytes themselves.
+    BytesPattern(Option<u32>, u32, Vec<u8>, u8, bool),
+    I80F48(Option<i128>, Option<i128>, u8, bool),
+    U64F64(Option<u128>, Option<u128>, u8, bool),
+    Wad(Option<u128>, Option<u128>, u8, bool),
+    Ray(Option<u128>, Option<u128>, u8, bool),
+    F32(Option<u32>, Option<u32>, u8, bool),
+    F64(Option<u64>, Option<u64>, u8, bool),
+    String(Vec<u8>, Vec<u8>, u8, bool),
 }
 
 impl AssertionResult {
@@ -80,7 +154,18 @@ impl AssertionResult {
         match log_level {
             LogLevel::Silent => {}
             LogLevel::PlaintextMessage | LogLevel::FailedPlaintextMessage => {
-                log_cases!(self, U8, U16, U32, U64, U128, I8, I16, I32, I64, I128, Bool, Bytes);
+                log_cases!(
+                    self,
+                    [U8, U16, U32, U64, U128, I8, I16, I32, I64, I128, Bool, Bytes],
+                    [
+                        (I80F48, I80F48::format),
+                        (U64F64, U64F64::format),
+                        (Wad, Wad::format),
+                        (Ray, Ray::format),
+                        (F32, |bits| f32::from_bits(bits).to_string()),
+                        (F64, |bits| f64::from_bits(bits).to_string())
+                    ]
+                );
             }
             LogLevel::EncodedMessage | LogLevel::FailedEncodedMessage => {
                 let data = self.try_to_vec().map_err(LighthouseError::serialize_err)?;
@@ -118,7 +203,17 @@ impl AssertionResult {
             | AssertionResult::I128(_, _, _, passed)
             | AssertionResult::Pubkey(_, _, _, passed)
             | AssertionResult::Bytes(_, _, _, passed)
-            | AssertionResult::Bool(_, _, _, passed) => *passed,
+            | AssertionResult::Bool(_, _, _, passed)
+            | AssertionResult::Range(_, _, _, _, passed)
+            | AssertionResult::PubkeySet(_, _, _, passed)
+            | AssertionResult::BytesPattern(_, _, _, _, passed)
+            | AssertionResult::I80F48(_, _, _, passed)
+            | AssertionResult::U64F64(_, _, _, passed)
+            | AssertionResult::Wad(_, _, _, passed)
+            | AssertionResult::Ray(_, _, _, passed)
+            | AssertionResult::F32(_, _, _, passed)
+            | AssertionResult::F64(_, _, _, passed)
+            | AssertionResult::String(_, _, _, passed) => *passed,
         }
     }
 }
@@ -1,6 +1,10 @@
-use super::{AssertionResult, EquatableOperator, IntegerOperator, LogLevel, Operator};
+use super::{
+    AssertionResult, BytesOperator, EquatableOperator, IntegerOperator, LogLevel, Operator, Ray,
+    SetOperator, StringOperator, Wad, I80F48, U64F64,
+};
 use crate::{error::LighthouseError, Result};
-use solana_program::{program_memory::sol_memcmp, pubkey::Pubkey};
+use solana_program::{msg, program_memory::sol_memcmp, pubkey::Pubkey};
+use std::cmp::Ordering;
 
 /*
 *    Implement the `Evaluate` trait for any `Operator`, used in the evaluation of two values + operator.
@@ -25,6 +29,9 @@ macro_rules! impl_integer_evaluate {
                     operator: &IntegerOperator,
                     log_level: LogLevel,
                 ) -> Result<()> {
+                    // Only u128 values above i128::MAX fail to widen, which are greater than any bound.
+                    let widened_value = i128::try_from(*actual_value).ok();
+
                     let passed = match operator {
                         IntegerOperator::Equal => actual_value == assertion_value,
                         IntegerOperator::NotEqual => actual_value != assertion_value,
@@ -34,15 +41,32 @@ macro_rules! impl_integer_evaluate {
                         IntegerOperator::LessThanOrEqual => actual_value <= assertion_value,
                         IntegerOperator::Contains => actual_value & assertion_value == *assertion_value,
                         IntegerOperator::DoesNotContain => actual_value & assertion_value == 0,
+                        IntegerOperator::Between { .. }
+                        | IntegerOperator::NotBetween { .. }
+                        | IntegerOperator::BetweenExclusive { .. }
+                        | IntegerOperator::NotBetweenExclusive { .. } => {
+                            operator.evaluate_range(|bound| {
+                                widened_value.map_or(Ordering::Greater, |value| value.cmp(bound))
+                            })
+                        }
                     };
 
                     if log_level != LogLevel::Silent {
-                        AssertionResult::$payload_variant(
-                            Some(*actual_value),
-                            Some(*assertion_value),
-                            *operator as u8,
-                            passed,
-                        )
+                        match operator.bounds() {
+                            Some((lower, upper)) => AssertionResult::Range(
+                                widened_value,
+                                lower,
+                                upper,
+                                u8::from(*operator),
+                                passed,
+                            ),
+                            None => AssertionResult::$payload_variant(
+                                Some(*actual_value),
+                                Some(*assertion_value),
+                                u8::from(*operator),
+                                passed,
+                            ),
+                        }
                         .log(log_level)?;
                     }
 
@@ -70,6 +94,120 @@ impl_integer_evaluate!(
     (i128, I128)
 );
 
+#[macro_export]
+macro_rules! impl_fixed_point_evaluate {
+    ($(($type:ty, $inner_type:ty, $payload_variant:ident)),*) => {
+        $(
+            impl Evaluate<IntegerOperator> for $type {
+                fn evaluate(
+                    actual_value: &Self,
+                    assertion_value: &Self,
+                    operator: &IntegerOperator,
+                    log_level: LogLevel,
+                ) -> Result<()> {
+                    // Fixed-point values compare numerically on their raw representation.
+                    let passed = <$inner_type>::evaluate(
+                        &actual_value.0,
+                        &assertion_value.0,
+                        operator,
+                        LogLevel::Silent,
+                    )
+                    .is_ok();
+
+                    if log_level != LogLevel::Silent {
+                        match operator.bounds() {
+                            Some((lower, upper)) => AssertionResult::Range(
+                                i128::try_from(actual_value.0).ok(),
+                                lower,
+                                upper,
+                                u8::from(*operator),
+                                passed,
+                            ),
+                            None => AssertionResult::$payload_variant(
+                                Some(actual_value.0),
+                                Some(assertion_value.0),
+                                u8::from(*operator),
+                                passed,
+                            ),
+                        }
+                        .log(log_level)?;
+                    }
+
+                    if passed {
+                        Ok(())
+                    } else {
+                        Err(LighthouseError::AssertionFailed.into())
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_point_evaluate!(
+    (I80F48, i128, I80F48),
+    (U64F64, u128, U64F64),
+    (Wad, u128, Wad),
+    (Ray, u128, Ray)
+);
+
+#[macro_export]
+macro_rules! impl_float_evaluate {
+    ($(($type:ty, $payload_variant:ident)),*) => {
+        $(
+            impl Evaluate<IntegerOperator> for $type {
+                fn evaluate(
+                    actual_value: &Self,
+                    assertion_value: &Self,
+                    operator: &IntegerOperator,
+                    log_level: LogLevel,
+                ) -> Result<()> {
+                    // IEEE 754 comparisons, any comparison against NaN is false except NotEqual.
+                    let passed = match operator {
+                        IntegerOperator::Equal => actual_value == assertion_value,
+                        IntegerOperator::NotEqual => actual_value != assertion_value,
+                        IntegerOperator::GreaterThan => actual_value > assertion_value,
+                        IntegerOperator::LessThan => actual_value < assertion_value,
+                        IntegerOperator::GreaterThanOrEqual => actual_value >= assertion_value,
+                        IntegerOperator::LessThanOrEqual => actual_value <= assertion_value,
+                        IntegerOperator::Contains
+                        | IntegerOperator::DoesNotContain
+                        | IntegerOperator::Between { .. }
+                        | IntegerOperator::NotBetween { .. }
+                        | IntegerOperator::BetweenExclusive { .. }
+                        | IntegerOperator::NotBetweenExclusive { .. } => {
+                            msg!(
+                                "Operator {} is not supported for floating-point values",
+                                operator.format()
+                            );
+                            return Err(LighthouseError::InvalidInstructionData.into());
+                        }
+                    };
+
+                    if log_level != LogLevel::Silent {
+                        // Logged as raw bits as borsh cannot serialize NaN.
+                        AssertionResult::$payload_variant(
+                            Some(actual_value.to_bits()),
+                            Some(assertion_value.to_bits()),
+                            u8::from(*operator),
+                            passed,
+                        )
+                        .log(log_level)?;
+                    }
+
+                    if passed {
+                        Ok(())
+                    } else {
+                        Err(LighthouseError::AssertionFailed.into())
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_float_evaluate!((f32, F32), (f64, F64));
+
 #[macro_export]
 macro_rules! impl_evaluate {
     ($(($type:ty, $payload_variant:ident)),*) => {
@@ -183,3 +321,127 @@ impl Evaluate<EquatableOperator> for [u8] {
             .unwrap_or_else(|| Err(LighthouseError::AssertionFailed.into()))
     }
 }
+
+impl Evaluate<BytesOperator> for [u8] {
+    fn evaluate(
+        actual_value: &Self,
+        assertion_value: &Self,
+        operator: &BytesOperator,
+        log_level: LogLevel,
+    ) -> Result<()> {
+        let match_offset = match operator {
+            BytesOperator::StartsWith => (actual_value.len() >= assertion_value.len()
+                && sol_memcmp(actual_value, assertion_value, assertion_value.len()) == 0)
+                .then_some(0),
+            BytesOperator::Contains | BytesOperator::DoesNotContain => {
+                if assertion_value.is_empty() {
+                    Some(0)
+                } else {
+                    actual_value
+                        .windows(assertion_value.len())
+                        .position(|window| {
+                            sol_memcmp(window, assertion_value, assertion_value.len()) == 0
+                        })
+                }
+            }
+        };
+
+        let passed = match operator {
+            BytesOperator::StartsWith | BytesOperator::Contains => match_offset.is_some(),
+            BytesOperator::DoesNotContain => match_offset.is_none(),
+        };
+
+        if log_level != LogLevel::Silent {
+            AssertionResult::BytesPattern(
+                match_offset.map(|offset| offset as u32),
+                actual_value.len() as u32,
+                assertion_value.to_vec(),
+                *operator as u8,
+                passed,
+            )
+            .log(log_level)?;
+        }
+
+        passed
+            .then_some(Ok(()))
+            .unwrap_or_else(|| Err(LighthouseError::AssertionFailed.into()))
+    }
+}
+
+impl Evaluate<StringOperator> for [u8] {
+    fn evaluate(
+        actual_value: &Self,
+        assertion_value: &Self,
+        operator: &StringOperator,
+        log_level: LogLevel,
+    ) -> Result<()> {
+        let passed = match operator {
+            StringOperator::Equal => actual_value == assertion_value,
+            StringOperator::NotEqual => actual_value != assertion_value,
+            StringOperator::StartsWith => actual_value.starts_with(assertion_value),
+            StringOperator::EndsWith => actual_value.ends_with(assertion_value),
+        };
+
+        if log_level != LogLevel::Silent {
+            AssertionResult::String(
+                actual_value.to_vec(),
+                assertion_value.to_vec(),
+                *operator as u8,
+                passed,
+            )
+            .log(log_level)?;
+        }
+
+        passed
+            .then_some(Ok(()))
+            .unwrap_or_else(|| Err(LighthouseError::AssertionFailed.into()))
+    }
+}
+
+/*
+*    Implement the `EvaluateSet` trait for any `Operator`, used in the evaluation of a value against a list of candidates.
+*/
+pub trait EvaluateSet<T: Operator>: Sized {
+    fn evaluate_set(
+        actual_value: &Self,
+        candidates: &[Self],
+        operator: &T,
+        log_level: LogLevel,
+    ) -> Result<()>;
+}
+
+impl EvaluateSet<SetOperator> for Option<Pubkey> {
+    fn evaluate_set(
+        actual_value: &Self,
+        candidates: &[Self],
+        operator: &SetOperator,
+        log_level: LogLevel,
+    ) -> Result<()> {
+        let passed = match operator {
+            SetOperator::In => candidates.contains(actual_value),
+            SetOperator::NotIn => !candidates.contains(actual_value),
+        };
+
+        if log_level != LogLevel::Silent {
+            AssertionResult::PubkeySet(*actual_value, candidates.to_vec(), *operator as u8, passed)
+                .log(log_level)?;
+        }
+
+        passed
+            .then_some(Ok(()))
+            .unwrap_or_else(|| Err(LighthouseError::AssertionFailed.into()))
+    }
+}
+
+impl EvaluateSet<SetOperator> for Pubkey {
+    fn evaluate_set(
+        actual_value: &Self,
+        candidates: &[Self],
+        operator: &SetOperator,
+        log_level: LogLevel,
+    ) -> Result<()> {
+        let candidates = candidates.iter().copied().map(Some).collect::<Vec<_>>();
+
+        <Option<Pubkey>>::evaluate_set(&Some(*actual_value), &candidates, operator, log_level)
+    }
+}
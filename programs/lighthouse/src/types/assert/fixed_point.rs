@@ -0,0 +1,104 @@
+/// Number of fractional digits shown when logging binary fixed-point values.
+const BINARY_FIXED_POINT_LOG_DIGITS: u32 = 15;
+
+/// Signed fixed-point number with 48 fractional bits, stored as its raw i128 bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct I80F48(pub i128);
+
+/// Unsigned fixed-point number with 64 fractional bits, stored as its raw u128 bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct U64F64(pub u128);
+
+/// Unsigned decimal fixed-point number scaled by 10^18.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Wad(pub u128);
+
+/// Unsigned decimal fixed-point number scaled by 10^27.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Ray(pub u128);
+
+impl I80F48 {
+    pub const FRACTIONAL_BITS: u32 = 48;
+
+    pub fn format(raw: i128) -> String {
+        format_binary_fixed(raw < 0, raw.unsigned_abs(), Self::FRACTIONAL_BITS)
+    }
+}
+
+impl U64F64 {
+    pub const FRACTIONAL_BITS: u32 = 64;
+
+    pub fn format(raw: u128) -> String {
+        format_binary_fixed(false, raw, Self::FRACTIONAL_BITS)
+    }
+}
+
+impl Wad {
+    pub const DECIMALS: u32 = 18;
+
+    pub fn format(raw: u128) -> String {
+        format_decimal_fixed(raw, Self::DECIMALS)
+    }
+}
+
+impl Ray {
+    pub const DECIMALS: u32 = 27;
+
+    pub fn format(raw: u128) -> String {
+        format_decimal_fixed(raw, Self::DECIMALS)
+    }
+}
+
+/// Formats a binary fixed-point magnitude, truncating the fraction to a fixed number of digits.
+pub fn format_binary_fixed(negative: bool, magnitude: u128, fractional_bits: u32) -> String {
+    let integer = magnitude >> fractional_bits;
+    let fraction = magnitude & ((1u128 << fractional_bits) - 1);
+
+    // fraction < 2^64 and 10^15 < 2^50 so the product fits in a u128.
+    let fraction_digits = (fraction * 10u128.pow(BINARY_FIXED_POINT_LOG_DIGITS)) >> fractional_bits;
+
+    format!(
+        "{}{}.{:0width$}",
+        if negative { "-" } else { "" },
+        integer,
+        fraction_digits,
+        width = BINARY_FIXED_POINT_LOG_DIGITS as usize
+    )
+}
+
+/// Formats a decimal fixed-point value with all of its fractional digits.
+pub fn format_decimal_fixed(raw: u128, decimals: u32) -> String {
+    let scale = 10u128.pow(decimals);
+
+    format!(
+        "{}.{:0width$}",
+        raw / scale,
+        raw % scale,
+        width = decimals as usize
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Ray, Wad, I80F48, U64F64};
+
+    #[test]
+    fn format() {
+        assert_eq!(I80F48::format(3 << 47), "1.500000000000000");
+        assert_eq!(I80F48::format(-(3 << 47)), "-1.500000000000000");
+        assert_eq!(
+            I80F48::format(i128::MIN),
+            "-604462909807314587353088.000000000000000"
+        );
+        assert_eq!(U64F64::format(1 << 62), "0.250000000000000");
+        assert_eq!(
+            U64F64::format(u128::MAX),
+            "18446744073709551615.999999999999999"
+        );
+        assert_eq!(
+            Wad::format(1_500_000_000_000_000_000),
+            "1.500000000000000000"
+        );
+        assert_eq!(Ray::format(10u128.pow(27)), "1.000000000000000000000000000");
+    }
+}
@@ -1,9 +1,13 @@
-use super::{Assert, EquatableOperator, Evaluate, IntegerOperator, LogLevel};
+use super::{
+    Assert, EquatableOperator, Evaluate, EvaluateSet, IntegerOperator, LogLevel, SetOperator,
+};
 use crate::error::LighthouseError;
 use crate::generate_asserts_c;
-use crate::utils::Result;
+use crate::types::CompactOptionPubkeys;
+use crate::utils::{get_account_key, unpack_coption, Result};
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+use std::slice;
 
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
 pub enum MintAccountAssertion {
@@ -27,10 +31,33 @@ pub enum MintAccountAssertion {
         value: Option<Pubkey>,
         operator: EquatableOperator,
     },
+    MintAuthorityIn {
+        value: CompactOptionPubkeys,
+        operator: SetOperator,
+    },
+    // Account reference variants compare against the key of the instruction account at `account_index`.
+    MintAuthorityAccountRef {
+        account_index: u8,
+        operator: EquatableOperator,
+    },
+    FreezeAuthorityAccountRef {
+        account_index: u8,
+        operator: EquatableOperator,
+    },
 }
 
 impl Assert<&AccountInfo<'_>> for MintAccountAssertion {
     fn evaluate(&self, account: &AccountInfo<'_>, log_level: LogLevel) -> Result<()> {
+        self.evaluate((account, slice::from_ref(account)), log_level)
+    }
+}
+
+impl Assert<(&AccountInfo<'_>, &[AccountInfo<'_>])> for MintAccountAssertion {
+    fn evaluate(
+        &self,
+        (account, accounts): (&AccountInfo<'_>, &[AccountInfo<'_>]),
+        log_level: LogLevel,
+    ) -> Result<()> {
         let data = account
             .try_borrow_mut_data()
             .map_err(LighthouseError::failed_borrow_err)?;
@@ -44,7 +71,24 @@ impl Assert<&AccountInfo<'_>> for MintAccountAssertion {
             (Supply, u64, 36),
             (Decimals, u8, 44),
             (IsInitialized, bool, 45),
-            (FreezeAuthority, (Option<Pubkey>), 46)
+            (FreezeAuthority, (Option<Pubkey>), 46),
+            (MintAuthorityIn, [Option<Pubkey>], 0)
+            (custom, MintAuthorityAccountRef { account_index, operator },
+                {
+                    let actual_value = unpack_coption::<Pubkey>(&data, 0)?;
+                    let value = get_account_key(accounts, *account_index)?;
+
+                    <Option<&Pubkey>>::evaluate(&actual_value, &Some(value), operator, log_level)
+                }
+            ),
+            (custom, FreezeAuthorityAccountRef { account_index, operator },
+                {
+                    let actual_value = unpack_coption::<Pubkey>(&data, 46)?;
+                    let value = get_account_key(accounts, *account_index)?;
+
+                    <Option<&Pubkey>>::evaluate(&actual_value, &Some(value), operator, log_level)
+                }
+            )
         )
     }
 }
@@ -63,6 +107,7 @@ mod tests {
             test_utils::{assert_failed, assert_passed},
             types::assert::{
                 Assert, EquatableOperator, IntegerOperator, LogLevel, MintAccountAssertion,
+                SetOperator,
             },
         };
 
@@ -249,6 +294,34 @@ mod tests {
             .evaluate(&account_info, LogLevel::PlaintextMessage);
 
             assert_failed(result);
+
+            let result = MintAccountAssertion::MintAuthorityIn {
+                value: vec![
+                    Some(freeze_authority.encodable_pubkey()),
+                    Some(mint_authority.encodable_pubkey()),
+                ]
+                .into(),
+                operator: SetOperator::In,
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage);
+
+            assert_passed(result);
+
+            let result = MintAccountAssertion::MintAuthorityIn {
+                value: vec![None, Some(freeze_authority.encodable_pubkey())].into(),
+                operator: SetOperator::In,
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage);
+
+            assert_failed(result);
+
+            let result = MintAccountAssertion::MintAuthorityIn {
+                value: vec![None, Some(freeze_authority.encodable_pubkey())].into(),
+                operator: SetOperator::NotIn,
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage);
+
+            assert_passed(result);
             //
             // Assert on freeze_authority
             //
@@ -0,0 +1,67 @@
+use super::{Assert, EquatableOperator, Evaluate, IntegerOperator, LogLevel};
+use crate::{
+    error::LighthouseError,
+    utils::{try_from_slice, unpack_coption, Result},
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+
+// Compares a snapshot written by `WriteType::MintAccountSnapshot` against the current state of
+// the mint. Authority fields pass `Equal` when they are unchanged.
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub enum MintAccountDeltaAssertion {
+    Supply {
+        value: i128,
+        operator: IntegerOperator,
+    },
+    MintAuthority {
+        operator: EquatableOperator,
+    },
+    FreezeAuthority {
+        operator: EquatableOperator,
+    },
+}
+
+impl Assert<(&[u8], &AccountInfo<'_>)> for MintAccountDeltaAssertion {
+    fn evaluate(
+        &self,
+        (snapshot, mint_account): (&[u8], &AccountInfo<'_>),
+        log_level: LogLevel,
+    ) -> Result<()> {
+        let data = mint_account
+            .try_borrow_data()
+            .map_err(LighthouseError::failed_borrow_err)?;
+
+        match self {
+            MintAccountDeltaAssertion::Supply { value, operator } => {
+                let a_supply = try_from_slice::<u64>(snapshot, 36)?;
+                let b_supply = try_from_slice::<u64>(&data, 36)?;
+                let diff_value = b_supply as i128 - a_supply as i128;
+
+                i128::evaluate(&diff_value, value, operator, log_level)
+            }
+            MintAccountDeltaAssertion::MintAuthority { operator } => {
+                let a_authority = unpack_coption::<Pubkey>(snapshot, 0)?;
+                let b_authority = unpack_coption::<Pubkey>(&data, 0)?;
+
+                <Option<&Pubkey>>::evaluate(&a_authority, &b_authority, operator, log_level)
+            }
+            MintAccountDeltaAssertion::FreezeAuthority { operator } => {
+                let a_authority = unpack_coption::<Pubkey>(snapshot, 46)?;
+                let b_authority = unpack_coption::<Pubkey>(&data, 46)?;
+
+                <Option<&Pubkey>>::evaluate(&a_authority, &b_authority, operator, log_level)
+            }
+        }
+    }
+}
+
+impl Assert<(&[u8], &AccountInfo<'_>, &[AccountInfo<'_>])> for MintAccountDeltaAssertion {
+    fn evaluate(
+        &self,
+        (snapshot, mint_account, _): (&[u8], &AccountInfo<'_>, &[AccountInfo<'_>]),
+        log_level: LogLevel,
+    ) -> Result<()> {
+        self.evaluate((snapshot, mint_account), log_level)
+    }
+}
@@ -5,13 +5,20 @@ pub mod assertion_result;
 pub mod bubblegum_tree_config;
 pub mod clock;
 pub mod evaluate;
+pub mod fixed_point;
 pub mod known_program;
 pub mod log_level;
 pub mod merkle_tree;
 pub mod mint_account;
+pub mod mint_account_delta;
+pub mod offset_path;
 pub mod operator;
+pub mod pda_seed;
+pub mod return_data;
 pub mod stake_account;
 pub mod token_account;
+pub mod token_account_delta;
+pub mod ui_amount;
 pub mod upgradable_loader_state;
 
 pub use account_data::*;
@@ -21,13 +28,20 @@ pub use assertion_result::*;
 pub use bubblegum_tree_config::*;
 pub use clock::*;
 pub use evaluate::*;
+pub use fixed_point::*;
 pub use known_program::*;
 pub use log_level::*;
 pub use merkle_tree::*;
 pub use mint_account::*;
+pub use mint_account_delta::*;
+pub use offset_path::*;
 pub use operator::*;
+pub use pda_seed::*;
+pub use return_data::*;
 pub use stake_account::*;
 pub use token_account::*;
+pub use token_account_delta::*;
+pub use ui_amount::*;
 pub use upgradable_loader_state::*;
 
 use crate::utils::Result;
@@ -0,0 +1,238 @@
+use crate::{
+    error::LighthouseError,
+    types::OffsetSteps,
+    utils::{try_from_slice, Result},
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use lighthouse_common::CompactU64;
+use solana_program::msg;
+
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub enum OffsetStep {
+    /// Advances the cursor by a fixed number of bytes.
+    Advance { length: CompactU64 },
+    /// Skips a Borsh `Vec` or `String` at the cursor by reading its u32 length prefix.
+    SkipVec { element_size: CompactU64 },
+    /// Skips a Borsh `Option` at the cursor by reading its tag, skipping `size` more bytes if it is `Some`.
+    SkipOption { size: CompactU64 },
+    /// Advances the cursor by the u32 read at `cursor + offset`.
+    AdvanceByU32 { offset: CompactU64 },
+    /// Advances the cursor by the u64 read at `cursor + offset`.
+    AdvanceByU64 { offset: CompactU64 },
+}
+
+/// Resolves the offset of a field that follows dynamically sized fields, starting at `start`
+/// and applying each step in order.
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub struct OffsetPath {
+    pub start: CompactU64,
+    pub steps: OffsetSteps,
+}
+
+impl OffsetPath {
+    pub fn resolve(&self, data: &[u8]) -> Result<usize> {
+        let mut cursor = *self.start;
+
+        for step in self.steps.iter() {
+            let advance_by = match step {
+                OffsetStep::Advance { length } => Some(**length),
+                OffsetStep::SkipVec { element_size } => {
+                    let length = try_from_slice::<u32>(data, cursor as usize)?;
+
+                    (length as u64)
+                        .checked_mul(**element_size)
+                        .and_then(|size| size.checked_add(4))
+                }
+                OffsetStep::SkipOption { size } => {
+                    match try_from_slice::<u8>(data, cursor as usize)? {
+                        0 => Some(1),
+                        1 => size.checked_add(1),
+                        tag => {
+                            msg!("Invalid option tag {} at offset {}", tag, cursor);
+                            return Err(LighthouseError::FailedToDeserialize.into());
+                        }
+                    }
+                }
+                OffsetStep::AdvanceByU32 { offset } => {
+                    let location = cursor
+                        .checked_add(**offset)
+                        .ok_or_else(|| LighthouseError::oob_err(cursor as usize..usize::MAX))?;
+
+                    Some(try_from_slice::<u32>(data, location as usize)? as u64)
+                }
+                OffsetStep::AdvanceByU64 { offset } => {
+                    let location = cursor
+                        .checked_add(**offset)
+                        .ok_or_else(|| LighthouseError::oob_err(cursor as usize..usize::MAX))?;
+
+                    Some(try_from_slice::<u64>(data, location as usize)?)
+                }
+            };
+
+            cursor = advance_by
+                .and_then(|advance_by| cursor.checked_add(advance_by))
+                .ok_or_else(|| LighthouseError::oob_err(cursor as usize..usize::MAX))?;
+        }
+
+        usize::try_from(cursor).map_err(|_| LighthouseError::oob_err(0..usize::MAX))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OffsetPath, OffsetStep};
+    use crate::{
+        error::LighthouseError,
+        test_utils::{assert_failed, assert_passed},
+        types::assert::{
+            AccountDataPathAssertion, Assert, DataValueAssertion, IntegerOperator, LogLevel,
+        },
+    };
+    use borsh::BorshSerialize;
+    use solana_sdk::{account_info::AccountInfo, pubkey::Pubkey};
+
+    #[derive(BorshSerialize)]
+    struct TestAccount {
+        discriminator: [u8; 8],
+        name: String,
+        authority: Option<Pubkey>,
+        items: Vec<u64>,
+        header_length: u32,
+        header: [u8; 3],
+        target: u64,
+    }
+
+    fn test_account_path() -> OffsetPath {
+        OffsetPath {
+            start: 8u8.into(),
+            steps: vec![
+                OffsetStep::SkipVec {
+                    element_size: 1u8.into(),
+                },
+                OffsetStep::SkipOption { size: 32u8.into() },
+                OffsetStep::SkipVec {
+                    element_size: 8u8.into(),
+                },
+                OffsetStep::AdvanceByU32 { offset: 0u8.into() },
+                OffsetStep::Advance { length: 4u8.into() },
+            ]
+            .into(),
+        }
+    }
+
+    #[test]
+    fn resolve() {
+        let data = TestAccount {
+            discriminator: [1; 8],
+            name: "lighthouse".to_string(),
+            authority: Some(Pubkey::new_unique()),
+            items: vec![1, 2, 3],
+            header_length: 3,
+            header: [0; 3],
+            target: 69,
+        }
+        .try_to_vec()
+        .unwrap();
+
+        // 8 + (4 + 10) + (1 + 32) + (4 + 24) + 3 + 4
+        assert_eq!(test_account_path().resolve(&data).unwrap(), 90);
+
+        let data = TestAccount {
+            discriminator: [1; 8],
+            name: "".to_string(),
+            authority: None,
+            items: vec![],
+            header_length: 3,
+            header: [0; 3],
+            target: 69,
+        }
+        .try_to_vec()
+        .unwrap();
+
+        // 8 + 4 + 1 + 4 + 3 + 4
+        assert_eq!(test_account_path().resolve(&data).unwrap(), 24);
+    }
+
+    #[test]
+    fn resolve_invalid() {
+        // Option tag must be 0 or 1
+        let path = OffsetPath {
+            start: 0u8.into(),
+            steps: vec![OffsetStep::SkipOption { size: 32u8.into() }].into(),
+        };
+
+        assert_eq!(
+            path.resolve(&[2u8; 64]).err().unwrap(),
+            LighthouseError::FailedToDeserialize.into()
+        );
+
+        // Length prefix out of bounds
+        let path = OffsetPath {
+            start: 62u8.into(),
+            steps: vec![OffsetStep::SkipVec {
+                element_size: 1u8.into(),
+            }]
+            .into(),
+        };
+
+        assert_eq!(
+            path.resolve(&[0u8; 64]).err().unwrap(),
+            LighthouseError::RangeOutOfBounds.into()
+        );
+
+        // Overflowing vec sizes
+        let path = OffsetPath {
+            start: 0u8.into(),
+            steps: vec![OffsetStep::SkipVec {
+                element_size: u64::MAX.into(),
+            }]
+            .into(),
+        };
+
+        assert_eq!(
+            path.resolve(&[255u8; 64]).err().unwrap(),
+            LighthouseError::RangeOutOfBounds.into()
+        );
+    }
+
+    #[test]
+    fn evaluate_path_assertion() {
+        let key = Pubkey::new_unique();
+        let lamports = &mut 0;
+        let data: &mut [u8] = &mut TestAccount {
+            discriminator: [1; 8],
+            name: "lighthouse".to_string(),
+            authority: None,
+            items: vec![1, 2],
+            header_length: 3,
+            header: [0; 3],
+            target: 69,
+        }
+        .try_to_vec()
+        .unwrap();
+
+        let account_info = AccountInfo::new(&key, false, false, lamports, data, &key, false, 0);
+
+        let result = AccountDataPathAssertion {
+            path: test_account_path(),
+            assertion: DataValueAssertion::U64 {
+                value: 69,
+                operator: IntegerOperator::Equal,
+            },
+        }
+        .evaluate(&account_info, LogLevel::PlaintextMessage);
+
+        assert_passed(result);
+
+        let result = AccountDataPathAssertion {
+            path: test_account_path(),
+            assertion: DataValueAssertion::U64 {
+                value: 70,
+                operator: IntegerOperator::Equal,
+            },
+        }
+        .evaluate(&account_info, LogLevel::PlaintextMessage);
+
+        assert_failed(result);
+    }
+}
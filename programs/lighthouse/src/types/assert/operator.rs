@@ -1,5 +1,5 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use std::fmt::Debug;
+use std::{cmp::Ordering, fmt::Debug};
 
 use crate::error::LighthouseError;
 
@@ -11,13 +11,20 @@ const GREATER_THAN_OR_EQUAL_SYMBOL: &str = ">=";
 const LESS_THAN_OR_EQUAL_SYMBOL: &str = "<=";
 const CONTAINS_SYMBOL: &str = "&";
 const DOES_NOT_CONTAIN_SYMBOL: &str = "|";
+const IN_SYMBOL: &str = "in";
+const NOT_IN_SYMBOL: &str = "not in";
+const BETWEEN_SYMBOL: &str = "between";
+const NOT_BETWEEN_SYMBOL: &str = "not between";
+const STARTS_WITH_SYMBOL: &str = "starts with";
+const CONTAINS_BYTES_SYMBOL: &str = "contains";
+const DOES_NOT_CONTAIN_BYTES_SYMBOL: &str = "does not contain";
+const ENDS_WITH_SYMBOL: &str = "ends with";
 
 pub trait Operator: Sized {
     fn format(&self) -> &str;
 }
 
 #[derive(BorshDeserialize, BorshSerialize, Debug, Copy, Clone)]
-#[repr(u8)]
 pub enum IntegerOperator {
     Equal,
     NotEqual,
@@ -27,6 +34,99 @@ pub enum IntegerOperator {
     LessThanOrEqual,
     Contains,
     DoesNotContain,
+    // Range operators, bounds are widened to i128 so a single operator can be used for every integer width.
+    // Account data ranges can use `DataValueAssertion::IntegerRange` instead, which has no value.
+    // Floating-point values support neither the bitmask nor the range operators.
+    Between { lower: i128, upper: i128 },
+    NotBetween { lower: i128, upper: i128 },
+    BetweenExclusive { lower: i128, upper: i128 },
+    NotBetweenExclusive { lower: i128, upper: i128 },
+}
+
+impl IntegerOperator {
+    pub fn bounds(&self) -> Option<(i128, i128)> {
+        match self {
+            IntegerOperator::Between { lower, upper }
+            | IntegerOperator::NotBetween { lower, upper }
+            | IntegerOperator::BetweenExclusive { lower, upper }
+            | IntegerOperator::NotBetweenExclusive { lower, upper } => Some((*lower, *upper)),
+            _ => None,
+        }
+    }
+
+    /// Applies `f` to the bounds of a range operator, non-range operators are returned as is.
+    pub fn map_bounds(&self, f: impl Fn(i128) -> i128) -> Self {
+        match self {
+            IntegerOperator::Between { lower, upper } => IntegerOperator::Between {
+                lower: f(*lower),
+                upper: f(*upper),
+            },
+            IntegerOperator::NotBetween { lower, upper } => IntegerOperator::NotBetween {
+                lower: f(*lower),
+                upper: f(*upper),
+            },
+            IntegerOperator::BetweenExclusive { lower, upper } => {
+                IntegerOperator::BetweenExclusive {
+                    lower: f(*lower),
+                    upper: f(*upper),
+                }
+            }
+            IntegerOperator::NotBetweenExclusive { lower, upper } => {
+                IntegerOperator::NotBetweenExclusive {
+                    lower: f(*lower),
+                    upper: f(*upper),
+                }
+            }
+            _ => *self,
+        }
+    }
+
+    /// Evaluates a range operator where `cmp` orders the actual value against a bound.
+    ///
+    /// Non-range operators always evaluate to false.
+    pub fn evaluate_range(&self, cmp: impl Fn(&i128) -> Ordering) -> bool {
+        match self.range() {
+            Some((operator, lower, upper)) => operator.evaluate(lower, upper, cmp),
+            None => false,
+        }
+    }
+
+    pub fn range(&self) -> Option<(RangeOperator, i128, i128)> {
+        match self {
+            IntegerOperator::Between { lower, upper } => {
+                Some((RangeOperator::Between, *lower, *upper))
+            }
+            IntegerOperator::NotBetween { lower, upper } => {
+                Some((RangeOperator::NotBetween, *lower, *upper))
+            }
+            IntegerOperator::BetweenExclusive { lower, upper } => {
+                Some((RangeOperator::BetweenExclusive, *lower, *upper))
+            }
+            IntegerOperator::NotBetweenExclusive { lower, upper } => {
+                Some((RangeOperator::NotBetweenExclusive, *lower, *upper))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl From<IntegerOperator> for u8 {
+    fn from(operator: IntegerOperator) -> Self {
+        match operator {
+            IntegerOperator::Equal => 0,
+            IntegerOperator::NotEqual => 1,
+            IntegerOperator::GreaterThan => 2,
+            IntegerOperator::LessThan => 3,
+            IntegerOperator::GreaterThanOrEqual => 4,
+            IntegerOperator::LessThanOrEqual => 5,
+            IntegerOperator::Contains => 6,
+            IntegerOperator::DoesNotContain => 7,
+            IntegerOperator::Between { .. } => 8,
+            IntegerOperator::NotBetween { .. } => 9,
+            IntegerOperator::BetweenExclusive { .. } => 10,
+            IntegerOperator::NotBetweenExclusive { .. } => 11,
+        }
+    }
 }
 
 impl TryFrom<u8> for IntegerOperator {
@@ -42,11 +142,23 @@ impl TryFrom<u8> for IntegerOperator {
             5 => Ok(IntegerOperator::LessThanOrEqual),
             6 => Ok(IntegerOperator::Contains),
             7 => Ok(IntegerOperator::DoesNotContain),
+            // Range codes carry no bounds, they are decoded with `RangeOperator::try_from`.
             _ => Err(LighthouseError::InvalidInstructionData),
         }
     }
 }
 
+/// Formats a range operator code and its bounds, e.g. `between [0, 10]` or `not between (0, 10)`.
+pub fn format_range(operator: u8, lower: i128, upper: i128) -> String {
+    match RangeOperator::try_from(operator) {
+        Ok(operator @ (RangeOperator::Between | RangeOperator::NotBetween)) => {
+            format!("{} [{}, {}]", operator.format(), lower, upper)
+        }
+        Ok(operator) => format!("{} ({}, {})", operator.format(), lower, upper),
+        Err(_) => format!("? [{}, {}]", lower, upper),
+    }
+}
+
 impl Operator for IntegerOperator {
     fn format(&self) -> &str {
         match self {
@@ -58,6 +170,82 @@ impl Operator for IntegerOperator {
             IntegerOperator::LessThanOrEqual => LESS_THAN_OR_EQUAL_SYMBOL,
             IntegerOperator::Contains => CONTAINS_SYMBOL,
             IntegerOperator::DoesNotContain => DOES_NOT_CONTAIN_SYMBOL,
+            IntegerOperator::Between { .. } | IntegerOperator::BetweenExclusive { .. } => {
+                BETWEEN_SYMBOL
+            }
+            IntegerOperator::NotBetween { .. } | IntegerOperator::NotBetweenExclusive { .. } => {
+                NOT_BETWEEN_SYMBOL
+            }
+        }
+    }
+}
+
+// Logged with the same codes as the matching `IntegerOperator` range variants.
+#[derive(BorshDeserialize, BorshSerialize, Debug, Copy, Clone)]
+#[repr(u8)]
+pub enum RangeOperator {
+    Between,
+    NotBetween,
+    BetweenExclusive,
+    NotBetweenExclusive,
+}
+
+impl RangeOperator {
+    pub fn with_bounds(self, lower: i128, upper: i128) -> IntegerOperator {
+        match self {
+            RangeOperator::Between => IntegerOperator::Between { lower, upper },
+            RangeOperator::NotBetween => IntegerOperator::NotBetween { lower, upper },
+            RangeOperator::BetweenExclusive => IntegerOperator::BetweenExclusive { lower, upper },
+            RangeOperator::NotBetweenExclusive => {
+                IntegerOperator::NotBetweenExclusive { lower, upper }
+            }
+        }
+    }
+
+    /// Evaluates the range where `cmp` orders the actual value against a bound.
+    pub fn evaluate(&self, lower: i128, upper: i128, cmp: impl Fn(&i128) -> Ordering) -> bool {
+        match self {
+            RangeOperator::Between => {
+                cmp(&lower) != Ordering::Less && cmp(&upper) != Ordering::Greater
+            }
+            RangeOperator::NotBetween => {
+                cmp(&lower) == Ordering::Less || cmp(&upper) == Ordering::Greater
+            }
+            RangeOperator::BetweenExclusive => {
+                cmp(&lower) == Ordering::Greater && cmp(&upper) == Ordering::Less
+            }
+            RangeOperator::NotBetweenExclusive => {
+                cmp(&lower) != Ordering::Greater || cmp(&upper) != Ordering::Less
+            }
+        }
+    }
+}
+
+impl From<RangeOperator> for u8 {
+    fn from(operator: RangeOperator) -> Self {
+        u8::from(operator.with_bounds(0, 0))
+    }
+}
+
+impl TryFrom<u8> for RangeOperator {
+    type Error = LighthouseError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            8 => Ok(RangeOperator::Between),
+            9 => Ok(RangeOperator::NotBetween),
+            10 => Ok(RangeOperator::BetweenExclusive),
+            11 => Ok(RangeOperator::NotBetweenExclusive),
+            _ => Err(LighthouseError::InvalidInstructionData),
+        }
+    }
+}
+
+impl Operator for RangeOperator {
+    fn format(&self) -> &str {
+        match self {
+            RangeOperator::Between | RangeOperator::BetweenExclusive => BETWEEN_SYMBOL,
+            RangeOperator::NotBetween | RangeOperator::NotBetweenExclusive => NOT_BETWEEN_SYMBOL,
         }
     }
 }
@@ -89,3 +277,96 @@ impl Operator for EquatableOperator {
         }
     }
 }
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Copy, Clone)]
+#[repr(u8)]
+pub enum SetOperator {
+    In,
+    NotIn,
+}
+
+impl TryFrom<u8> for SetOperator {
+    type Error = LighthouseError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(SetOperator::In),
+            1 => Ok(SetOperator::NotIn),
+            _ => Err(LighthouseError::InvalidInstructionData),
+        }
+    }
+}
+
+impl Operator for SetOperator {
+    fn format(&self) -> &str {
+        match self {
+            SetOperator::In => IN_SYMBOL,
+            SetOperator::NotIn => NOT_IN_SYMBOL,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Copy, Clone)]
+#[repr(u8)]
+pub enum BytesOperator {
+    StartsWith,
+    Contains,
+    DoesNotContain,
+}
+
+impl TryFrom<u8> for BytesOperator {
+    type Error = LighthouseError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(BytesOperator::StartsWith),
+            1 => Ok(BytesOperator::Contains),
+            2 => Ok(BytesOperator::DoesNotContain),
+            _ => Err(LighthouseError::InvalidInstructionData),
+        }
+    }
+}
+
+impl Operator for BytesOperator {
+    fn format(&self) -> &str {
+        match self {
+            BytesOperator::StartsWith => STARTS_WITH_SYMBOL,
+            BytesOperator::Contains => CONTAINS_BYTES_SYMBOL,
+            BytesOperator::DoesNotContain => DOES_NOT_CONTAIN_BYTES_SYMBOL,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Copy, Clone)]
+#[repr(u8)]
+pub enum StringOperator {
+    Equal,
+    NotEqual,
+    StartsWith,
+    EndsWith,
+}
+
+impl TryFrom<u8> for StringOperator {
+    type Error = LighthouseError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(StringOperator::Equal),
+            1 => Ok(StringOperator::NotEqual),
+            2 => Ok(StringOperator::StartsWith),
+            3 => Ok(StringOperator::EndsWith),
+            _ => Err(LighthouseError::InvalidInstructionData),
+        }
+    }
+}
+
+impl Operator for StringOperator {
+    fn format(&self) -> &str {
+        match self {
+            StringOperator::Equal => EQUAL_SYMBOL,
+            StringOperator::NotEqual => NOT_EQUAL_SYMBOL,
+            StringOperator::StartsWith => STARTS_WITH_SYMBOL,
+            StringOperator::EndsWith => ENDS_WITH_SYMBOL,
+        }
+    }
+}
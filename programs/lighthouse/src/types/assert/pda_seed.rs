@@ -0,0 +1,166 @@
+use crate::{
+    error::LighthouseError,
+    types::CompactBytes,
+    utils::{checked_get_slice, get_account, get_account_key, Result},
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use lighthouse_common::CompactU64;
+use solana_program::{account_info::AccountInfo, msg, pubkey::Pubkey};
+
+/// A seed used to derive a program address, indexes refer to the instruction accounts.
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub enum PdaSeed {
+    Bytes {
+        value: CompactBytes,
+    },
+    AccountKey {
+        account_index: u8,
+    },
+    AccountData {
+        account_index: u8,
+        offset: CompactU64,
+        length: CompactU64,
+    },
+}
+
+impl PdaSeed {
+    pub fn resolve(&self, accounts: &[AccountInfo]) -> Result<Vec<u8>> {
+        match self {
+            PdaSeed::Bytes { value } => Ok(value.to_vec()),
+            PdaSeed::AccountKey { account_index } => Ok(get_account_key(accounts, *account_index)?
+                .to_bytes()
+                .to_vec()),
+            PdaSeed::AccountData {
+                account_index,
+                offset,
+                length,
+            } => {
+                let data = get_account(accounts, *account_index)?
+                    .try_borrow_data()
+                    .map_err(LighthouseError::failed_borrow_err)?;
+
+                Ok(checked_get_slice(&data, **offset as usize, **length as usize)?.to_vec())
+            }
+        }
+    }
+}
+
+/// Derives the program address for `seeds`, searching for the canonical bump if none is given.
+///
+/// Returns `None` if the seeds and bump have no valid program address, which no key can match.
+pub fn derive_program_address(
+    seeds: &[PdaSeed],
+    program_id: &Pubkey,
+    bump: Option<u8>,
+    accounts: &[AccountInfo],
+) -> Result<Option<Pubkey>> {
+    let mut seeds = seeds
+        .iter()
+        .map(|seed| seed.resolve(accounts))
+        .collect::<Result<Vec<_>>>()?;
+
+    let address = match bump {
+        Some(bump) => {
+            seeds.push(vec![bump]);
+            let seeds = seeds.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+            Pubkey::create_program_address(&seeds, program_id).ok()
+        }
+        None => {
+            let seeds = seeds.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+            Pubkey::try_find_program_address(&seeds, program_id).map(|(address, _)| address)
+        }
+    };
+
+    if address.is_none() {
+        msg!("No program address for the given seeds and bump");
+    }
+
+    Ok(address)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{derive_program_address, PdaSeed};
+    use crate::{error::LighthouseError, utils::Result};
+    use solana_sdk::{account_info::AccountInfo, pubkey::Pubkey};
+
+    #[test]
+    fn derive() {
+        let program_id = Pubkey::new_unique();
+        let (pool_key, mint_key) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (pool_lamports, mint_lamports) = (&mut 0, &mut 0);
+        let mut pool_data = vec![0u8; 8];
+        pool_data.extend(mint_key.to_bytes());
+        let mint_data: &mut [u8] = &mut [];
+
+        let accounts = [
+            AccountInfo::new(
+                &pool_key,
+                false,
+                false,
+                pool_lamports,
+                &mut pool_data,
+                &program_id,
+                false,
+                0,
+            ),
+            AccountInfo::new(
+                &mint_key,
+                false,
+                false,
+                mint_lamports,
+                mint_data,
+                &program_id,
+                false,
+                0,
+            ),
+        ];
+
+        let (expected, bump) = Pubkey::find_program_address(
+            &[b"vault", pool_key.as_ref(), mint_key.as_ref()],
+            &program_id,
+        );
+
+        let seeds = vec![
+            PdaSeed::Bytes {
+                value: b"vault".to_vec().into(),
+            },
+            PdaSeed::AccountKey { account_index: 0 },
+            PdaSeed::AccountData {
+                account_index: 0,
+                offset: 8u8.into(),
+                length: 32u8.into(),
+            },
+        ];
+
+        let derive = |bump| derive_program_address(&seeds, &program_id, bump, &accounts);
+
+        assert_eq!(derive(None).unwrap(), Some(expected));
+        assert_eq!(derive(Some(bump)).unwrap(), Some(expected));
+
+        // Roughly half of all bumps land on the curve and have no program address.
+        let invalid_bump = (0..=u8::MAX)
+            .find(|bump| {
+                Pubkey::create_program_address(
+                    &[b"vault", pool_key.as_ref(), mint_key.as_ref(), &[*bump]],
+                    &program_id,
+                )
+                .is_err()
+            })
+            .unwrap();
+        assert_eq!(derive(Some(invalid_bump)).unwrap(), None);
+
+        let result: Result<Option<Pubkey>> = derive_program_address(
+            &[PdaSeed::AccountKey { account_index: 2 }],
+            &program_id,
+            None,
+            &accounts,
+        );
+        assert_eq!(
+            result.err().unwrap(),
+            LighthouseError::NotEnoughAccounts.into()
+        );
+    }
+}
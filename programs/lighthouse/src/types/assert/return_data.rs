@@ -0,0 +1,103 @@
+use super::{Assert, DataValueAssertion, EquatableOperator, LogLevel};
+use crate::{error::LighthouseError, types::assert::evaluate::Evaluate, utils::Result};
+use borsh::{BorshDeserialize, BorshSerialize};
+use lighthouse_common::CompactU64;
+use solana_program::{account_info::AccountInfo, msg, program::get_return_data, pubkey::Pubkey};
+
+// Asserts on the data most recently set through `set_return_data` in the transaction, such as the
+// realized output amount of a swap.
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub struct ReturnDataAssertion {
+    pub program_id: Pubkey,
+    pub offset: CompactU64,
+    pub assertion: DataValueAssertion,
+}
+
+impl Assert<()> for ReturnDataAssertion {
+    fn evaluate(&self, _: (), log_level: LogLevel) -> Result<()> {
+        self.evaluate(get_return_data(), log_level)
+    }
+}
+
+impl Assert<Option<(Pubkey, Vec<u8>)>> for ReturnDataAssertion {
+    fn evaluate(&self, return_data: Option<(Pubkey, Vec<u8>)>, log_level: LogLevel) -> Result<()> {
+        let (program_id, data) = return_data.ok_or_else(|| {
+            msg!("No return data set");
+            LighthouseError::AssertionFailed
+        })?;
+
+        Pubkey::evaluate(
+            &program_id,
+            &self.program_id,
+            &EquatableOperator::Equal,
+            log_level,
+        )?;
+
+        self.assertion.evaluate(
+            (
+                data.as_slice(),
+                *self.offset as usize,
+                &[] as &[AccountInfo],
+            ),
+            log_level,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::LighthouseError,
+        test_utils::{assert_failed, assert_passed},
+        types::assert::{Assert, DataValueAssertion, IntegerOperator, LogLevel},
+    };
+    use solana_sdk::{program_error::ProgramError, pubkey::Pubkey};
+
+    use super::ReturnDataAssertion;
+
+    #[test]
+    fn evaluate_return_data() {
+        let program_id = Pubkey::new_unique();
+        let mut data = vec![0u8; 4];
+        data.extend_from_slice(&1_000_000u64.to_le_bytes());
+
+        let assertion =
+            |program_id: Pubkey, value: u64, operator: IntegerOperator| ReturnDataAssertion {
+                program_id,
+                offset: 4u8.into(),
+                assertion: DataValueAssertion::U64 { value, operator },
+            };
+
+        let result = assertion(program_id, 1_000_000, IntegerOperator::Equal)
+            .evaluate(Some((program_id, data.clone())), LogLevel::PlaintextMessage);
+        assert_passed(result);
+
+        let result = assertion(program_id, 999_999, IntegerOperator::GreaterThan)
+            .evaluate(Some((program_id, data.clone())), LogLevel::PlaintextMessage);
+        assert_passed(result);
+
+        let result = assertion(program_id, 1_000_001, IntegerOperator::GreaterThanOrEqual)
+            .evaluate(Some((program_id, data.clone())), LogLevel::PlaintextMessage);
+        assert_failed(result);
+
+        // Return data set by a different program
+        let result = assertion(program_id, 1_000_000, IntegerOperator::Equal).evaluate(
+            Some((Pubkey::new_unique(), data.clone())),
+            LogLevel::PlaintextMessage,
+        );
+        assert_failed(result);
+
+        let result = assertion(program_id, 1_000_000, IntegerOperator::Equal)
+            .evaluate(None, LogLevel::PlaintextMessage);
+        assert_failed(result);
+
+        let result = assertion(program_id, 1_000_000, IntegerOperator::Equal).evaluate(
+            Some((program_id, data[..8].to_vec())),
+            LogLevel::PlaintextMessage,
+        );
+        assert_eq!(
+            result.err().unwrap(),
+            ProgramError::from(LighthouseError::RangeOutOfBounds)
+        );
+    }
+}
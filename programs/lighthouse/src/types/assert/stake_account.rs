@@ -1,14 +1,24 @@
 use super::{Assert, EquatableOperator, IntegerOperator, LogLevel};
 use crate::types::assert::evaluate::Evaluate;
-use crate::utils::Result;
+use crate::utils::{keys_equal, try_from_slice, Result};
 use crate::{err, err_msg, error::LighthouseError};
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::msg;
 use solana_program::{
+    account_info::AccountInfo,
+    clock::{Clock, Epoch},
+    epoch_schedule::EpochSchedule,
+    feature::{self, Feature},
+    pubkey,
     pubkey::Pubkey,
-    stake::state::{Meta as StakeMeta, Stake as StakeInfo, StakeStateV2},
+    stake::state::{Meta as StakeMeta, Stake as StakeInfo, StakeActivationStatus, StakeStateV2},
+    stake_history::{StakeHistory, StakeHistoryEntry},
+    sysvar::{self, Sysvar},
 };
 
+pub const REDUCE_STAKE_WARMUP_COOLDOWN_FEATURE_ID: Pubkey =
+    pubkey!("GwtDQBghCTBgmX2cpEGNPxTEBUTQRaDMGTr5qychdGMj");
+
 #[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Copy)]
 #[repr(u8)]
 pub enum StakeStateType {
@@ -30,10 +40,17 @@ pub enum StakeAccountAssertion {
         value: u8,
         operator: IntegerOperator,
     },
+    /// Evaluated at the current epoch, the StakeHistory sysvar and the reduced warmup/cooldown
+    /// feature account must be passed in the instruction accounts.
+    StakeActivationAssertion(StakeActivationAssertion),
 }
 
-impl<'a> Assert<&'a StakeStateV2> for StakeAccountAssertion {
-    fn evaluate(&self, stake_account: &'a StakeStateV2, log_level: LogLevel) -> Result<()> {
+impl Assert<(&StakeStateV2, &[AccountInfo<'_>])> for StakeAccountAssertion {
+    fn evaluate(
+        &self,
+        (stake_account, accounts): (&StakeStateV2, &[AccountInfo<'_>]),
+        log_level: LogLevel,
+    ) -> Result<()> {
         match self {
             StakeAccountAssertion::State {
                 value: assertion_value,
@@ -85,10 +102,94 @@ impl<'a> Assert<&'a StakeStateV2> for StakeAccountAssertion {
                     }
                 }
             }
+            StakeAccountAssertion::StakeActivationAssertion(assertion) => match stake_account {
+                StakeStateV2::Stake(_, stake, _) => {
+                    let stake_history =
+                        load_stake_history(accounts, stake.delegation.activation_epoch)?;
+
+                    assertion.evaluate(
+                        (
+                            stake,
+                            Clock::get()?.epoch,
+                            &stake_history,
+                            load_new_rate_activation_epoch(accounts)?,
+                        ),
+                        log_level,
+                    )
+                }
+                _ => {
+                    msg!("Stake account is not in a state that has stake field");
+                    Err(LighthouseError::AssertionFailed.into())
+                }
+            },
         }
     }
 }
 
+// Reads the StakeHistory sysvar from the instruction accounts. Entries are stored newest first and
+// only those from `from_epoch` on are deserialized, as stake never reads the history of the epochs
+// before its activation. Stake activated long ago still reads the whole sysvar, up to 512 entries
+// of 32 bytes.
+pub(crate) fn load_stake_history(
+    accounts: &[AccountInfo],
+    from_epoch: Epoch,
+) -> Result<StakeHistory> {
+    let stake_history_account = accounts
+        .iter()
+        .find(|account| keys_equal(account.key, &sysvar::stake_history::ID))
+        .ok_or_else(|| {
+            msg!("StakeHistory sysvar account not provided");
+            LighthouseError::NotEnoughAccounts
+        })?;
+    let data = stake_history_account
+        .try_borrow_data()
+        .map_err(LighthouseError::failed_borrow_err)?;
+
+    let mut stake_history = StakeHistory::default();
+    for index in 0..try_from_slice::<u64>(&data, 0)? as usize {
+        let offset = 8 + index * 32;
+        let epoch = try_from_slice::<u64>(&data, offset)?;
+        if epoch < from_epoch {
+            break;
+        }
+
+        stake_history.add(
+            epoch,
+            StakeHistoryEntry {
+                effective: try_from_slice::<u64>(&data, offset + 8)?,
+                activating: try_from_slice::<u64>(&data, offset + 16)?,
+                deactivating: try_from_slice::<u64>(&data, offset + 24)?,
+            },
+        );
+    }
+
+    Ok(stake_history)
+}
+
+// Epoch the reduced warmup/cooldown rate activated on the cluster, read from the feature account
+// passed in the instruction accounts the same way the runtime does.
+pub(crate) fn load_new_rate_activation_epoch(accounts: &[AccountInfo]) -> Result<Option<Epoch>> {
+    let feature_account = accounts
+        .iter()
+        .find(|account| keys_equal(account.key, &REDUCE_STAKE_WARMUP_COOLDOWN_FEATURE_ID))
+        .ok_or_else(|| {
+            msg!("Reduce stake warmup/cooldown feature account not provided");
+            LighthouseError::NotEnoughAccounts
+        })?;
+
+    // The feature account only exists once the feature has been proposed.
+    if !keys_equal(feature_account.owner, &feature::ID) {
+        return Ok(None);
+    }
+
+    let activated_at = Feature::from_account_info(feature_account)?.activated_at;
+
+    Ok(match activated_at {
+        Some(slot) => Some(EpochSchedule::get()?.get_epoch(slot)),
+        None => None,
+    })
+}
+
 #[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
 pub enum MetaAssertion {
     RentExemptReserve {
@@ -243,3 +344,223 @@ impl Assert<&StakeInfo> for StakeAssertion {
         }
     }
 }
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub enum StakeActivationAssertion {
+    EffectiveStake {
+        value: u64,
+        operator: IntegerOperator,
+    },
+    ActivatingStake {
+        value: u64,
+        operator: IntegerOperator,
+    },
+    DeactivatingStake {
+        value: u64,
+        operator: IntegerOperator,
+    },
+    /// All delegated stake is effective with nothing activating or deactivating.
+    IsFullyActive {
+        value: bool,
+        operator: EquatableOperator,
+    },
+    /// No stake is effective, activating or deactivating.
+    IsFullyDeactivated {
+        value: bool,
+        operator: EquatableOperator,
+    },
+}
+
+impl Assert<(&StakeInfo, Epoch, &StakeHistory, Option<Epoch>)> for StakeActivationAssertion {
+    fn evaluate(
+        &self,
+        (stake, epoch, stake_history, new_rate_activation_epoch): (
+            &StakeInfo,
+            Epoch,
+            &StakeHistory,
+            Option<Epoch>,
+        ),
+        log_level: LogLevel,
+    ) -> Result<()> {
+        let StakeActivationStatus {
+            effective,
+            activating,
+            deactivating,
+        } = stake.delegation.stake_activating_and_deactivating(
+            epoch,
+            stake_history,
+            new_rate_activation_epoch,
+        );
+
+        match self {
+            StakeActivationAssertion::EffectiveStake { value, operator } => {
+                u64::evaluate(&effective, value, operator, log_level)
+            }
+            StakeActivationAssertion::ActivatingStake { value, operator } => {
+                u64::evaluate(&activating, value, operator, log_level)
+            }
+            StakeActivationAssertion::DeactivatingStake { value, operator } => {
+                u64::evaluate(&deactivating, value, operator, log_level)
+            }
+            StakeActivationAssertion::IsFullyActive { value, operator } => {
+                let is_fully_active =
+                    effective == stake.delegation.stake && activating == 0 && deactivating == 0;
+
+                bool::evaluate(&is_fully_active, value, operator, log_level)
+            }
+            StakeActivationAssertion::IsFullyDeactivated { value, operator } => {
+                let is_fully_deactivated = effective == 0 && activating == 0 && deactivating == 0;
+
+                bool::evaluate(&is_fully_deactivated, value, operator, log_level)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        load_new_rate_activation_epoch, load_stake_history, StakeActivationAssertion,
+        REDUCE_STAKE_WARMUP_COOLDOWN_FEATURE_ID,
+    };
+    use crate::{
+        error::LighthouseError,
+        test_utils::{assert_failed, assert_passed, create_test_stake, create_test_stake_history},
+        types::assert::{Assert, EquatableOperator, IntegerOperator, LogLevel},
+    };
+    use solana_program::{
+        account_info::AccountInfo,
+        system_program,
+        sysvar::{self, stake_history},
+    };
+
+    #[test]
+    fn evaluate_stake_activation() {
+        let stake_history = create_test_stake_history(|_| 1_000_000);
+        let stake = create_test_stake();
+
+        let assertions = vec![
+            // Activating in the activation epoch.
+            (
+                StakeActivationAssertion::ActivatingStake {
+                    value: 1_000,
+                    operator: IntegerOperator::Equal,
+                },
+                5,
+                true,
+            ),
+            (
+                StakeActivationAssertion::IsFullyActive {
+                    value: false,
+                    operator: EquatableOperator::Equal,
+                },
+                5,
+                true,
+            ),
+            (
+                StakeActivationAssertion::EffectiveStake {
+                    value: 1_000,
+                    operator: IntegerOperator::Equal,
+                },
+                8,
+                true,
+            ),
+            (
+                StakeActivationAssertion::IsFullyActive {
+                    value: true,
+                    operator: EquatableOperator::Equal,
+                },
+                8,
+                true,
+            ),
+            (
+                StakeActivationAssertion::DeactivatingStake {
+                    value: 1_000,
+                    operator: IntegerOperator::Equal,
+                },
+                10,
+                true,
+            ),
+            (
+                StakeActivationAssertion::IsFullyDeactivated {
+                    value: true,
+                    operator: EquatableOperator::Equal,
+                },
+                10,
+                false,
+            ),
+            (
+                StakeActivationAssertion::IsFullyDeactivated {
+                    value: true,
+                    operator: EquatableOperator::Equal,
+                },
+                12,
+                true,
+            ),
+        ];
+
+        for (assertion, epoch, should_pass) in assertions {
+            let result = assertion.evaluate(
+                (&stake, epoch, &stake_history, None),
+                LogLevel::PlaintextMessage,
+            );
+
+            if should_pass {
+                assert_passed(result);
+            } else {
+                assert_failed(result);
+            }
+        }
+    }
+
+    #[test]
+    fn load_partial_stake_history() {
+        let stake_history = create_test_stake_history(|epoch| epoch);
+
+        let mut data = bincode::serialize(&stake_history).unwrap();
+        let lamports = &mut 0;
+        let account = AccountInfo::new(
+            &stake_history::ID,
+            false,
+            false,
+            lamports,
+            &mut data,
+            &sysvar::ID,
+            false,
+            0,
+        );
+        let accounts = [account];
+
+        let loaded = load_stake_history(&accounts, 15).unwrap();
+        assert_eq!(loaded.len(), 5);
+        assert_eq!(loaded.get(15).unwrap().effective, 15);
+        assert_eq!(loaded.get(19).unwrap().effective, 19);
+        assert!(loaded.get(14).is_none());
+
+        assert_eq!(
+            load_stake_history(&accounts[..0], 0).unwrap_err(),
+            LighthouseError::NotEnoughAccounts.into()
+        );
+
+        // The feature account is not created until the feature is proposed.
+        let lamports = &mut 0;
+        let feature_account = AccountInfo::new(
+            &REDUCE_STAKE_WARMUP_COOLDOWN_FEATURE_ID,
+            false,
+            false,
+            lamports,
+            &mut [],
+            &system_program::ID,
+            false,
+            0,
+        );
+        assert_eq!(
+            load_new_rate_activation_epoch(&[feature_account]).unwrap(),
+            None
+        );
+        assert_eq!(
+            load_new_rate_activation_epoch(&accounts).unwrap_err(),
+            LighthouseError::NotEnoughAccounts.into()
+        );
+    }
+}
@@ -1,13 +1,18 @@
-use super::{Assert, EquatableOperator, Evaluate, IntegerOperator, LogLevel};
+use super::{
+    evaluate_ui_value, Assert, EquatableOperator, Evaluate, EvaluateSet, IntegerOperator, LogLevel,
+    SetOperator, UiScale,
+};
 use crate::{
     error::LighthouseError,
     generate_asserts_c,
-    utils::{checked_get_slice, Result},
+    types::CompactOptionPubkeys,
+    utils::{checked_get_slice, get_account, get_account_key, keys_equal, unpack_coption, Result},
 };
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+use solana_program::{account_info::AccountInfo, msg, pubkey::Pubkey};
 use spl_associated_token_account::get_associated_token_address_with_program_id;
 use spl_token_2022::state::AccountState;
+use std::slice;
 
 #[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
 pub enum TokenAccountAssertion {
@@ -45,6 +50,34 @@ pub enum TokenAccountAssertion {
         operator: EquatableOperator,
     },
     TokenAccountOwnerIsDerived,
+    DelegateIn {
+        value: CompactOptionPubkeys,
+        operator: SetOperator,
+    },
+    // Account reference variants compare against the key of the instruction account at `account_index`.
+    MintAccountRef {
+        account_index: u8,
+        operator: EquatableOperator,
+    },
+    OwnerAccountRef {
+        account_index: u8,
+        operator: EquatableOperator,
+    },
+    DelegateAccountRef {
+        account_index: u8,
+        operator: EquatableOperator,
+    },
+    CloseAuthorityAccountRef {
+        account_index: u8,
+        operator: EquatableOperator,
+    },
+    // Compares the amount in UI units, `mantissa * 10^exponent`, using the decimals of the mint
+    // passed as the second instruction account.
+    UiAmount {
+        mantissa: u64,
+        exponent: i8,
+        operator: IntegerOperator,
+    },
 }
 
 pub fn account_state_from_u8(value: u8) -> AccountState {
@@ -66,6 +99,16 @@ pub fn u8_from_account_state(state: AccountState) -> u8 {
 
 impl Assert<&AccountInfo<'_>> for TokenAccountAssertion {
     fn evaluate(&self, account: &AccountInfo<'_>, log_level: LogLevel) -> Result<()> {
+        self.evaluate((account, slice::from_ref(account)), log_level)
+    }
+}
+
+impl Assert<(&AccountInfo<'_>, &[AccountInfo<'_>])> for TokenAccountAssertion {
+    fn evaluate(
+        &self,
+        (account, accounts): (&AccountInfo<'_>, &[AccountInfo<'_>]),
+        log_level: LogLevel,
+    ) -> Result<()> {
         let data = account
             .try_borrow_data()
             .map_err(LighthouseError::failed_borrow_err)?;
@@ -82,7 +125,8 @@ impl Assert<&AccountInfo<'_>> for TokenAccountAssertion {
             (State, u8, 108),
             (IsNative, (Option<u64>), 109),
             (DelegatedAmount, u64, 121),
-            (CloseAuthority, (Option<Pubkey>), 129)
+            (CloseAuthority, (Option<Pubkey>), 129),
+            (DelegateIn, [Option<Pubkey>], 72)
             (custom, TokenAccountOwnerIsDerived,
                 {
                     let mint = bytemuck::from_bytes::<Pubkey>(checked_get_slice(&data, 0, 32)?);
@@ -97,11 +141,88 @@ impl Assert<&AccountInfo<'_>> for TokenAccountAssertion {
                         log_level,
                     )
                 }
+            ),
+            (custom, MintAccountRef { account_index, operator },
+                {
+                    let actual_value =
+                        bytemuck::from_bytes::<Pubkey>(checked_get_slice(&data, 0, 32)?);
+                    let value = get_account_key(accounts, *account_index)?;
+
+                    Pubkey::evaluate(actual_value, value, operator, log_level)
+                }
+            ),
+            (custom, OwnerAccountRef { account_index, operator },
+                {
+                    let actual_value =
+                        bytemuck::from_bytes::<Pubkey>(checked_get_slice(&data, 32, 32)?);
+                    let value = get_account_key(accounts, *account_index)?;
+
+                    Pubkey::evaluate(actual_value, value, operator, log_level)
+                }
+            ),
+            (custom, DelegateAccountRef { account_index, operator },
+                {
+                    let actual_value = unpack_coption::<Pubkey>(&data, 72)?;
+                    let value = get_account_key(accounts, *account_index)?;
+
+                    <Option<&Pubkey>>::evaluate(&actual_value, &Some(value), operator, log_level)
+                }
+            ),
+            (custom, CloseAuthorityAccountRef { account_index, operator },
+                {
+                    let actual_value = unpack_coption::<Pubkey>(&data, 129)?;
+                    let value = get_account_key(accounts, *account_index)?;
+
+                    <Option<&Pubkey>>::evaluate(&actual_value, &Some(value), operator, log_level)
+                }
+            ),
+            (custom, UiAmount { mantissa, exponent, operator },
+                {
+                    let mint = bytemuck::from_bytes::<Pubkey>(checked_get_slice(&data, 0, 32)?);
+                    let amount = u64::from_le_bytes(checked_get_slice(&data, 64, 8)?.try_into().unwrap());
+
+                    evaluate_ui_amount(
+                        amount,
+                        mint,
+                        get_account(accounts, 1)?,
+                        (*mantissa, *exponent),
+                        operator,
+                        log_level,
+                    )
+                }
             )
         )
     }
 }
 
+fn evaluate_ui_amount(
+    amount: u64,
+    token_mint: &Pubkey,
+    mint_account: &AccountInfo<'_>,
+    (mantissa, exponent): (u64, i8),
+    operator: &IntegerOperator,
+    log_level: LogLevel,
+) -> Result<()> {
+    if !keys_equal(token_mint, mint_account.key) {
+        msg!(
+            "Mint account {} does not match token account mint {}",
+            mint_account.key,
+            token_mint
+        );
+        return Err(LighthouseError::AccountKeyMismatch.into());
+    }
+
+    let (value, decimals) = UiScale::load(mint_account)?.apply(amount as i128)?;
+
+    evaluate_ui_value(
+        value,
+        decimals,
+        (mantissa as i128, exponent),
+        operator,
+        log_level,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     mod evaluate {
@@ -110,13 +231,15 @@ mod tests {
         };
         use solana_sdk::{signature::Keypair, signer::EncodableKeypair};
         use spl_associated_token_account::get_associated_token_address_with_program_id;
-        use spl_token_2022::state::{Account, AccountState};
+        use spl_token_2022::state::{Account, AccountState, Mint};
         use std::{cell::RefCell, rc::Rc};
 
         use crate::{
+            error::LighthouseError,
             test_utils::{assert_failed, assert_passed},
             types::assert::{
-                Assert, EquatableOperator, IntegerOperator, LogLevel, TokenAccountAssertion,
+                Assert, EquatableOperator, IntegerOperator, LogLevel, SetOperator,
+                TokenAccountAssertion,
             },
         };
 
@@ -351,6 +474,30 @@ mod tests {
 
             assert_passed(result);
 
+            let result = TokenAccountAssertion::DelegateIn {
+                value: vec![None, Some(delegate.encodable_pubkey())].into(),
+                operator: SetOperator::In,
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage);
+
+            assert_passed(result);
+
+            let result = TokenAccountAssertion::DelegateIn {
+                value: vec![None, Some(delegate.encodable_pubkey())].into(),
+                operator: SetOperator::NotIn,
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage);
+
+            assert_failed(result);
+
+            let result = TokenAccountAssertion::DelegateIn {
+                value: vec![None, Some(close_authority.encodable_pubkey())].into(),
+                operator: SetOperator::In,
+            }
+            .evaluate(&account_info, LogLevel::PlaintextMessage);
+
+            assert_failed(result);
+
             //
             // Assert on close_authority
             //
@@ -770,5 +917,276 @@ mod tests {
                 .evaluate(&account_info, LogLevel::PlaintextMessage),
             );
         }
+
+        #[test]
+        fn evaluate_account_references() {
+            let mint = Keypair::new();
+            let owner = Keypair::new();
+            let delegate = Keypair::new();
+
+            let serialized_token_account: &mut [u8; Account::LEN] = &mut [0u8; Account::LEN];
+            Account::pack(
+                Account {
+                    mint: mint.encodable_pubkey(),
+                    owner: owner.encodable_pubkey(),
+                    delegate: COption::Some(delegate.encodable_pubkey()),
+                    state: AccountState::Initialized,
+                    ..Account::default()
+                },
+                serialized_token_account,
+            )
+            .unwrap();
+
+            let token_account_key = Pubkey::new_unique();
+            let (owner_key, mint_key) = (owner.encodable_pubkey(), mint.encodable_pubkey());
+            let (token_lamports, owner_lamports, mint_lamports) = (&mut 0, &mut 0, &mut 0);
+            let (owner_data, mint_data): (&mut [u8], &mut [u8]) = (&mut [], &mut []);
+
+            let accounts = [
+                AccountInfo::new(
+                    &token_account_key,
+                    false,
+                    false,
+                    token_lamports,
+                    serialized_token_account,
+                    &spl_token_2022::ID,
+                    false,
+                    0,
+                ),
+                AccountInfo::new(
+                    &owner_key,
+                    true,
+                    false,
+                    owner_lamports,
+                    owner_data,
+                    &solana_program::system_program::ID,
+                    false,
+                    0,
+                ),
+                AccountInfo::new(
+                    &mint_key,
+                    false,
+                    false,
+                    mint_lamports,
+                    mint_data,
+                    &spl_token_2022::ID,
+                    false,
+                    0,
+                ),
+            ];
+
+            let assertions = vec![
+                (
+                    TokenAccountAssertion::OwnerAccountRef {
+                        account_index: 1,
+                        operator: EquatableOperator::Equal,
+                    },
+                    true,
+                ),
+                (
+                    TokenAccountAssertion::OwnerAccountRef {
+                        account_index: 2,
+                        operator: EquatableOperator::Equal,
+                    },
+                    false,
+                ),
+                (
+                    TokenAccountAssertion::MintAccountRef {
+                        account_index: 2,
+                        operator: EquatableOperator::Equal,
+                    },
+                    true,
+                ),
+                (
+                    TokenAccountAssertion::DelegateAccountRef {
+                        account_index: 1,
+                        operator: EquatableOperator::NotEqual,
+                    },
+                    true,
+                ),
+                (
+                    TokenAccountAssertion::CloseAuthorityAccountRef {
+                        account_index: 1,
+                        operator: EquatableOperator::Equal,
+                    },
+                    false,
+                ),
+            ];
+
+            for (assertion, should_pass) in assertions {
+                let result =
+                    assertion.evaluate((&accounts[0], &accounts[..]), LogLevel::PlaintextMessage);
+
+                if should_pass {
+                    assert_passed(result);
+                } else {
+                    assert_failed(result);
+                }
+            }
+
+            let result = TokenAccountAssertion::OwnerAccountRef {
+                account_index: 3,
+                operator: EquatableOperator::Equal,
+            }
+            .evaluate((&accounts[0], &accounts[..]), LogLevel::PlaintextMessage);
+
+            assert_eq!(
+                result.err().unwrap(),
+                LighthouseError::NotEnoughAccounts.into()
+            );
+
+            // Without the instruction accounts only the target account can be referenced.
+            assert_failed(
+                TokenAccountAssertion::OwnerAccountRef {
+                    account_index: 0,
+                    operator: EquatableOperator::Equal,
+                }
+                .evaluate(&accounts[0], LogLevel::PlaintextMessage),
+            );
+        }
+
+        #[test]
+        fn evaluate_ui_amount() {
+            let mint_key = Pubkey::new_unique();
+
+            let serialized_token_account: &mut [u8; Account::LEN] = &mut [0u8; Account::LEN];
+            Account::pack(
+                Account {
+                    mint: mint_key,
+                    owner: Pubkey::new_unique(),
+                    amount: 1_500_000,
+                    state: AccountState::Initialized,
+                    ..Account::default()
+                },
+                serialized_token_account,
+            )
+            .unwrap();
+
+            let serialized_mint: &mut [u8; Mint::LEN] = &mut [0u8; Mint::LEN];
+            Mint::pack(
+                Mint {
+                    decimals: 6,
+                    is_initialized: true,
+                    ..Mint::default()
+                },
+                serialized_mint,
+            )
+            .unwrap();
+
+            let token_account_key = Pubkey::new_unique();
+            let (token_lamports, mint_lamports) = (&mut 0, &mut 0);
+
+            let accounts = [
+                AccountInfo::new(
+                    &token_account_key,
+                    false,
+                    false,
+                    token_lamports,
+                    serialized_token_account,
+                    &spl_token::ID,
+                    false,
+                    0,
+                ),
+                AccountInfo::new(
+                    &mint_key,
+                    false,
+                    false,
+                    mint_lamports,
+                    serialized_mint,
+                    &spl_token::ID,
+                    false,
+                    0,
+                ),
+            ];
+
+            let assertions = vec![
+                (15, -1, IntegerOperator::Equal, true),
+                (15, -1, IntegerOperator::LessThanOrEqual, true),
+                (15, -1, IntegerOperator::LessThan, false),
+                (1_500_001, -6, IntegerOperator::LessThan, true),
+                (14_999_999, -7, IntegerOperator::GreaterThan, true),
+                (150_000_000, -8, IntegerOperator::Equal, true),
+                (149_999_999, -8, IntegerOperator::GreaterThan, true),
+                (1, 0, IntegerOperator::GreaterThan, true),
+                (2, 0, IntegerOperator::LessThan, true),
+                (1, 20, IntegerOperator::LessThan, true),
+                (
+                    0,
+                    -1,
+                    IntegerOperator::Between {
+                        lower: 14,
+                        upper: 16,
+                    },
+                    true,
+                ),
+                (
+                    0,
+                    -8,
+                    IntegerOperator::Between {
+                        lower: 150_000_001,
+                        upper: 160_000_000,
+                    },
+                    false,
+                ),
+            ];
+
+            for (mantissa, exponent, operator, should_pass) in assertions {
+                let result = TokenAccountAssertion::UiAmount {
+                    mantissa,
+                    exponent,
+                    operator,
+                }
+                .evaluate((&accounts[0], &accounts[..]), LogLevel::PlaintextMessage);
+
+                if should_pass {
+                    assert_passed(result);
+                } else {
+                    assert_failed(result);
+                }
+            }
+
+            let result = TokenAccountAssertion::UiAmount {
+                mantissa: 15,
+                exponent: -1,
+                operator: IntegerOperator::Contains,
+            }
+            .evaluate((&accounts[0], &accounts[..]), LogLevel::PlaintextMessage);
+
+            assert_eq!(
+                result.err().unwrap(),
+                LighthouseError::InvalidInstructionData.into()
+            );
+
+            // The second account must be the mint of the token account.
+            let result = TokenAccountAssertion::UiAmount {
+                mantissa: 15,
+                exponent: -1,
+                operator: IntegerOperator::Equal,
+            }
+            .evaluate((&accounts[0], &accounts[..1]), LogLevel::PlaintextMessage);
+
+            assert_eq!(
+                result.err().unwrap(),
+                LighthouseError::NotEnoughAccounts.into()
+            );
+
+            let result = TokenAccountAssertion::UiAmount {
+                mantissa: 15,
+                exponent: -1,
+                operator: IntegerOperator::Equal,
+            }
+            .evaluate(
+                (
+                    &accounts[0],
+                    &[accounts[0].clone(), accounts[0].clone()][..],
+                ),
+                LogLevel::PlaintextMessage,
+            );
+
+            assert_eq!(
+                result.err().unwrap(),
+                LighthouseError::AccountKeyMismatch.into()
+            );
+        }
     }
 }
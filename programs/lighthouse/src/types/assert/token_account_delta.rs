@@ -0,0 +1,317 @@
+use super::{
+    evaluate_ui_value, Assert, EquatableOperator, Evaluate, IntegerOperator, LogLevel, UiScale,
+};
+use crate::{
+    error::LighthouseError,
+    utils::{get_account, keys_equal, try_from_slice, unpack_coption, Result},
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{account_info::AccountInfo, msg, pubkey::Pubkey};
+
+// Compares a snapshot written by `WriteType::TokenAccountSnapshot` against the current state of
+// the token account. Pubkey fields pass `Equal` when they are unchanged.
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub enum TokenAccountDeltaAssertion {
+    Amount {
+        value: i128,
+        operator: IntegerOperator,
+    },
+    DelegatedAmount {
+        value: i128,
+        operator: IntegerOperator,
+    },
+    Owner {
+        operator: EquatableOperator,
+    },
+    Delegate {
+        operator: EquatableOperator,
+    },
+    CloseAuthority {
+        operator: EquatableOperator,
+    },
+    // Change of the amount in UI units, `mantissa * 10^exponent`, using the decimals and interest
+    // of the mint passed as the third instruction account.
+    UiAmount {
+        mantissa: i64,
+        exponent: i8,
+        operator: IntegerOperator,
+    },
+}
+
+impl Assert<(&[u8], &AccountInfo<'_>)> for TokenAccountDeltaAssertion {
+    fn evaluate(
+        &self,
+        (snapshot, token_account): (&[u8], &AccountInfo<'_>),
+        log_level: LogLevel,
+    ) -> Result<()> {
+        self.evaluate((snapshot, token_account, &[][..]), log_level)
+    }
+}
+
+impl Assert<(&[u8], &AccountInfo<'_>, &[AccountInfo<'_>])> for TokenAccountDeltaAssertion {
+    fn evaluate(
+        &self,
+        (snapshot, token_account, accounts): (&[u8], &AccountInfo<'_>, &[AccountInfo<'_>]),
+        log_level: LogLevel,
+    ) -> Result<()> {
+        let data = token_account
+            .try_borrow_data()
+            .map_err(LighthouseError::failed_borrow_err)?;
+
+        match self {
+            TokenAccountDeltaAssertion::Amount { value, operator } => {
+                let a_amount = try_from_slice::<u64>(snapshot, 64)?;
+                let b_amount = try_from_slice::<u64>(&data, 64)?;
+                let diff_value = b_amount as i128 - a_amount as i128;
+
+                i128::evaluate(&diff_value, value, operator, log_level)
+            }
+            TokenAccountDeltaAssertion::DelegatedAmount { value, operator } => {
+                let a_amount = try_from_slice::<u64>(snapshot, 121)?;
+                let b_amount = try_from_slice::<u64>(&data, 121)?;
+                let diff_value = b_amount as i128 - a_amount as i128;
+
+                i128::evaluate(&diff_value, value, operator, log_level)
+            }
+            TokenAccountDeltaAssertion::Owner { operator } => {
+                let a_owner = try_from_slice::<Pubkey>(snapshot, 32)?;
+                let b_owner = try_from_slice::<Pubkey>(&data, 32)?;
+
+                Pubkey::evaluate(&a_owner, &b_owner, operator, log_level)
+            }
+            TokenAccountDeltaAssertion::Delegate { operator } => {
+                let a_delegate = unpack_coption::<Pubkey>(snapshot, 72)?;
+                let b_delegate = unpack_coption::<Pubkey>(&data, 72)?;
+
+                <Option<&Pubkey>>::evaluate(&a_delegate, &b_delegate, operator, log_level)
+            }
+            TokenAccountDeltaAssertion::CloseAuthority { operator } => {
+                let a_close_authority = unpack_coption::<Pubkey>(snapshot, 129)?;
+                let b_close_authority = unpack_coption::<Pubkey>(&data, 129)?;
+
+                <Option<&Pubkey>>::evaluate(
+                    &a_close_authority,
+                    &b_close_authority,
+                    operator,
+                    log_level,
+                )
+            }
+            TokenAccountDeltaAssertion::UiAmount {
+                mantissa,
+                exponent,
+                operator,
+            } => {
+                let token_mint = try_from_slice::<Pubkey>(&data, 0)?;
+                let mint_account = get_account(accounts, 2)?;
+                if !keys_equal(&token_mint, mint_account.key) {
+                    msg!(
+                        "Mint account {} does not match token account mint {}",
+                        mint_account.key,
+                        token_mint
+                    );
+                    return Err(LighthouseError::AccountKeyMismatch.into());
+                }
+
+                let a_amount = try_from_slice::<u64>(snapshot, 64)?;
+                let b_amount = try_from_slice::<u64>(&data, 64)?;
+                let (diff_value, decimals) =
+                    UiScale::load(mint_account)?.apply(b_amount as i128 - a_amount as i128)?;
+
+                evaluate_ui_value(
+                    diff_value,
+                    decimals,
+                    (*mantissa as i128, *exponent),
+                    operator,
+                    log_level,
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::LighthouseError,
+        test_utils::{assert_failed, assert_passed},
+        types::assert::{
+            Assert, EquatableOperator, IntegerOperator, LogLevel, TokenAccountDeltaAssertion,
+        },
+    };
+    use solana_program::{account_info::AccountInfo, program_option::COption, program_pack::Pack};
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::EncodableKeypair};
+    use spl_token::state::{Account, AccountState, Mint};
+
+    #[test]
+    fn evaluate_token_account_delta() {
+        let owner = Keypair::new().encodable_pubkey();
+        let delegate = Keypair::new().encodable_pubkey();
+
+        let token_account = |amount: u64, delegate: COption<Pubkey>| {
+            let mut data = [0u8; Account::LEN];
+            Account {
+                mint: Pubkey::default(),
+                owner,
+                amount,
+                delegate,
+                state: AccountState::Initialized,
+                is_native: COption::None,
+                delegated_amount: 0,
+                close_authority: COption::None,
+            }
+            .pack_into_slice(&mut data);
+            data
+        };
+
+        let snapshot = token_account(100, COption::None);
+        let mut data = token_account(60, COption::Some(delegate));
+
+        let key = Pubkey::default();
+        let lamports = &mut 0;
+        let account_info = solana_program::account_info::AccountInfo::new(
+            &key,
+            false,
+            false,
+            lamports,
+            &mut data,
+            &spl_token::ID,
+            false,
+            0,
+        );
+
+        let evaluate = |assertion: TokenAccountDeltaAssertion| {
+            assertion.evaluate((&snapshot[..], &account_info), LogLevel::PlaintextMessage)
+        };
+
+        assert_passed(evaluate(TokenAccountDeltaAssertion::Amount {
+            value: -40,
+            operator: IntegerOperator::Equal,
+        }));
+        assert_passed(evaluate(TokenAccountDeltaAssertion::Amount {
+            value: -50,
+            operator: IntegerOperator::GreaterThanOrEqual,
+        }));
+        assert_failed(evaluate(TokenAccountDeltaAssertion::Amount {
+            value: -30,
+            operator: IntegerOperator::GreaterThanOrEqual,
+        }));
+        assert_passed(evaluate(TokenAccountDeltaAssertion::Owner {
+            operator: EquatableOperator::Equal,
+        }));
+        assert_passed(evaluate(TokenAccountDeltaAssertion::CloseAuthority {
+            operator: EquatableOperator::Equal,
+        }));
+        assert_failed(evaluate(TokenAccountDeltaAssertion::Delegate {
+            operator: EquatableOperator::Equal,
+        }));
+        assert_passed(evaluate(TokenAccountDeltaAssertion::Delegate {
+            operator: EquatableOperator::NotEqual,
+        }));
+    }
+
+    #[test]
+    fn evaluate_ui_amount_delta() {
+        let mint_key = Pubkey::new_unique();
+
+        let token_account = |amount: u64| {
+            let mut data = [0u8; Account::LEN];
+            Account {
+                mint: mint_key,
+                owner: Pubkey::new_unique(),
+                amount,
+                state: AccountState::Initialized,
+                ..Account::default()
+            }
+            .pack_into_slice(&mut data);
+            data
+        };
+
+        let snapshot = token_account(1_250);
+        let mut data = token_account(1_000);
+        let mut mint_data = [0u8; Mint::LEN];
+        Mint {
+            decimals: 2,
+            is_initialized: true,
+            ..Mint::default()
+        }
+        .pack_into_slice(&mut mint_data);
+
+        let key = Pubkey::new_unique();
+        let (lamports, mint_lamports) = (&mut 0, &mut 0);
+        let accounts = [
+            AccountInfo::new(
+                &key,
+                false,
+                false,
+                lamports,
+                &mut data,
+                &spl_token::ID,
+                false,
+                0,
+            ),
+            AccountInfo::new(
+                &mint_key,
+                false,
+                false,
+                mint_lamports,
+                &mut mint_data,
+                &spl_token::ID,
+                false,
+                0,
+            ),
+        ];
+        let accounts = [
+            accounts[0].clone(),
+            accounts[0].clone(),
+            accounts[1].clone(),
+        ];
+
+        let evaluate = |mantissa, exponent, operator, accounts: &[AccountInfo]| {
+            TokenAccountDeltaAssertion::UiAmount {
+                mantissa,
+                exponent,
+                operator,
+            }
+            .evaluate(
+                (&snapshot[..], &accounts[1], accounts),
+                LogLevel::PlaintextMessage,
+            )
+        };
+
+        assert_passed(evaluate(-25, -1, IntegerOperator::Equal, &accounts));
+        assert_passed(evaluate(-2, 0, IntegerOperator::LessThan, &accounts));
+        assert_failed(evaluate(-3, 0, IntegerOperator::LessThan, &accounts));
+        assert_passed(evaluate(
+            0,
+            -1,
+            IntegerOperator::Between {
+                lower: -30,
+                upper: -20,
+            },
+            &accounts,
+        ));
+
+        // The third account must be the mint of the token account.
+        assert_eq!(
+            evaluate(-25, -1, IntegerOperator::Equal, &accounts[..2])
+                .err()
+                .unwrap(),
+            LighthouseError::NotEnoughAccounts.into()
+        );
+        assert_eq!(
+            evaluate(
+                -25,
+                -1,
+                IntegerOperator::Equal,
+                &[
+                    accounts[0].clone(),
+                    accounts[0].clone(),
+                    accounts[0].clone()
+                ]
+            )
+            .err()
+            .unwrap(),
+            LighthouseError::AccountKeyMismatch.into()
+        );
+    }
+}
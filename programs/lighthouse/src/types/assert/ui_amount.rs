@@ -0,0 +1,247 @@
+use super::{Evaluate, IntegerOperator, LogLevel, Operator};
+use crate::{
+    error::LighthouseError,
+    utils::{checked_get_slice, keys_equal, Result},
+};
+use solana_program::{account_info::AccountInfo, clock::Clock, msg, sysvar::Sysvar};
+use spl_token_2022::{
+    extension::{
+        interest_bearing_mint::InterestBearingConfig, BaseStateWithExtensions, StateWithExtensions,
+    },
+    state::Mint,
+};
+
+// Amounts of interest bearing mints carry this many extra decimals.
+pub const INTEREST_DECIMALS: u32 = 12;
+
+const ONE: i128 = 10i128.pow(INTEREST_DECIMALS);
+// The factor is computed with six guard digits.
+const WIDE_ONE: i128 = ONE * 1_000_000;
+const E: i128 = 2_718_281_828_459_045_235;
+const SECONDS_PER_YEAR: i128 = 60 * 60 * 24 * 36_524 / 100;
+const ONE_IN_BASIS_POINTS: i128 = 10_000;
+
+// Decimals of a mint and, for interest bearing mints, the factor amounts have grown by as a
+// fixed-point number with `INTEREST_DECIMALS` decimals.
+pub(crate) struct UiScale {
+    decimals: u32,
+    interest_factor: Option<i128>,
+}
+
+impl UiScale {
+    pub(crate) fn load(mint_account: &AccountInfo<'_>) -> Result<Self> {
+        let mint_data = mint_account
+            .try_borrow_data()
+            .map_err(LighthouseError::failed_borrow_err)?;
+        let decimals = *checked_get_slice(&mint_data, 44, 1)?.first().unwrap() as u32;
+
+        let mut interest_factor = None;
+        if keys_equal(mint_account.owner, &spl_token_2022::ID) {
+            let mint = StateWithExtensions::<Mint>::unpack(&mint_data)
+                .map_err(|_| LighthouseError::FailedToDeserialize)?;
+
+            if let Ok(config) = mint.get_extension::<InterestBearingConfig>() {
+                interest_factor = Some(compute_interest_factor(
+                    config,
+                    Clock::get()?.unix_timestamp,
+                )?);
+            }
+        }
+
+        Ok(Self {
+            decimals,
+            interest_factor,
+        })
+    }
+
+    // Returns `amount` with interest and the number of decimals of the result.
+    pub(crate) fn apply(&self, amount: i128) -> Result<(i128, u32)> {
+        match self.interest_factor {
+            Some(factor) => {
+                let amount = amount.checked_mul(factor).ok_or_else(|| {
+                    msg!("Amount with interest overflows");
+                    LighthouseError::ArithmeticOverflow
+                })?;
+
+                Ok((amount, self.decimals + INTEREST_DECIMALS))
+            }
+            None => Ok((amount, self.decimals)),
+        }
+    }
+}
+
+// Token-2022 grows amounts by e^(rate * time), from the average rate before the last rate update
+// and the current rate after it, in f64. The same factor is computed here in fixed-point.
+pub(crate) fn compute_interest_factor(
+    config: &InterestBearingConfig,
+    unix_timestamp: i64,
+) -> Result<i128> {
+    let initialization_timestamp = i64::from(config.initialization_timestamp) as i128;
+    let last_update_timestamp = i64::from(config.last_update_timestamp) as i128;
+
+    let pre_update = i16::from(config.pre_update_average_rate) as i128
+        * (last_update_timestamp - initialization_timestamp);
+    let post_update =
+        i16::from(config.current_rate) as i128 * (unix_timestamp as i128 - last_update_timestamp);
+
+    exp(
+        pre_update + post_update,
+        SECONDS_PER_YEAR * ONE_IN_BASIS_POINTS,
+    )
+}
+
+// e^(numerator / denominator) with `INTEREST_DECIMALS` decimals, rounded down.
+fn exp(numerator: i128, denominator: i128) -> Result<i128> {
+    // Negative exponents too large to compute round down to zero.
+    if numerator < 0 {
+        return Ok(exp(-numerator, denominator).map_or(0, |factor| ONE * ONE / factor));
+    }
+
+    let whole = numerator / denominator;
+    let fraction = numerator % denominator * WIDE_ONE / denominator;
+
+    // Taylor series of e^fraction, fraction is below one so the terms vanish quickly.
+    let (mut sum, mut term, mut n) = (WIDE_ONE, WIDE_ONE, 1);
+    while term > 0 {
+        term = term * fraction / WIDE_ONE / n;
+        sum += term;
+        n += 1;
+    }
+
+    let mut factor = sum;
+    for _ in 0..whole {
+        factor = mul_wide(factor, E).ok_or_else(|| {
+            msg!("Interest factor overflows");
+            LighthouseError::ArithmeticOverflow
+        })?;
+    }
+
+    Ok(factor / (WIDE_ONE / ONE))
+}
+
+// `a * b` of two fixed-point numbers, `b` must be below 10 so the low half can't overflow.
+fn mul_wide(a: i128, b: i128) -> Option<i128> {
+    (a / WIDE_ONE)
+        .checked_mul(b)?
+        .checked_add(a % WIDE_ONE * b / WIDE_ONE)
+}
+
+// Compares `value * 10^-decimals` against `mantissa * 10^exponent` exactly, both sides are scaled
+// to the smaller exponent. Overflow saturates, which preserves ordering as the other side is never
+// scaled.
+pub(crate) fn evaluate_ui_value(
+    value: i128,
+    decimals: u32,
+    (mantissa, exponent): (i128, i8),
+    operator: &IntegerOperator,
+    log_level: LogLevel,
+) -> Result<()> {
+    if matches!(
+        operator,
+        IntegerOperator::Contains | IntegerOperator::DoesNotContain
+    ) {
+        msg!(
+            "Operator {} is not supported for UI amounts",
+            operator.format()
+        );
+        return Err(LighthouseError::InvalidInstructionData.into());
+    }
+
+    let shift = exponent as i32 + decimals as i32;
+    let scale = 10i128
+        .checked_pow(shift.unsigned_abs())
+        .unwrap_or(i128::MAX);
+    let (actual_value, assertion_value, operator) = if shift >= 0 {
+        (
+            value,
+            mantissa.saturating_mul(scale),
+            operator.map_bounds(|bound| bound.saturating_mul(scale)),
+        )
+    } else {
+        (value.saturating_mul(scale), mantissa, *operator)
+    };
+
+    i128::evaluate(&actual_value, &assertion_value, &operator, log_level)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compute_interest_factor, evaluate_ui_value, ONE};
+    use crate::{
+        test_utils::{assert_failed, assert_passed},
+        types::assert::{IntegerOperator, LogLevel},
+    };
+    use spl_token_2022::extension::interest_bearing_mint::InterestBearingConfig;
+
+    #[test]
+    fn interest_factor() {
+        const YEAR: i64 = 31_556_736;
+
+        let config = |rate: i16| InterestBearingConfig {
+            initialization_timestamp: 0.into(),
+            pre_update_average_rate: rate.into(),
+            last_update_timestamp: YEAR.into(),
+            current_rate: rate.into(),
+            ..InterestBearingConfig::default()
+        };
+
+        // e^0.05, e^0.1, e^-0.05 and e^3.2767
+        let cases = [
+            (500, YEAR, 1_051_271_096_376),
+            (500, 2 * YEAR, 1_105_170_918_075),
+            (-500, YEAR, 951_229_424_500),
+            (32_767, YEAR, 26_488_217_196_002),
+            (500, 0, ONE),
+        ];
+
+        for (rate, timestamp, expected) in cases {
+            let factor = compute_interest_factor(&config(rate), timestamp).unwrap();
+            assert!(
+                (factor - expected).abs() <= 2,
+                "rate {} at {}: {} != {}",
+                rate,
+                timestamp,
+                factor,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn evaluate_ui_values() {
+        let evaluate = |value, decimals, mantissa, exponent, operator| {
+            evaluate_ui_value(
+                value,
+                decimals,
+                (mantissa, exponent),
+                &operator,
+                LogLevel::PlaintextMessage,
+            )
+        };
+
+        assert_passed(evaluate(-1_500, 3, -15, -1, IntegerOperator::Equal));
+        assert_passed(evaluate(-1_500, 3, -1, 0, IntegerOperator::LessThan));
+        assert_failed(evaluate(-1_500, 3, -2, 0, IntegerOperator::LessThan));
+        assert_passed(evaluate(
+            1_051_271_096_376,
+            12,
+            0,
+            -2,
+            IntegerOperator::Between {
+                lower: 105,
+                upper: 106,
+            },
+        ));
+        assert_failed(evaluate(
+            1_051_271_096_376,
+            12,
+            0,
+            -2,
+            IntegerOperator::Between {
+                lower: 106,
+                upper: 107,
+            },
+        ));
+        assert_passed(evaluate(1, 60, 1, 0, IntegerOperator::LessThan));
+    }
+}
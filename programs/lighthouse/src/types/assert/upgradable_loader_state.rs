@@ -1,7 +1,12 @@
 use super::{Assert, EquatableOperator, IntegerOperator, LogLevel};
-use crate::{error::LighthouseError, types::assert::evaluate::Evaluate, utils::Result};
+use crate::{
+    error::LighthouseError,
+    types::assert::evaluate::Evaluate,
+    utils::{checked_get_slice, Result},
+};
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::{bpf_loader_upgradeable::UpgradeableLoaderState, msg, pubkey::Pubkey};
+use lighthouse_common::CompactU64;
+use solana_program::{bpf_loader_upgradeable::UpgradeableLoaderState, keccak, msg, pubkey::Pubkey};
 
 #[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Copy)]
 #[repr(u8)]
@@ -25,8 +30,12 @@ pub enum UpgradeableLoaderStateAssertion {
     ProgramData(UpgradeableProgramDataAssertion),
 }
 
-impl Assert<&UpgradeableLoaderState> for UpgradeableLoaderStateAssertion {
-    fn evaluate(&self, state: &UpgradeableLoaderState, log_level: LogLevel) -> Result<()> {
+impl Assert<(&UpgradeableLoaderState, &[u8])> for UpgradeableLoaderStateAssertion {
+    fn evaluate(
+        &self,
+        (state, data): (&UpgradeableLoaderState, &[u8]),
+        log_level: LogLevel,
+    ) -> Result<()> {
         match &self {
             UpgradeableLoaderStateAssertion::State {
                 value: assertion_value,
@@ -53,7 +62,7 @@ impl Assert<&UpgradeableLoaderState> for UpgradeableLoaderStateAssertion {
                 assertion.evaluate(state, log_level)
             }
             UpgradeableLoaderStateAssertion::ProgramData(assertion) => {
-                assertion.evaluate(state, log_level)
+                assertion.evaluate((state, data), log_level)
             }
         }
     }
@@ -140,12 +149,24 @@ pub enum UpgradeableProgramDataAssertion {
         value: u64,
         operator: IntegerOperator,
     },
+    /// Bytecode hash chain of the deployed ELF over `length` bytes from `offset`, or to the end of
+    /// the ELF if no length is given. See `hash_bytecode` for how the chain is computed.
+    ///
+    /// `previous_hash` is the chain hash at `offset` and must be given for every offset but 0, so
+    /// a large program is pinned to its whole-ELF digest by splitting it across instructions that
+    /// each continue from the hash the previous one asserted.
+    BytecodeHash {
+        expected_hash: [u8; 32],
+        previous_hash: Option<[u8; 32]>,
+        offset: CompactU64,
+        length: Option<CompactU64>,
+    },
 }
 
-impl Assert<&UpgradeableLoaderState> for UpgradeableProgramDataAssertion {
+impl Assert<(&UpgradeableLoaderState, &[u8])> for UpgradeableProgramDataAssertion {
     fn evaluate(
         &self,
-        upgradable_loader_state: &UpgradeableLoaderState,
+        (upgradable_loader_state, data): (&UpgradeableLoaderState, &[u8]),
         log_level: LogLevel,
     ) -> Result<()> {
         match &upgradable_loader_state {
@@ -166,6 +187,48 @@ impl Assert<&UpgradeableLoaderState> for UpgradeableProgramDataAssertion {
                     value: assertion_value,
                     operator,
                 } => u64::evaluate(slot, assertion_value, operator, log_level),
+                UpgradeableProgramDataAssertion::BytecodeHash {
+                    expected_hash,
+                    previous_hash,
+                    offset,
+                    length,
+                } => {
+                    let elf = get_program_data_elf(data)?;
+                    let offset = **offset as usize;
+                    let length = match length {
+                        Some(length) => **length as usize,
+                        None => elf.len().saturating_sub(offset),
+                    };
+                    let bytes = checked_get_slice(elf, offset, length)?;
+
+                    // The chunk size is a power of two.
+                    let chunk_mask = BYTECODE_HASH_CHUNK_SIZE - 1;
+                    let ends_elf = offset + length == elf.len();
+                    if offset & chunk_mask != 0 || (length & chunk_mask != 0 && !ends_elf) {
+                        msg!(
+                            "Bytecode hash range must be aligned to {} byte chunks",
+                            BYTECODE_HASH_CHUNK_SIZE
+                        );
+                        return Err(LighthouseError::InvalidInstructionData.into());
+                    }
+
+                    let previous_hash = match (previous_hash, offset) {
+                        (None, 0) => hash_bytecode_length(elf.len()),
+                        (Some(previous_hash), offset) if offset > 0 => *previous_hash,
+                        _ => {
+                            msg!("Previous hash must be given for every offset but 0");
+                            return Err(LighthouseError::InvalidInstructionData.into());
+                        }
+                    };
+                    let actual_hash = hash_bytecode(previous_hash, bytes);
+
+                    <[u8]>::evaluate(
+                        &actual_hash,
+                        expected_hash,
+                        &EquatableOperator::Equal,
+                        log_level,
+                    )
+                }
             },
             _ => {
                 msg!(
@@ -178,6 +241,25 @@ impl Assert<&UpgradeableLoaderState> for UpgradeableProgramDataAssertion {
     }
 }
 
+/// Bytes hashed per link of the bytecode hash chain.
+pub const BYTECODE_HASH_CHUNK_SIZE: usize = 16 * 1024;
+
+/// Seed of the bytecode hash chain, `keccak(elf_length as u64 LE)`.
+pub fn hash_bytecode_length(elf_length: usize) -> [u8; 32] {
+    keccak::hashv(&[&(elf_length as u64).to_le_bytes()]).0
+}
+
+/// Extends the bytecode hash chain from `previous_hash` over `bytes`, one link
+/// `keccak(previous_hash || chunk)` per `BYTECODE_HASH_CHUNK_SIZE` chunk. The whole-ELF digest is
+/// the chain over the entire ELF starting from `hash_bytecode_length`.
+pub fn hash_bytecode(previous_hash: [u8; 32], bytes: &[u8]) -> [u8; 32] {
+    bytes
+        .chunks(BYTECODE_HASH_CHUNK_SIZE)
+        .fold(previous_hash, |hash, chunk| {
+            keccak::hashv(&[&hash, chunk]).0
+        })
+}
+
 pub fn get_state_enum(state: &UpgradeableLoaderState) -> u8 {
     match state {
         UpgradeableLoaderState::Uninitialized => 0,
@@ -186,3 +268,230 @@ pub fn get_state_enum(state: &UpgradeableLoaderState) -> u8 {
         UpgradeableLoaderState::ProgramData { .. } => 3,
     }
 }
+
+/// ELF bytes of a program data account, trimmed of the zero padding left by program extensions.
+pub fn get_program_data_elf(data: &[u8]) -> Result<&[u8]> {
+    let elf = data
+        .get(UpgradeableLoaderState::size_of_programdata_metadata()..)
+        .ok_or(LighthouseError::FailedToDeserialize)?;
+    let elf_length = get_elf_length(elf).ok_or_else(|| {
+        msg!("Failed to parse ELF header");
+        LighthouseError::FailedToDeserialize
+    })?;
+
+    elf.get(..elf_length)
+        .ok_or_else(|| LighthouseError::oob_err(0..elf_length))
+}
+
+/// Length of a 64-bit little-endian ELF, the furthest extent of its header tables and sections.
+fn get_elf_length(elf: &[u8]) -> Option<usize> {
+    const SHT_NOBITS: u32 = 8;
+
+    let read = |offset: usize, length: usize| elf.get(offset..offset.checked_add(length)?);
+    let read_u16 = |offset: usize| -> Option<usize> {
+        Some(u16::from_le_bytes(read(offset, 2)?.try_into().ok()?) as usize)
+    };
+    let read_u32 = |offset: usize| -> Option<u32> {
+        Some(u32::from_le_bytes(read(offset, 4)?.try_into().ok()?))
+    };
+    let read_u64 = |offset: usize| -> Option<usize> {
+        usize::try_from(u64::from_le_bytes(read(offset, 8)?.try_into().ok()?)).ok()
+    };
+
+    if elf.get(0..6)? != b"\x7fELF\x02\x01" {
+        return None;
+    }
+
+    let table_end = |offset: usize, count: usize, size: usize| -> Option<usize> {
+        offset.checked_add(count.checked_mul(size)?)
+    };
+
+    let (ph_offset, ph_size, ph_count) = (read_u64(0x20)?, read_u16(0x36)?, read_u16(0x38)?);
+    let (sh_offset, sh_size, sh_count) = (read_u64(0x28)?, read_u16(0x3A)?, read_u16(0x3C)?);
+
+    let mut end = read_u16(0x34)?
+        .max(table_end(ph_offset, ph_count, ph_size)?)
+        .max(table_end(sh_offset, sh_count, sh_size)?);
+
+    for i in 0..sh_count {
+        let header = table_end(sh_offset, i, sh_size)?;
+
+        if read_u32(header.checked_add(4)?)? != SHT_NOBITS {
+            let section_offset = read_u64(header.checked_add(24)?)?;
+            let section_size = read_u64(header.checked_add(32)?)?;
+
+            end = end.max(section_offset.checked_add(section_size)?);
+        }
+    }
+
+    Some(end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        get_program_data_elf, hash_bytecode, hash_bytecode_length, UpgradeableProgramDataAssertion,
+        BYTECODE_HASH_CHUNK_SIZE,
+    };
+    use crate::{
+        error::LighthouseError,
+        test_utils::{assert_failed, assert_passed},
+        types::assert::{Assert, LogLevel},
+    };
+    use solana_program::{bpf_loader_upgradeable::UpgradeableLoaderState, keccak};
+
+    // 64 byte header, `section_length` bytes of section data then a null and a progbits section
+    // header.
+    fn create_elf(section_length: usize) -> Vec<u8> {
+        let mut elf = vec![0u8; 64];
+        elf[0..6].copy_from_slice(b"\x7fELF\x02\x01");
+        elf[0x28..0x30].copy_from_slice(&(64 + section_length as u64).to_le_bytes());
+        elf[0x34..0x36].copy_from_slice(&64u16.to_le_bytes());
+        elf[0x3A..0x3C].copy_from_slice(&64u16.to_le_bytes());
+        elf[0x3C..0x3E].copy_from_slice(&2u16.to_le_bytes());
+        elf.extend((0..section_length).map(|i| i as u8));
+        elf.extend([0u8; 64]);
+
+        let mut section_header = [0u8; 64];
+        section_header[4..8].copy_from_slice(&1u32.to_le_bytes());
+        section_header[24..32].copy_from_slice(&64u64.to_le_bytes());
+        section_header[32..40].copy_from_slice(&(section_length as u64).to_le_bytes());
+        elf.extend(section_header);
+
+        elf
+    }
+
+    fn create_program_data(elf: &[u8]) -> Vec<u8> {
+        let mut data = vec![0u8; UpgradeableLoaderState::size_of_programdata_metadata()];
+        data.extend(elf);
+        // Trailing padding from an extended program is not part of the ELF.
+        data.extend([0u8; 100]);
+
+        data
+    }
+
+    #[test]
+    fn bytecode_hash() {
+        let elf = create_elf(16);
+        let data = create_program_data(&elf);
+        let state = UpgradeableLoaderState::ProgramData {
+            slot: 0,
+            upgrade_authority_address: None,
+        };
+
+        assert_eq!(get_program_data_elf(&data).unwrap(), elf.as_slice());
+
+        let seed = keccak::hashv(&[&208u64.to_le_bytes()]).0;
+        let full_hash = keccak::hashv(&[&seed, &elf]).0;
+        assert_eq!(
+            hash_bytecode(hash_bytecode_length(elf.len()), &elf),
+            full_hash
+        );
+
+        let assertions = vec![
+            (full_hash, 0u8, None, true),
+            (full_hash, 0u8, Some(208u8), true),
+            (keccak::hashv(&[&elf]).0, 0u8, None, false),
+        ];
+
+        for (expected_hash, offset, length, should_pass) in assertions {
+            let result = UpgradeableProgramDataAssertion::BytecodeHash {
+                expected_hash,
+                previous_hash: None,
+                offset: offset.into(),
+                length: length.map(Into::into),
+            }
+            .evaluate((&state, &data), LogLevel::PlaintextMessage);
+
+            if should_pass {
+                assert_passed(result);
+            } else {
+                assert_failed(result);
+            }
+        }
+
+        let result = UpgradeableProgramDataAssertion::BytecodeHash {
+            expected_hash: full_hash,
+            previous_hash: None,
+            offset: 0u8.into(),
+            length: Some(209u8.into()),
+        }
+        .evaluate((&state, &data), LogLevel::PlaintextMessage);
+
+        assert_eq!(
+            result.err().unwrap(),
+            LighthouseError::RangeOutOfBounds.into()
+        );
+    }
+
+    #[test]
+    fn chained_bytecode_hash() {
+        let elf = create_elf(2 * BYTECODE_HASH_CHUNK_SIZE);
+        let data = create_program_data(&elf);
+        let state = UpgradeableLoaderState::ProgramData {
+            slot: 0,
+            upgrade_authority_address: None,
+        };
+
+        let chunk = BYTECODE_HASH_CHUNK_SIZE as u64;
+        let seed = hash_bytecode_length(elf.len());
+        let first_hash = hash_bytecode(seed, &elf[..BYTECODE_HASH_CHUNK_SIZE]);
+        let full_hash = hash_bytecode(seed, &elf);
+
+        let assertions = vec![
+            // The whole ELF in one instruction and split across two give the same digest.
+            (full_hash, None, 0, None, Ok(())),
+            (first_hash, None, 0, Some(chunk), Ok(())),
+            (full_hash, Some(first_hash), chunk, None, Ok(())),
+            (
+                full_hash,
+                Some(seed),
+                chunk,
+                None,
+                Err(LighthouseError::AssertionFailed),
+            ),
+            // Segments must start on a chunk boundary and end on one or at the end of the ELF.
+            (
+                first_hash,
+                None,
+                0,
+                Some(chunk - 1),
+                Err(LighthouseError::InvalidInstructionData),
+            ),
+            (
+                full_hash,
+                Some(first_hash),
+                chunk + 1,
+                None,
+                Err(LighthouseError::InvalidInstructionData),
+            ),
+            // Only a chain starting at offset 0 is seeded on chain.
+            (
+                full_hash,
+                None,
+                chunk,
+                None,
+                Err(LighthouseError::InvalidInstructionData),
+            ),
+            (
+                full_hash,
+                Some(seed),
+                0,
+                None,
+                Err(LighthouseError::InvalidInstructionData),
+            ),
+        ];
+
+        for (expected_hash, previous_hash, offset, length, expected_result) in assertions {
+            let result = UpgradeableProgramDataAssertion::BytecodeHash {
+                expected_hash,
+                previous_hash,
+                offset: offset.into(),
+                length: length.map(Into::into),
+            }
+            .evaluate((&state, &data), LogLevel::PlaintextMessage);
+
+            assert_eq!(result, expected_result.map_err(Into::into));
+        }
+    }
+}
@@ -1,6 +1,15 @@
+use assert::{KnownProgram, OffsetStep, PdaSeed};
 use lighthouse_common::LEB128Vec;
+use solana_program::pubkey::Pubkey;
+use write::DataRange;
 
 pub mod assert;
 pub mod write;
 
 pub type CompactBytes = LEB128Vec<u8>;
+pub type CompactPubkeys = LEB128Vec<Pubkey>;
+pub type CompactOptionPubkeys = LEB128Vec<Option<Pubkey>>;
+pub type CompactKnownPrograms = LEB128Vec<KnownProgram>;
+pub type CompactPdaSeeds = LEB128Vec<PdaSeed>;
+pub type CompactDataRanges = LEB128Vec<DataRange>;
+pub type OffsetSteps = LEB128Vec<OffsetStep>;
@@ -0,0 +1,112 @@
+use crate::error::LighthouseError;
+use crate::utils::{checked_get_slice, Result};
+use borsh::{BorshDeserialize, BorshSerialize};
+use lighthouse_common::CompactU64;
+use solana_program::{account_info::AccountInfo, hash, keccak};
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub enum HashAlgorithm {
+    Keccak256,
+    Sha256,
+}
+
+impl HashAlgorithm {
+    pub fn hashv(&self, values: &[&[u8]]) -> [u8; 32] {
+        match self {
+            HashAlgorithm::Keccak256 => keccak::hashv(values).0,
+            HashAlgorithm::Sha256 => hash::hashv(values).to_bytes(),
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub enum AccountHashScope {
+    // Hashes the owner, the little-endian lamports and the data of the account.
+    Account,
+    DataRange {
+        offset: CompactU64,
+        length: CompactU64,
+    },
+}
+
+impl AccountHashScope {
+    pub fn hash(&self, account: &AccountInfo, algorithm: &HashAlgorithm) -> Result<[u8; 32]> {
+        let data = account
+            .try_borrow_data()
+            .map_err(LighthouseError::failed_borrow_err)?;
+
+        match self {
+            AccountHashScope::Account => Ok(algorithm.hashv(&[
+                account.owner.as_ref(),
+                &account.lamports().to_le_bytes(),
+                &data,
+            ])),
+            AccountHashScope::DataRange { offset, length } => {
+                let data_slice = checked_get_slice(&data, **offset as usize, **length as usize)?;
+
+                Ok(algorithm.hashv(&[data_slice]))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AccountHashScope, HashAlgorithm};
+    use crate::error::LighthouseError;
+    use lighthouse_common::CompactU64;
+    use solana_program::{account_info::AccountInfo, hash, keccak, pubkey::Pubkey};
+
+    #[test]
+    fn hash_account() {
+        let key = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let lamports = &mut 1_000;
+        let data: &mut [u8] = &mut [1, 2, 3, 4, 5];
+
+        let account = AccountInfo::new(&key, false, false, lamports, data, &owner, false, 0);
+
+        let expected_hash =
+            keccak::hashv(&[owner.as_ref(), &1_000u64.to_le_bytes(), &[1, 2, 3, 4, 5]]).0;
+        assert_eq!(
+            AccountHashScope::Account
+                .hash(&account, &HashAlgorithm::Keccak256)
+                .unwrap(),
+            expected_hash
+        );
+
+        let range = AccountHashScope::DataRange {
+            offset: CompactU64(1),
+            length: CompactU64(3),
+        };
+        assert_eq!(
+            range.hash(&account, &HashAlgorithm::Sha256).unwrap(),
+            hash::hashv(&[&[2, 3, 4]]).to_bytes()
+        );
+        assert_eq!(
+            range.hash(&account, &HashAlgorithm::Keccak256).unwrap(),
+            keccak::hashv(&[&[2, 3, 4]]).0
+        );
+
+        // Lamports are part of the account hash.
+        **account.try_borrow_mut_lamports().unwrap() += 1;
+        assert_ne!(
+            AccountHashScope::Account
+                .hash(&account, &HashAlgorithm::Keccak256)
+                .unwrap(),
+            expected_hash
+        );
+
+        let out_of_bounds = AccountHashScope::DataRange {
+            offset: CompactU64(3),
+            length: CompactU64(3),
+        };
+        assert_eq!(
+            out_of_bounds
+                .hash(&account, &HashAlgorithm::Sha256)
+                .err()
+                .unwrap(),
+            LighthouseError::RangeOutOfBounds.into()
+        );
+    }
+}
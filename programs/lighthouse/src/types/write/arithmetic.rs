@@ -0,0 +1,189 @@
+use super::{AccountInfoField, ClockField, DataValue, WriteType};
+use crate::error::LighthouseError;
+use crate::utils::Result;
+use borsh::{BorshDeserialize, BorshSerialize};
+use lighthouse_common::CompactU64;
+use solana_program::msg;
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub enum ArithmeticMode {
+    CheckedAdd,
+    CheckedSub,
+    Min,
+    Max,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub enum IntegerType {
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+    U64,
+    I64,
+    U128,
+    I128,
+}
+
+impl IntegerType {
+    pub fn width(&self) -> usize {
+        match self {
+            IntegerType::U8 | IntegerType::I8 => 1,
+            IntegerType::U16 | IntegerType::I16 => 2,
+            IntegerType::U32 | IntegerType::I32 => 4,
+            IntegerType::U64 | IntegerType::I64 => 8,
+            IntegerType::U128 | IntegerType::I128 => 16,
+        }
+    }
+}
+
+// The value combined with memory, integer sized sources are read the same as their `WriteType`.
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub enum ArithmeticSource {
+    AccountData { offset: CompactU64 },
+    AccountInfoField(AccountInfoField),
+    DataValue(DataValue),
+    Clock(ClockField),
+}
+
+impl ArithmeticSource {
+    pub fn to_write_type(&self, integer_type: &IntegerType) -> WriteType {
+        match self {
+            ArithmeticSource::AccountData { offset } => WriteType::AccountData {
+                offset: offset.clone(),
+                data_length: CompactU64(integer_type.width() as u64),
+            },
+            ArithmeticSource::AccountInfoField(field) => WriteType::AccountInfoField(field.clone()),
+            ArithmeticSource::DataValue(value) => WriteType::DataValue(value.clone()),
+            ArithmeticSource::Clock(field) => WriteType::Clock(field.clone()),
+        }
+    }
+}
+
+macro_rules! combine_le_bytes {
+    ($type:ty, $mode:expr, $current:expr, $value:expr) => {{
+        let current = <$type>::from_le_bytes($current.try_into().unwrap());
+        let value = <$type>::from_le_bytes($value.try_into().unwrap());
+
+        match $mode {
+            ArithmeticMode::CheckedAdd => current.checked_add(value),
+            ArithmeticMode::CheckedSub => current.checked_sub(value),
+            ArithmeticMode::Min => Some(current.min(value)),
+            ArithmeticMode::Max => Some(current.max(value)),
+        }
+        .map(|result| result.to_le_bytes().to_vec())
+    }};
+}
+
+impl ArithmeticMode {
+    /// Combines the little-endian `current` and `value` integers, both `integer_type` wide.
+    pub fn combine(
+        &self,
+        integer_type: &IntegerType,
+        current: &[u8],
+        value: &[u8],
+    ) -> Result<Vec<u8>> {
+        if current.len() != integer_type.width() || value.len() != integer_type.width() {
+            msg!(
+                "Arithmetic write - expected {} byte values, got {} and {}",
+                integer_type.width(),
+                current.len(),
+                value.len()
+            );
+            return Err(LighthouseError::InvalidInstructionData.into());
+        }
+
+        let result = match integer_type {
+            IntegerType::U8 => combine_le_bytes!(u8, self, current, value),
+            IntegerType::I8 => combine_le_bytes!(i8, self, current, value),
+            IntegerType::U16 => combine_le_bytes!(u16, self, current, value),
+            IntegerType::I16 => combine_le_bytes!(i16, self, current, value),
+            IntegerType::U32 => combine_le_bytes!(u32, self, current, value),
+            IntegerType::I32 => combine_le_bytes!(i32, self, current, value),
+            IntegerType::U64 => combine_le_bytes!(u64, self, current, value),
+            IntegerType::I64 => combine_le_bytes!(i64, self, current, value),
+            IntegerType::U128 => combine_le_bytes!(u128, self, current, value),
+            IntegerType::I128 => combine_le_bytes!(i128, self, current, value),
+        };
+
+        result.ok_or_else(|| {
+            msg!("Arithmetic write - {:?} overflowed", self);
+            LighthouseError::ArithmeticOverflow.into()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ArithmeticMode, IntegerType};
+    use crate::error::LighthouseError;
+
+    #[test]
+    fn combine() {
+        let combine = |mode: ArithmeticMode, integer_type: IntegerType, a: &[u8], b: &[u8]| {
+            mode.combine(&integer_type, a, b)
+        };
+
+        assert_eq!(
+            combine(
+                ArithmeticMode::CheckedAdd,
+                IntegerType::U64,
+                &100u64.to_le_bytes(),
+                &25u64.to_le_bytes()
+            )
+            .unwrap(),
+            125u64.to_le_bytes()
+        );
+        assert_eq!(
+            combine(
+                ArithmeticMode::CheckedSub,
+                IntegerType::I32,
+                &(-5i32).to_le_bytes(),
+                &10i32.to_le_bytes()
+            )
+            .unwrap(),
+            (-15i32).to_le_bytes()
+        );
+        assert_eq!(
+            combine(
+                ArithmeticMode::Min,
+                IntegerType::I16,
+                &(-1i16).to_le_bytes(),
+                &1i16.to_le_bytes()
+            )
+            .unwrap(),
+            (-1i16).to_le_bytes()
+        );
+        assert_eq!(
+            combine(
+                ArithmeticMode::Max,
+                IntegerType::U128,
+                &1u128.to_le_bytes(),
+                &u128::MAX.to_le_bytes()
+            )
+            .unwrap(),
+            u128::MAX.to_le_bytes()
+        );
+
+        assert_eq!(
+            combine(ArithmeticMode::CheckedAdd, IntegerType::U8, &[255], &[1])
+                .err()
+                .unwrap(),
+            LighthouseError::ArithmeticOverflow.into()
+        );
+        assert_eq!(
+            combine(ArithmeticMode::CheckedSub, IntegerType::U8, &[0], &[1])
+                .err()
+                .unwrap(),
+            LighthouseError::ArithmeticOverflow.into()
+        );
+        assert_eq!(
+            combine(ArithmeticMode::CheckedAdd, IntegerType::U16, &[0, 0], &[1])
+                .err()
+                .unwrap(),
+            LighthouseError::InvalidInstructionData.into()
+        );
+    }
+}
@@ -0,0 +1,10 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub enum EpochScheduleField {
+    SlotsPerEpoch,
+    LeaderScheduleSlotOffset,
+    Warmup,
+    FirstNormalEpoch,
+    FirstNormalSlot,
+}
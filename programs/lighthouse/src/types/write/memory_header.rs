@@ -0,0 +1,233 @@
+use super::WriteTypeTag;
+use crate::err_msg;
+use crate::error::LighthouseError;
+use crate::utils::{checked_get_slice, keys_equal, Result};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{account_info::AccountInfo, msg};
+
+pub const MEMORY_HEADER_DISCRIMINATOR: [u8; 8] = *b"lhmemhdr";
+pub const MEMORY_HEADER_VERSION: u8 = 2;
+pub const MEMORY_HEADER_TAGS: usize = 8;
+
+// Written at the start of a memory account so delta assertions can reject leftover data from an
+// earlier transaction. Once written, every later write to the memory restamps the slot and records
+// the slot and type of the write at its offset, writing the header again clears the recorded tags.
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub struct MemoryHeader {
+    pub discriminator: [u8; 8],
+    pub version: u8,
+    pub slot: u64,
+    pub nonce: u64,
+    pub tag_count: u8,
+    pub tags: [MemoryTag; 8],
+}
+
+// The slot and type of the write most recently made at `offset`, recorded by the program.
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryTag {
+    pub offset: u32,
+    pub slot: u64,
+    pub tag: WriteTypeTag,
+}
+
+impl MemoryTag {
+    const EMPTY: Self = Self {
+        offset: 0,
+        slot: 0,
+        tag: WriteTypeTag::AccountData,
+    };
+}
+
+impl MemoryHeader {
+    pub const LEN: usize = 130;
+
+    pub fn new(slot: u64, nonce: u64) -> Self {
+        Self {
+            discriminator: MEMORY_HEADER_DISCRIMINATOR,
+            version: MEMORY_HEADER_VERSION,
+            slot,
+            nonce,
+            tag_count: 0,
+            tags: [MemoryTag::EMPTY; MEMORY_HEADER_TAGS],
+        }
+    }
+
+    pub fn is_present(memory: &[u8]) -> bool {
+        memory.get(..MEMORY_HEADER_DISCRIMINATOR.len()) == Some(&MEMORY_HEADER_DISCRIMINATOR[..])
+    }
+
+    pub fn read(memory: &[u8]) -> Result<Self> {
+        let header = Self::try_from_slice(checked_get_slice(memory, 0, Self::LEN)?)
+            .map_err(|_| LighthouseError::FailedToDeserialize)?;
+
+        if header.discriminator != MEMORY_HEADER_DISCRIMINATOR {
+            msg!("Memory header not found");
+            return Err(LighthouseError::AccountDiscriminatorValidationFailed.into());
+        }
+
+        if header.version != MEMORY_HEADER_VERSION {
+            msg!(
+                "Memory header version mismatch: expected {} found {}",
+                MEMORY_HEADER_VERSION,
+                header.version
+            );
+            return Err(LighthouseError::AccountDiscriminatorValidationFailed.into());
+        }
+
+        Ok(header)
+    }
+
+    // Only the program writes memory accounts it owns, so a header read from any other account
+    // could have been forged.
+    pub fn read_account(memory: &AccountInfo) -> Result<Self> {
+        if !keys_equal(memory.owner, &crate::ID) {
+            msg!("Memory account {} is not owned by Lighthouse", memory.key);
+            return Err(LighthouseError::AccountOwnerMismatch.into());
+        }
+
+        let memory_data = memory
+            .try_borrow_data()
+            .map_err(LighthouseError::failed_borrow_err)?;
+
+        Self::read(&memory_data)
+    }
+
+    pub fn write(&self, memory: &mut [u8]) -> Result<()> {
+        let bytes = self.try_to_vec().map_err(|err| {
+            err_msg!("Failed to serialize MemoryHeader", err);
+            LighthouseError::FailedToSerialize
+        })?;
+
+        let memory_slice = memory.get_mut(..Self::LEN).ok_or_else(|| {
+            msg!("MemoryHeader write - range out of bounds 0..{}", Self::LEN);
+            LighthouseError::RangeOutOfBounds
+        })?;
+        memory_slice.copy_from_slice(&bytes);
+
+        Ok(())
+    }
+
+    pub fn tag(&self, offset: u64) -> Option<&MemoryTag> {
+        self.tags[..self.tag_count as usize]
+            .iter()
+            .find(|tag| tag.offset as u64 == offset)
+    }
+
+    // Like `tag`, but a missing write fails the assertion.
+    pub fn expect_tag(&self, offset: u64) -> Result<&MemoryTag> {
+        self.tag(offset).ok_or_else(|| {
+            msg!("No write recorded at memory offset {}", offset);
+            LighthouseError::AssertionFailed.into()
+        })
+    }
+
+    // Tags are kept from least to most recently written. Once the table is full, the tag of the
+    // least recently written offset is dropped and a guard on that offset fails as if it had never
+    // been written.
+    pub fn record(&mut self, offset: u64, slot: u64, tag: WriteTypeTag) -> Result<()> {
+        let offset = u32::try_from(offset).map_err(|_| LighthouseError::RangeOutOfBounds)?;
+        let count = self.tag_count as usize;
+
+        let removed = self.tags[..count]
+            .iter()
+            .position(|entry| entry.offset == offset)
+            .or((count == MEMORY_HEADER_TAGS).then_some(0));
+
+        match removed {
+            Some(index) => self.tags.copy_within(index + 1..count, index),
+            None => self.tag_count += 1,
+        }
+        self.tags[self.tag_count as usize - 1] = MemoryTag { offset, slot, tag };
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MemoryHeader, MemoryTag, MEMORY_HEADER_TAGS};
+    use crate::error::LighthouseError;
+    use crate::types::write::WriteTypeTag;
+    use borsh::BorshSerialize;
+    use solana_program::program_error::ProgramError;
+
+    #[test]
+    fn read_header() {
+        let header = MemoryHeader::new(42, 7);
+        let mut memory = vec![0u8; MemoryHeader::LEN + 4];
+
+        assert!(!MemoryHeader::is_present(&memory));
+        assert_eq!(
+            MemoryHeader::read(&memory).unwrap_err(),
+            ProgramError::from(LighthouseError::AccountDiscriminatorValidationFailed)
+        );
+
+        header.write(&mut memory).unwrap();
+
+        assert_eq!(header.try_to_vec().unwrap().len(), MemoryHeader::LEN);
+        assert!(MemoryHeader::is_present(&memory));
+        assert_eq!(MemoryHeader::read(&memory).unwrap(), header);
+
+        memory[8] = 1;
+        assert_eq!(
+            MemoryHeader::read(&memory).unwrap_err(),
+            ProgramError::from(LighthouseError::AccountDiscriminatorValidationFailed)
+        );
+        assert_eq!(
+            MemoryHeader::read(&memory[..MemoryHeader::LEN - 1]).unwrap_err(),
+            ProgramError::from(LighthouseError::RangeOutOfBounds)
+        );
+    }
+
+    #[test]
+    fn record_tags() {
+        let mut header = MemoryHeader::new(42, 7);
+
+        header
+            .record(130, 42, WriteTypeTag::TokenAccountSnapshot)
+            .unwrap();
+        header.record(200, 42, WriteTypeTag::DataValue).unwrap();
+        header
+            .record(130, 43, WriteTypeTag::AccountInfoField)
+            .unwrap();
+
+        assert_eq!(header.tag_count, 2);
+        assert_eq!(
+            header.tag(130),
+            Some(&MemoryTag {
+                offset: 130,
+                slot: 43,
+                tag: WriteTypeTag::AccountInfoField
+            })
+        );
+        assert_eq!(header.tag(200).unwrap().tag, WriteTypeTag::DataValue);
+        assert_eq!(header.tag(0), None);
+        assert_eq!(
+            header.expect_tag(0).unwrap_err(),
+            ProgramError::from(LighthouseError::AssertionFailed)
+        );
+
+        for offset in 2..MEMORY_HEADER_TAGS as u64 {
+            header.record(offset, 44, WriteTypeTag::Clock).unwrap();
+        }
+        assert_eq!(header.tag_count as usize, MEMORY_HEADER_TAGS);
+
+        // A full table drops the least recently written offset, 200 since 130 was rewritten.
+        header.record(300, 45, WriteTypeTag::Arithmetic).unwrap();
+        assert_eq!(header.tag_count as usize, MEMORY_HEADER_TAGS);
+        assert_eq!(header.tag(200), None);
+        assert_eq!(header.tag(130).unwrap().tag, WriteTypeTag::AccountInfoField);
+        assert_eq!(header.tag(300).unwrap().tag, WriteTypeTag::Arithmetic);
+
+        header.record(130, 46, WriteTypeTag::DataValue).unwrap();
+        header.record(400, 46, WriteTypeTag::DataValue).unwrap();
+        assert_eq!(header.tag(2), None);
+        assert_eq!(header.tag(130).unwrap().slot, 46);
+        assert_eq!(
+            header
+                .record(u64::MAX, 42, WriteTypeTag::Clock)
+                .unwrap_err(),
+            ProgramError::from(LighthouseError::RangeOutOfBounds)
+        );
+    }
+}
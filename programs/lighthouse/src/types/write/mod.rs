@@ -1,9 +1,19 @@
+pub mod account_hash;
 pub mod account_info;
+pub mod arithmetic;
 pub mod clock;
 pub mod data_value;
+pub mod epoch_schedule;
+pub mod memory_header;
+pub mod stake_activation;
 pub mod write_type;
 
+pub use account_hash::*;
 pub use account_info::*;
+pub use arithmetic::*;
 pub use clock::*;
 pub use data_value::*;
+pub use epoch_schedule::*;
+pub use memory_header::*;
+pub use stake_activation::*;
 pub use write_type::*;
@@ -0,0 +1,71 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    clock::Epoch,
+    stake::state::{Stake, StakeActivationStatus},
+    stake_history::StakeHistory,
+};
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub enum StakeActivationField {
+    EffectiveStake,
+    ActivatingStake,
+    DeactivatingStake,
+}
+
+impl StakeActivationField {
+    pub fn read(
+        &self,
+        stake: &Stake,
+        epoch: Epoch,
+        stake_history: &StakeHistory,
+        new_rate_activation_epoch: Option<Epoch>,
+    ) -> u64 {
+        let StakeActivationStatus {
+            effective,
+            activating,
+            deactivating,
+        } = stake.delegation.stake_activating_and_deactivating(
+            epoch,
+            stake_history,
+            new_rate_activation_epoch,
+        );
+
+        match self {
+            StakeActivationField::EffectiveStake => effective,
+            StakeActivationField::ActivatingStake => activating,
+            StakeActivationField::DeactivatingStake => deactivating,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StakeActivationField;
+    use crate::test_utils::{create_test_stake, create_test_stake_history};
+
+    #[test]
+    fn read_stake_activation() {
+        let stake_history = create_test_stake_history(|_| 1_000_000);
+        let stake = create_test_stake();
+
+        let cases = [
+            (StakeActivationField::ActivatingStake, 5, 1_000),
+            (StakeActivationField::EffectiveStake, 5, 0),
+            (StakeActivationField::EffectiveStake, 8, 1_000),
+            (StakeActivationField::ActivatingStake, 8, 0),
+            (StakeActivationField::DeactivatingStake, 10, 1_000),
+            (StakeActivationField::EffectiveStake, 15, 0),
+            (StakeActivationField::DeactivatingStake, 15, 0),
+        ];
+
+        for (field, epoch, expected) in cases {
+            assert_eq!(
+                field.read(&stake, epoch, &stake_history, None),
+                expected,
+                "{:?} at epoch {}",
+                field,
+                epoch
+            );
+        }
+    }
+}
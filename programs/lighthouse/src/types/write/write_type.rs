@@ -1,21 +1,154 @@
-use super::{AccountInfoField, ClockField, DataValue};
+use super::{
+    AccountHashScope, AccountInfoField, ArithmeticMode, ArithmeticSource, ClockField, DataValue,
+    EpochScheduleField, HashAlgorithm, IntegerType, MemoryHeader, StakeActivationField,
+};
+use crate::types::CompactDataRanges;
 use borsh::{BorshDeserialize, BorshSerialize};
+use lighthouse_common::CompactU64;
+use solana_program::{program_pack::Pack, pubkey::Pubkey};
 
 #[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
 pub enum WriteType {
-    AccountData { offset: u16, data_length: u16 },
+    AccountData {
+        offset: CompactU64,
+        data_length: CompactU64,
+    },
     AccountInfoField(AccountInfoField),
     DataValue(DataValue),
     Clock(ClockField),
+    AccountHash {
+        algorithm: HashAlgorithm,
+        scope: AccountHashScope,
+    },
+    // Copies each range of the source account data back to back into memory.
+    AccountDataRanges {
+        ranges: CompactDataRanges,
+    },
+    // Combines the integer produced by `source` with the integer already in memory.
+    Arithmetic {
+        mode: ArithmeticMode,
+        integer_type: IntegerType,
+        source: ArithmeticSource,
+    },
+    // Writes a `MemoryHeader` stamped with the current slot and the client supplied nonce, must be
+    // written at offset 0.
+    MemoryHeader {
+        nonce: u64,
+    },
+    // Copies the key of a token account or mint followed by its base state, read by
+    // `AssertTokenAccountDelta` and `AssertMintAccountDelta`.
+    TokenAccountSnapshot,
+    MintAccountSnapshot,
+    // Copies a range of the data most recently set through `set_return_data`, which must have been
+    // set by `program_id`.
+    ReturnData {
+        program_id: Pubkey,
+        offset: CompactU64,
+        length: CompactU64,
+    },
+    // Minimum balance for an account of `data_length` bytes to be rent exempt.
+    RentMinimumBalance {
+        data_length: CompactU64,
+    },
+    EpochSchedule(EpochScheduleField),
+    // Index of the executing instruction, the source account must be the Instructions sysvar.
+    InstructionIndex,
+    // Stake of the source stake account at the current epoch, the StakeHistory sysvar and the
+    // reduced warmup/cooldown feature account must be passed among the source accounts.
+    StakeActivation(StakeActivationField),
+    // Grows the memory to `space` bytes without writing, by at most the runtime's 10 KiB growth
+    // limit per instruction. Memory larger than that is grown by repeating the write in several
+    // instructions, must be written at offset 0.
+    Resize {
+        space: CompactU64,
+    },
+}
+
+// The kind of a `WriteType`, recorded in the `MemoryHeader` for each write.
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteTypeTag {
+    AccountData,
+    AccountInfoField,
+    DataValue,
+    Clock,
+    AccountHash,
+    AccountDataRanges,
+    Arithmetic,
+    MemoryHeader,
+    TokenAccountSnapshot,
+    MintAccountSnapshot,
+    ReturnData,
+    RentMinimumBalance,
+    EpochSchedule,
+    InstructionIndex,
+    StakeActivation,
+    Resize,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub struct DataRange {
+    pub offset: CompactU64,
+    pub length: CompactU64,
+}
+
+// A single write of a `MemoryWriteMulti` instruction, `source_account_index` indexes the accounts
+// following the memory account.
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub struct MemoryWriteEntry {
+    pub source_account_index: u8,
+    pub write_offset: CompactU64,
+    pub write_type: WriteType,
+}
+
+// Wider memory id with an optional namespace, such as a program id, so independent tools sharing
+// a payer don't collide.
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub struct MemoryKey {
+    pub namespace: Option<Pubkey>,
+    pub id: u64,
+}
+
+// Identifies a memory account by the id or key and the bump of its PDA.
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+pub enum MemoryAccount {
+    Index {
+        memory_id: u8,
+        memory_bump: u8,
+    },
+    Key {
+        memory_key: MemoryKey,
+        memory_bump: u8,
+    },
 }
 
 impl WriteType {
+    pub fn type_tag(&self) -> WriteTypeTag {
+        match self {
+            WriteType::AccountData { .. } => WriteTypeTag::AccountData,
+            WriteType::AccountInfoField(_) => WriteTypeTag::AccountInfoField,
+            WriteType::DataValue(_) => WriteTypeTag::DataValue,
+            WriteType::Clock(_) => WriteTypeTag::Clock,
+            WriteType::AccountHash { .. } => WriteTypeTag::AccountHash,
+            WriteType::AccountDataRanges { .. } => WriteTypeTag::AccountDataRanges,
+            WriteType::Arithmetic { .. } => WriteTypeTag::Arithmetic,
+            WriteType::MemoryHeader { .. } => WriteTypeTag::MemoryHeader,
+            WriteType::TokenAccountSnapshot => WriteTypeTag::TokenAccountSnapshot,
+            WriteType::MintAccountSnapshot => WriteTypeTag::MintAccountSnapshot,
+            WriteType::ReturnData { .. } => WriteTypeTag::ReturnData,
+            WriteType::RentMinimumBalance { .. } => WriteTypeTag::RentMinimumBalance,
+            WriteType::EpochSchedule(_) => WriteTypeTag::EpochSchedule,
+            WriteType::InstructionIndex => WriteTypeTag::InstructionIndex,
+            WriteType::StakeActivation(_) => WriteTypeTag::StakeActivation,
+            WriteType::Resize { .. } => WriteTypeTag::Resize,
+        }
+    }
+
     pub fn data_length(&self) -> u64 {
         match self {
             WriteType::AccountData {
                 offset: _,
                 data_length,
-            } => *data_length as u64,
+            } => **data_length,
             WriteType::AccountInfoField(field) => match field {
                 AccountInfoField::Key => 32,
                 AccountInfoField::Lamports => 8,
@@ -40,6 +173,34 @@ impl WriteType {
                 ClockField::LeaderScheduleEpoch => 8,
                 ClockField::UnixTimestamp => 8,
             },
+            WriteType::AccountHash { .. } => 32,
+            WriteType::AccountDataRanges { ranges } => ranges
+                .iter()
+                .fold(0u64, |length, range| length.saturating_add(*range.length)),
+            WriteType::Arithmetic { integer_type, .. } => integer_type.width() as u64,
+            WriteType::MemoryHeader { .. } => MemoryHeader::LEN as u64,
+            WriteType::TokenAccountSnapshot => 32 + spl_token::state::Account::LEN as u64,
+            WriteType::MintAccountSnapshot => 32 + spl_token::state::Mint::LEN as u64,
+            WriteType::ReturnData { length, .. } => **length,
+            WriteType::RentMinimumBalance { .. } => 8,
+            WriteType::EpochSchedule(field) => match field {
+                EpochScheduleField::SlotsPerEpoch => 8,
+                EpochScheduleField::LeaderScheduleSlotOffset => 8,
+                EpochScheduleField::Warmup => 1,
+                EpochScheduleField::FirstNormalEpoch => 8,
+                EpochScheduleField::FirstNormalSlot => 8,
+            },
+            WriteType::InstructionIndex => 2,
+            WriteType::StakeActivation(_) => 8,
+            WriteType::Resize { .. } => 0,
+        }
+    }
+
+    // Memory the write needs at `offset`, a resize needs the space it grows the memory to.
+    pub fn required_space(&self, offset: u64) -> u64 {
+        match self {
+            WriteType::Resize { space } => **space,
+            _ => offset.saturating_add(self.data_length()),
         }
     }
 }
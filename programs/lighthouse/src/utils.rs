@@ -11,11 +11,13 @@ use solana_program::{
     program::{invoke, invoke_signed},
     program_error::ProgramError,
     program_memory::sol_memcmp,
+    program_pack::Pack,
     pubkey::Pubkey,
     pubkey::PUBKEY_BYTES,
     rent::Rent,
     system_instruction, system_program,
 };
+use spl_token_2022::extension::{BaseState, StateWithExtensions};
 
 pub type Result<T> = std::result::Result<T, ProgramError>;
 
@@ -89,6 +91,46 @@ pub fn try_from_slice<T: BorshDeserialize + Sized>(data: &[u8], offset: usize) -
     Ok(T::try_from_slice(slice)?)
 }
 
+/// Instruction account at `index`, used for account reference operands.
+pub fn get_account<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    index: u8,
+) -> Result<&'a AccountInfo<'info>> {
+    accounts.get(index as usize).ok_or_else(|| {
+        msg!(
+            "Account index {} out of range, {} accounts provided",
+            index,
+            accounts.len()
+        );
+        LighthouseError::NotEnoughAccounts.into()
+    })
+}
+
+/// Key of the instruction account at `index`.
+pub fn get_account_key<'a>(accounts: &'a [AccountInfo], index: u8) -> Result<&'a Pubkey> {
+    get_account(accounts, index).map(|account| account.key)
+}
+
+/// Reads an unsigned integer of `width` bytes (1 to 16) in either byte order.
+pub fn read_unsigned(data: &[u8], offset: usize, width: u8, big_endian: bool) -> Result<u128> {
+    if width == 0 || width > 16 {
+        msg!("Invalid integer width {}", width);
+        return Err(LighthouseError::InvalidInstructionData.into());
+    }
+
+    let width = width as usize;
+    let slice = checked_get_slice(data, offset, width)?;
+    let mut bytes = [0u8; 16];
+
+    if big_endian {
+        bytes[16 - width..].copy_from_slice(slice);
+        Ok(u128::from_be_bytes(bytes))
+    } else {
+        bytes[..width].copy_from_slice(slice);
+        Ok(u128::from_le_bytes(bytes))
+    }
+}
+
 pub fn create_account<'a, 'info>(
     payer: &'a AccountInfo<'info>,
     new_account: &'a AccountInfo<'info>,
@@ -177,6 +219,31 @@ pub fn contains_key(key: &Pubkey, keys: &[&Pubkey]) -> bool {
     keys.iter().any(|k| keys_equal(k, key))
 }
 
+// Checks that `account` is owned by a token program and holds an initialized `S`, token-2022
+// accounts may carry extensions after the base state.
+pub fn validate_token_state<S: BaseState + Pack>(account: &AccountInfo) -> Result<()> {
+    if !keys_equal(account.owner, &spl_token::ID) && !keys_equal(account.owner, &spl_token_2022::ID)
+    {
+        return Err(LighthouseError::AccountOwnerMismatch.into());
+    }
+
+    let data = account
+        .try_borrow_data()
+        .map_err(LighthouseError::failed_borrow_err)?;
+
+    StateWithExtensions::<S>::unpack(&data).map_err(|err| {
+        msg!(
+            "Account {} is not a {}: {:?}",
+            account.key,
+            type_name::<S>(),
+            err
+        );
+        LighthouseError::AccountValidationFailed
+    })?;
+
+    Ok(())
+}
+
 pub const fn anchor_discriminator(preimage: &'static str) -> u64 {
     let hash: [u8; 32] = Sha256::new().update(preimage.as_bytes()).finalize();
     u64::from_le_bytes([
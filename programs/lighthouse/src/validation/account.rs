@@ -320,7 +320,8 @@ mod tests {
     use super::{AccountValidation, CheckedAccount};
     use crate::{
         error::LighthouseError,
-        validation::{DerivedAddress, Memory, MemorySeeds},
+        types::write::MemoryKey,
+        validation::{DerivedAddress, Memory, MemoryId, MemorySeeds},
         Result,
     };
     use solana_sdk::{
@@ -403,7 +404,7 @@ mod tests {
     ) -> Result<(Pubkey, u8, Vec<Vec<u8>>, Vec<Vec<u8>>)> {
         let seeds = Memory::get_seeds(MemorySeeds {
             payer,
-            memory_id,
+            memory_id: MemoryId::Index(memory_id),
             bump: None,
         });
 
@@ -427,6 +428,65 @@ mod tests {
         Ok((key, bump, seeds_without_bump, seeds))
     }
 
+    #[test]
+    fn memory_key_seeds() {
+        let payer = Keypair::new().encodable_pubkey();
+        let namespace = Keypair::new().encodable_pubkey();
+
+        let key = |memory_id: MemoryId| {
+            let seeds = Memory::get_seeds(MemorySeeds {
+                payer: &payer,
+                memory_id,
+                bump: None,
+            });
+
+            Pubkey::find_program_address(
+                &seeds.iter().map(|seed| seed.as_slice()).collect::<Vec<_>>(),
+                &crate::id(),
+            )
+            .0
+        };
+
+        let index_key = key(MemoryId::Index(1));
+        let wide_key = key(MemoryId::Key(&MemoryKey {
+            namespace: None,
+            id: 1,
+        }));
+        let namespaced_key = key(MemoryId::Key(&MemoryKey {
+            namespace: Some(namespace),
+            id: 1,
+        }));
+
+        assert_ne!(index_key, wide_key);
+        assert_ne!(wide_key, namespaced_key);
+        assert_ne!(
+            namespaced_key,
+            key(MemoryId::Key(&MemoryKey {
+                namespace: Some(namespace),
+                id: u64::MAX,
+            }))
+        );
+
+        let seeds = Memory::get_seeds(MemorySeeds {
+            payer: &payer,
+            memory_id: MemoryId::Key(&MemoryKey {
+                namespace: Some(namespace),
+                id: 1,
+            }),
+            bump: Some(255),
+        });
+        assert_eq!(
+            seeds,
+            vec![
+                b"memory".to_vec(),
+                payer.to_bytes().to_vec(),
+                namespace.to_bytes().to_vec(),
+                1u64.to_le_bytes().to_vec(),
+                vec![255],
+            ]
+        );
+    }
+
     #[test]
     fn new_checked_pda() {
         let payer = Keypair::new().encodable_pubkey();
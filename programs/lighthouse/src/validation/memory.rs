@@ -1,4 +1,5 @@
 use super::{AccountValidation, CheckedAccount, DerivedAddress};
+use crate::types::write::MemoryKey;
 use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 
 #[derive(Clone)]
@@ -6,9 +7,14 @@ pub(crate) struct Memory<'a, 'info> {
     pub(crate) info: &'a AccountInfo<'info>,
 }
 
+pub enum MemoryId<'a> {
+    Index(u8),
+    Key(&'a MemoryKey),
+}
+
 pub struct MemorySeeds<'a> {
     pub payer: &'a Pubkey,
-    pub memory_id: u8,
+    pub memory_id: MemoryId<'a>,
     pub bump: Option<u8>,
 }
 
@@ -20,12 +26,20 @@ impl<'a, 'info> DerivedAddress<MemorySeeds<'a>> for Memory<'a, 'info> {
             bump,
         } = seeds;
 
-        vec![
-            b"memory".to_vec(),
-            payer.to_bytes().to_vec(),
-            vec![memory_id],
-            bump.map_or_else(std::vec::Vec::new, |b| vec![b]),
-        ]
+        let mut seeds = vec![b"memory".to_vec(), payer.to_bytes().to_vec()];
+
+        match memory_id {
+            MemoryId::Index(memory_id) => seeds.push(vec![memory_id]),
+            MemoryId::Key(MemoryKey { namespace, id }) => {
+                if let Some(namespace) = namespace {
+                    seeds.push(namespace.to_bytes().to_vec());
+                }
+                seeds.push(id.to_le_bytes().to_vec());
+            }
+        }
+
+        seeds.push(bump.map_or_else(std::vec::Vec::new, |b| vec![b]));
+        seeds
     }
 }
 
@@ -6,12 +6,15 @@ use crate::utils::{
     process_transaction_assert_failure, process_transaction_assert_success, to_transaction_error,
 };
 use lighthouse_sdk::errors::LighthouseError;
-use lighthouse_sdk::instructions::AssertAccountInfoBuilder;
+use lighthouse_sdk::instructions::{AssertAccountInfoBuilder, AssertAccountInfoMultiBuilder};
 use lighthouse_sdk::types::{
-    AccountInfoAssertion, EquatableOperator, IntegerOperator, KnownProgram,
+    AccountInfoAssertion, EquatableOperator, IntegerOperator, KnownProgram, PdaSeed, SetOperator,
 };
 use lighthouse_sdk::CompactU64;
 use solana_program_test::tokio;
+use solana_sdk::instruction::AccountMeta;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
 use solana_sdk::signer::{EncodableKeypair, Signer};
 use solana_sdk::transaction::Transaction;
 use solana_sdk::{keccak, system_program};
@@ -109,6 +112,307 @@ async fn test_account_balance() {
         .unwrap();
 }
 
+#[tokio::test]
+async fn test_owner_in() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            AssertAccountInfoBuilder::new()
+                .target_account(user.encodable_pubkey())
+                .log_level(lighthouse_sdk::types::LogLevel::PlaintextMessage)
+                .assertion(AccountInfoAssertion::OwnerIn {
+                    value: vec![spl_token::id(), system_program::id()].into(),
+                    operator: SetOperator::In,
+                })
+                .instruction(),
+            AssertAccountInfoBuilder::new()
+                .target_account(user.encodable_pubkey())
+                .log_level(lighthouse_sdk::types::LogLevel::PlaintextMessage)
+                .assertion(AccountInfoAssertion::KnownOwnerIn {
+                    value: vec![KnownProgram::Token, KnownProgram::Token2022].into(),
+                    operator: SetOperator::NotIn,
+                })
+                .instruction(),
+        ],
+        Some(&user.pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[AssertAccountInfoBuilder::new()
+            .target_account(user.encodable_pubkey())
+            .log_level(lighthouse_sdk::types::LogLevel::PlaintextMessage)
+            .assertion(AccountInfoAssertion::KnownOwnerIn {
+                value: vec![KnownProgram::Token, KnownProgram::Token2022].into(),
+                operator: SetOperator::In,
+            })
+            .instruction()],
+        Some(&user.pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(0, LighthouseError::AssertionFailed),
+        None,
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_owner_account_ref() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[AssertAccountInfoBuilder::new()
+            .target_account(user.encodable_pubkey())
+            .log_level(lighthouse_sdk::types::LogLevel::PlaintextMessage)
+            .assertion(AccountInfoAssertion::OwnerAccountRef {
+                account_index: 1,
+                operator: EquatableOperator::Equal,
+            })
+            .add_remaining_account(AccountMeta::new_readonly(system_program::id(), false))
+            .instruction()],
+        Some(&user.pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[AssertAccountInfoBuilder::new()
+            .target_account(user.encodable_pubkey())
+            .log_level(lighthouse_sdk::types::LogLevel::PlaintextMessage)
+            .assertion(AccountInfoAssertion::OwnerAccountRef {
+                account_index: 1,
+                operator: EquatableOperator::Equal,
+            })
+            .instruction()],
+        Some(&user.pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(0, LighthouseError::NotEnoughAccounts),
+        None,
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_account_lifecycle() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+    let missing_account = Keypair::new();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            AssertAccountInfoMultiBuilder::new()
+                .target_account(user.encodable_pubkey())
+                .log_level(lighthouse_sdk::types::LogLevel::PlaintextMessage)
+                .assertions(
+                    vec![
+                        AccountInfoAssertion::IsWallet {
+                            value: true,
+                            operator: EquatableOperator::Equal,
+                        },
+                        AccountInfoAssertion::Exists {
+                            value: true,
+                            operator: EquatableOperator::Equal,
+                        },
+                        AccountInfoAssertion::IsRentExempt {
+                            value: true,
+                            operator: EquatableOperator::Equal,
+                        },
+                        AccountInfoAssertion::LamportsAboveRentMinimum {
+                            value: 0,
+                            operator: IntegerOperator::GreaterThan,
+                        },
+                    ]
+                    .into(),
+                )
+                .instruction(),
+            AssertAccountInfoBuilder::new()
+                .target_account(missing_account.encodable_pubkey())
+                .log_level(lighthouse_sdk::types::LogLevel::PlaintextMessage)
+                .assertion(AccountInfoAssertion::Exists {
+                    value: false,
+                    operator: EquatableOperator::Equal,
+                })
+                .instruction(),
+            // An account below the rent-exempt minimum has a negative balance above it.
+            AssertAccountInfoBuilder::new()
+                .target_account(missing_account.encodable_pubkey())
+                .log_level(lighthouse_sdk::types::LogLevel::PlaintextMessage)
+                .assertion(AccountInfoAssertion::LamportsAboveRentMinimum {
+                    value: 0,
+                    operator: IntegerOperator::LessThan,
+                })
+                .instruction(),
+        ],
+        Some(&user.pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[AssertAccountInfoBuilder::new()
+            .target_account(missing_account.encodable_pubkey())
+            .log_level(lighthouse_sdk::types::LogLevel::PlaintextMessage)
+            .assertion(AccountInfoAssertion::IsRentExempt {
+                value: true,
+                operator: EquatableOperator::Equal,
+            })
+            .instruction()],
+        Some(&user.pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(0, LighthouseError::AssertionFailed),
+        None,
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_key_is_derived() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+    let mint = Keypair::new();
+    let ata = get_associated_token_address(&user.pubkey(), &mint.pubkey());
+
+    let seeds = vec![
+        PdaSeed::AccountKey { account_index: 1 },
+        PdaSeed::Bytes {
+            value: spl_token::id().to_bytes().to_vec().into(),
+        },
+        PdaSeed::AccountKey { account_index: 2 },
+    ];
+
+    let assert_derived_with = |target: Pubkey, bump: Option<u8>, operator: EquatableOperator| {
+        AssertAccountInfoBuilder::new()
+            .target_account(target)
+            .log_level(lighthouse_sdk::types::LogLevel::PlaintextMessage)
+            .assertion(AccountInfoAssertion::KeyIsDerived {
+                seeds: seeds.clone().into(),
+                program_id: spl_associated_token_account::id(),
+                bump,
+                operator,
+            })
+            .add_remaining_account(AccountMeta::new_readonly(user.pubkey(), false))
+            .add_remaining_account(AccountMeta::new_readonly(mint.pubkey(), false))
+            .instruction()
+    };
+    let assert_derived =
+        |target: Pubkey| assert_derived_with(target, None, EquatableOperator::Equal);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[assert_derived(ata)],
+        Some(&user.pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    // A look-alike account that is not the derived address is rejected.
+    let tx = Transaction::new_signed_with_payer(
+        &[assert_derived(Keypair::new().pubkey())],
+        Some(&user.pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(0, LighthouseError::AssertionFailed),
+        None,
+    )
+    .await
+    .unwrap();
+
+    // A bump with no valid program address matches no key.
+    let invalid_bump = (0..=u8::MAX)
+        .find(|bump| {
+            Pubkey::create_program_address(
+                &[
+                    user.pubkey().as_ref(),
+                    spl_token::id().as_ref(),
+                    mint.pubkey().as_ref(),
+                    &[*bump],
+                ],
+                &spl_associated_token_account::id(),
+            )
+            .is_err()
+        })
+        .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[assert_derived_with(
+            ata,
+            Some(invalid_bump),
+            EquatableOperator::NotEqual,
+        )],
+        Some(&user.pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[assert_derived_with(
+            ata,
+            Some(invalid_bump),
+            EquatableOperator::Equal,
+        )],
+        Some(&user.pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(0, LighthouseError::AssertionFailed),
+        None,
+    )
+    .await
+    .unwrap();
+}
+
 #[tokio::test]
 async fn data_hash() {
     let ctx = &mut TestContext::new().await.unwrap();
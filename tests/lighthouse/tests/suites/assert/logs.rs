@@ -110,7 +110,7 @@ async fn encoded_message() {
         AssertionResult::U64(
             Some(69_000),
             Some(69_000),
-            IntegerOperator::Equal as u8,
+            u8::from(IntegerOperator::Equal),
             true,
         ),
     ];
@@ -196,7 +196,7 @@ async fn encoded_message_fail_only() {
     let expected_payloads: [AssertionResult; 1] = [AssertionResult::U64(
         Some(69_000),
         Some(69_000),
-        IntegerOperator::NotEqual as u8,
+        u8::from(IntegerOperator::NotEqual),
         false,
     )];
 
@@ -8,6 +8,7 @@ pub mod logs;
 pub mod merkle_tree;
 pub mod mint_account;
 pub mod mint_account_multi;
+pub mod return_data;
 pub mod stake_account;
 pub mod stake_account_multi;
 pub mod token_account;
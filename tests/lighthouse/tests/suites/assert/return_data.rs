@@ -0,0 +1,133 @@
+use crate::utils::context::TestContext;
+use crate::utils::{
+    create_mint, create_user, process_transaction_assert_failure,
+    process_transaction_assert_success, to_transaction_error, CreateMintParameters,
+};
+use lighthouse_sdk::errors::LighthouseError;
+use lighthouse_sdk::instructions::AssertReturnDataBuilder;
+use lighthouse_sdk::types::{DataValueAssertion, IntegerOperator, LogLevel};
+use solana_program::program_pack::Pack;
+use solana_program_test::tokio;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::EncodableKeypair;
+use solana_sdk::transaction::Transaction;
+
+#[tokio::test]
+async fn simple() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+
+    let (tx, mint) = create_mint(
+        context,
+        &user,
+        CreateMintParameters {
+            token_program: spl_token::id(),
+            mint_authority: None,
+            freeze_authority: None,
+            mint_to: None,
+            decimals: 9,
+        },
+    )
+    .await
+    .unwrap();
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    // GetAccountDataSize returns the size of a token account as a little endian u64.
+    let get_account_data_size_ix =
+        spl_token::instruction::get_account_data_size(&spl_token::id(), &mint.encodable_pubkey())
+            .unwrap();
+    let account_size = spl_token::state::Account::LEN as u64;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            get_account_data_size_ix.clone(),
+            AssertReturnDataBuilder::new()
+                .log_level(LogLevel::PlaintextMessage)
+                .program_id(spl_token::id())
+                .offset(0u8.into())
+                .assertion(DataValueAssertion::U64 {
+                    value: account_size,
+                    operator: IntegerOperator::Equal,
+                })
+                .instruction(),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    let failing_assertions = [
+        (
+            spl_token::id(),
+            DataValueAssertion::U64 {
+                value: account_size,
+                operator: IntegerOperator::GreaterThan,
+            },
+        ),
+        // Return data was set by a different program
+        (
+            Pubkey::new_unique(),
+            DataValueAssertion::U64 {
+                value: account_size,
+                operator: IntegerOperator::Equal,
+            },
+        ),
+    ];
+
+    for (program_id, assertion) in failing_assertions {
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                get_account_data_size_ix.clone(),
+                AssertReturnDataBuilder::new()
+                    .log_level(LogLevel::PlaintextMessage)
+                    .program_id(program_id)
+                    .offset(0u8.into())
+                    .assertion(assertion)
+                    .instruction(),
+            ],
+            Some(&user.encodable_pubkey()),
+            &[&user],
+            context.get_blockhash().await,
+        );
+
+        process_transaction_assert_failure(
+            context,
+            tx,
+            to_transaction_error(1, LighthouseError::AssertionFailed),
+            None,
+        )
+        .await
+        .unwrap();
+    }
+
+    // No return data set in the transaction
+    let tx = Transaction::new_signed_with_payer(
+        &[AssertReturnDataBuilder::new()
+            .log_level(LogLevel::PlaintextMessage)
+            .program_id(spl_token::id())
+            .offset(0u8.into())
+            .assertion(DataValueAssertion::U64 {
+                value: account_size,
+                operator: IntegerOperator::Equal,
+            })
+            .instruction()],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(0, LighthouseError::AssertionFailed),
+        None,
+    )
+    .await
+    .unwrap();
+}
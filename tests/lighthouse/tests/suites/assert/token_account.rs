@@ -9,14 +9,20 @@ use crate::utils::{
 use anchor_spl::associated_token::get_associated_token_address;
 use anchor_spl::token_2022;
 use lighthouse_sdk::errors::LighthouseError;
+use lighthouse_sdk::find_memory_pda;
 use lighthouse_sdk::instructions::AssertTokenAccountBuilder;
 use lighthouse_sdk::instructions::AssertTokenAccountMultiBuilder;
+use lighthouse_sdk::instructions::{
+    AssertMintAccountDeltaBuilder, AssertTokenAccountDeltaBuilder, MemoryWriteBuilder,
+};
 use lighthouse_sdk::types::{
-    AssertionResult, EquatableOperator, IntegerOperator, TokenAccountAssertion,
+    AssertionResult, EquatableOperator, IntegerOperator, LogLevel, MintAccountDeltaAssertion,
+    TokenAccountAssertion, TokenAccountDeltaAssertion, WriteType,
 };
 use lighthouse_sdk::utils::{
     append_instructions_to_transaction, parse_evaluation_payloads_from_logs,
 };
+use solana_program::instruction::AccountMeta;
 use solana_program::program_pack::Pack;
 use solana_program::system_instruction::transfer;
 use solana_program_test::tokio;
@@ -878,30 +884,30 @@ async fn encoded_message() {
         AssertionResult::U64(
             Some(69_000),
             Some(69_000),
-            IntegerOperator::Equal as u8,
+            u8::from(IntegerOperator::Equal),
             true,
         ),
         AssertionResult::U64(
             Some(69_000),
             Some(69_001),
-            IntegerOperator::NotEqual as u8,
+            u8::from(IntegerOperator::NotEqual),
             true,
         ),
         AssertionResult::Pubkey(None, None, EquatableOperator::Equal as u8, true),
         AssertionResult::U8(
             Some(AccountState::Initialized as u8),
             Some(AccountState::Initialized as u8),
-            IntegerOperator::Equal as u8,
+            u8::from(IntegerOperator::Equal),
             true,
         ),
         AssertionResult::U8(
             Some(AccountState::Initialized as u8),
             Some(AccountState::Frozen as u8),
-            IntegerOperator::NotEqual as u8,
+            u8::from(IntegerOperator::NotEqual),
             true,
         ),
         AssertionResult::U64(None, None, EquatableOperator::Equal as u8, true),
-        AssertionResult::U64(Some(0), Some(0), IntegerOperator::Equal as u8, true),
+        AssertionResult::U64(Some(0), Some(0), u8::from(IntegerOperator::Equal), true),
         AssertionResult::Pubkey(None, None, EquatableOperator::Equal as u8, true),
         AssertionResult::Pubkey(
             Some(user_ata),
@@ -949,7 +955,7 @@ async fn encoded_message() {
     let expected_payloads: [AssertionResult; 1] = [AssertionResult::U64(
         Some(69_000),
         Some(69_001),
-        IntegerOperator::Equal as u8,
+        u8::from(IntegerOperator::Equal),
         false,
     )];
 
@@ -995,29 +1001,29 @@ async fn encoded_message() {
         AssertionResult::U64(
             Some(69_000),
             Some(69_001),
-            IntegerOperator::Equal as u8,
+            u8::from(IntegerOperator::Equal),
             false,
         ),
         AssertionResult::U64(
             Some(69_000),
             Some(69_000),
-            IntegerOperator::NotEqual as u8,
+            u8::from(IntegerOperator::NotEqual),
             false,
         ),
         AssertionResult::U8(
             Some(AccountState::Initialized as u8),
             Some(AccountState::Frozen as u8),
-            IntegerOperator::Equal as u8,
+            u8::from(IntegerOperator::Equal),
             false,
         ),
         AssertionResult::U8(
             Some(AccountState::Initialized as u8),
             Some(AccountState::Initialized as u8),
-            IntegerOperator::NotEqual as u8,
+            u8::from(IntegerOperator::NotEqual),
             false,
         ),
-        AssertionResult::U64(Some(0), Some(0), IntegerOperator::NotEqual as u8, false),
-        AssertionResult::U64(Some(0), Some(69), IntegerOperator::Equal as u8, false),
+        AssertionResult::U64(Some(0), Some(0), u8::from(IntegerOperator::NotEqual), false),
+        AssertionResult::U64(Some(0), Some(69), u8::from(IntegerOperator::Equal), false),
         AssertionResult::Pubkey(None, None, EquatableOperator::NotEqual as u8, false),
     ];
 
@@ -1140,3 +1146,365 @@ async fn encoded_message() {
         assert_eq!(expected_payload[i], payloads[i]);
     }
 }
+
+#[tokio::test]
+async fn test_ui_amount() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+
+    let (tx, mint) = create_mint(
+        context,
+        &user,
+        CreateMintParameters {
+            token_program: spl_token::id(),
+            mint_authority: Some(Some(user.pubkey())),
+            freeze_authority: None,
+            mint_to: Some((user.pubkey(), 1_500_000)),
+            decimals: 6,
+        },
+    )
+    .await
+    .unwrap();
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    let user_ata = get_associated_token_address(&user.pubkey(), &mint.pubkey());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[AssertTokenAccountBuilder::new()
+            .target_account(user_ata)
+            .assertion(TokenAccountAssertion::UiAmount {
+                mantissa: 15,
+                exponent: -1,
+                operator: IntegerOperator::LessThanOrEqual,
+            })
+            .add_remaining_account(AccountMeta::new_readonly(mint.pubkey(), false))
+            .instruction()],
+        Some(&user.pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[AssertTokenAccountBuilder::new()
+            .target_account(user_ata)
+            .assertion(TokenAccountAssertion::UiAmount {
+                mantissa: 149,
+                exponent: -2,
+                operator: IntegerOperator::LessThanOrEqual,
+            })
+            .add_remaining_account(AccountMeta::new_readonly(mint.pubkey(), false))
+            .instruction()],
+        Some(&user.pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(0, LighthouseError::AssertionFailed),
+        None,
+    )
+    .await
+    .unwrap();
+
+    // The second account must be the mint of the token account.
+    let tx = Transaction::new_signed_with_payer(
+        &[AssertTokenAccountBuilder::new()
+            .target_account(user_ata)
+            .assertion(TokenAccountAssertion::UiAmount {
+                mantissa: 15,
+                exponent: -1,
+                operator: IntegerOperator::Equal,
+            })
+            .add_remaining_account(AccountMeta::new_readonly(user.pubkey(), false))
+            .instruction()],
+        Some(&user.pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(0, LighthouseError::AccountKeyMismatch),
+        None,
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_token_account_delta() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+
+    let (tx, mint) = create_mint(
+        context,
+        &user,
+        CreateMintParameters {
+            token_program: spl_token::id(),
+            mint_authority: Some(Some(user.pubkey())),
+            freeze_authority: None,
+            mint_to: Some((user.pubkey(), 69_000)),
+            decimals: 9,
+        },
+    )
+    .await
+    .unwrap();
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    let (tx, other_mint) = create_mint(
+        context,
+        &user,
+        CreateMintParameters {
+            token_program: spl_token::id(),
+            mint_authority: Some(Some(user.pubkey())),
+            freeze_authority: None,
+            mint_to: None,
+            decimals: 9,
+        },
+    )
+    .await
+    .unwrap();
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    let user_ata = get_associated_token_address(&user.pubkey(), &mint.pubkey());
+    let (memory, memory_bump) = find_memory_pda(user.pubkey(), 0);
+
+    // Snapshots follow the 130 byte memory header that records them.
+    let (token_offset, mint_offset) = (130u64, 327u64);
+
+    let snapshot = |source_account, write_offset: u64, write_type| {
+        MemoryWriteBuilder::new()
+            .payer(user.pubkey())
+            .source_account(source_account)
+            .program_id(lighthouse_sdk::ID)
+            .memory(memory)
+            .memory_bump(memory_bump)
+            .write_offset(write_offset.into())
+            .write_type(write_type)
+            .instruction()
+    };
+
+    let assert_token_delta = |assertion| {
+        AssertTokenAccountDeltaBuilder::new()
+            .account_a(memory)
+            .account_b(user_ata)
+            .snapshot_offset(token_offset.into())
+            .assertion(assertion)
+            .log_level(LogLevel::PlaintextMessage)
+            .instruction()
+    };
+
+    let ixs = |max_amount_decrease: i128| {
+        vec![
+            snapshot(user_ata, 0, WriteType::MemoryHeader { nonce: 0 }),
+            snapshot(user_ata, token_offset, WriteType::TokenAccountSnapshot),
+            snapshot(mint.pubkey(), mint_offset, WriteType::MintAccountSnapshot),
+            spl_token::instruction::burn(
+                &spl_token::id(),
+                &user_ata,
+                &mint.pubkey(),
+                &user.pubkey(),
+                &[],
+                1_000,
+            )
+            .unwrap(),
+            assert_token_delta(TokenAccountDeltaAssertion::Owner {
+                operator: EquatableOperator::Equal,
+            }),
+            assert_token_delta(TokenAccountDeltaAssertion::Delegate {
+                operator: EquatableOperator::Equal,
+            }),
+            assert_token_delta(TokenAccountDeltaAssertion::CloseAuthority {
+                operator: EquatableOperator::Equal,
+            }),
+            assert_token_delta(TokenAccountDeltaAssertion::DelegatedAmount {
+                value: 0,
+                operator: IntegerOperator::Equal,
+            }),
+            AssertMintAccountDeltaBuilder::new()
+                .account_a(memory)
+                .account_b(mint.pubkey())
+                .snapshot_offset(mint_offset.into())
+                .assertion(MintAccountDeltaAssertion::Supply {
+                    value: -1_000,
+                    operator: IntegerOperator::Equal,
+                })
+                .log_level(LogLevel::PlaintextMessage)
+                .instruction(),
+            assert_token_delta(TokenAccountDeltaAssertion::Amount {
+                value: -max_amount_decrease,
+                operator: IntegerOperator::GreaterThanOrEqual,
+            }),
+            AssertTokenAccountDeltaBuilder::new()
+                .account_a(memory)
+                .account_b(user_ata)
+                .snapshot_offset(token_offset.into())
+                .assertion(TokenAccountDeltaAssertion::UiAmount {
+                    mantissa: -1,
+                    exponent: -6,
+                    operator: IntegerOperator::Equal,
+                })
+                .log_level(LogLevel::PlaintextMessage)
+                .add_remaining_account(AccountMeta::new_readonly(mint.pubkey(), false))
+                .instruction(),
+        ]
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs(500),
+        Some(&user.pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(9, LighthouseError::AssertionFailed),
+        None,
+    )
+    .await
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs(1_000),
+        Some(&user.pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    // Snapshots are bound to the account they were taken from.
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            snapshot(user_ata, 0, WriteType::MemoryHeader { nonce: 0 }),
+            snapshot(mint.pubkey(), mint_offset, WriteType::MintAccountSnapshot),
+            AssertMintAccountDeltaBuilder::new()
+                .account_a(memory)
+                .account_b(other_mint.pubkey())
+                .snapshot_offset(mint_offset.into())
+                .assertion(MintAccountDeltaAssertion::Supply {
+                    value: 0,
+                    operator: IntegerOperator::Equal,
+                })
+                .log_level(LogLevel::PlaintextMessage)
+                .instruction(),
+        ],
+        Some(&user.pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(2, LighthouseError::AccountKeyMismatch),
+        None,
+    )
+    .await
+    .unwrap();
+
+    // Only snapshots recorded in the memory header at the offset are read.
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            snapshot(user_ata, 0, WriteType::MemoryHeader { nonce: 0 }),
+            snapshot(user_ata, token_offset, WriteType::TokenAccountSnapshot),
+            snapshot(mint.pubkey(), mint_offset, WriteType::MintAccountSnapshot),
+            AssertTokenAccountDeltaBuilder::new()
+                .account_a(memory)
+                .account_b(user_ata)
+                .snapshot_offset(mint_offset.into())
+                .assertion(TokenAccountDeltaAssertion::Owner {
+                    operator: EquatableOperator::Equal,
+                })
+                .log_level(LogLevel::PlaintextMessage)
+                .instruction(),
+        ],
+        Some(&user.pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(3, LighthouseError::AssertionFailed),
+        None,
+    )
+    .await
+    .unwrap();
+
+    // Snapshots must be held by a Lighthouse memory account.
+    let tx = Transaction::new_signed_with_payer(
+        &[AssertTokenAccountDeltaBuilder::new()
+            .account_a(user_ata)
+            .account_b(user_ata)
+            .snapshot_offset(0u8.into())
+            .assertion(TokenAccountDeltaAssertion::Owner {
+                operator: EquatableOperator::Equal,
+            })
+            .log_level(LogLevel::PlaintextMessage)
+            .instruction()],
+        Some(&user.pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(0, LighthouseError::AccountOwnerMismatch),
+        None,
+    )
+    .await
+    .unwrap();
+
+    // A token account is not accepted where a mint is expected.
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            snapshot(user_ata, 0, WriteType::MemoryHeader { nonce: 0 }),
+            snapshot(user_ata, token_offset, WriteType::TokenAccountSnapshot),
+            AssertMintAccountDeltaBuilder::new()
+                .account_a(memory)
+                .account_b(user_ata)
+                .snapshot_offset(token_offset.into())
+                .assertion(MintAccountDeltaAssertion::Supply {
+                    value: 0,
+                    operator: IntegerOperator::Equal,
+                })
+                .log_level(LogLevel::PlaintextMessage)
+                .instruction(),
+        ],
+        Some(&user.pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(2, LighthouseError::AccountValidationFailed),
+        None,
+    )
+    .await
+    .unwrap();
+}
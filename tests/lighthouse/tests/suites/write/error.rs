@@ -747,8 +747,8 @@ pub mod memory_write {
                 .write_offset(0u8.into())
                 .memory_bump(memory_bump)
                 .write_type(WriteType::AccountData {
-                    offset: 1024,
-                    data_length: 64,
+                    offset: 1024u16.into(),
+                    data_length: 64u8.into(),
                 })
                 .instruction()],
             Some(&user.encodable_pubkey()),
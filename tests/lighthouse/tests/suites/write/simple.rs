@@ -1,26 +1,34 @@
 use crate::utils::{context::TestContext, create_test_account, create_user};
 use crate::utils::{
     create_and_transfer_token_account_ix, create_mint, process_transaction_assert_failure,
-    process_transaction_assert_success, to_transaction_error, CreateMintParameters,
+    process_transaction_assert_success, set_account_from_refs, to_transaction_error,
+    to_transaction_error_u8, CreateMintParameters,
 };
 use anchor_lang::*;
 use borsh::BorshSerialize;
 use lighthouse_sdk::errors::LighthouseError;
 use lighthouse_sdk::instructions::{
-    AssertAccountDataBuilder, AssertAccountDeltaBuilder, MemoryCloseBuilder, MemoryWriteBuilder,
+    AssertAccountDataBuilder, AssertAccountDeltaBuilder, AssertMemoryDeltaAndCloseBuilder,
+    MemoryCloseBuilder, MemoryCloseKeyedBuilder, MemoryWriteBuilder, MemoryWriteKeyedBuilder,
+    MemoryWriteMultiBuilder, MemoryWriteMultiKeyedBuilder,
 };
 use lighthouse_sdk::types::{
-    AccountDeltaAssertion, AccountInfoField, DataValue, DataValueAssertion,
-    DataValueDeltaAssertion, EquatableOperator, IntegerOperator, LogLevel, WriteType,
+    AccountDeltaAssertion, AccountHashScope, AccountInfoDeltaAssertion, AccountInfoField,
+    ArithmeticMode, ArithmeticSource, DataRange, DataValue, DataValueAssertion,
+    DataValueDeltaAssertion, EpochScheduleField, EquatableOperator, HashAlgorithm, IntegerOperator,
+    IntegerType, LogLevel, MemoryAccount, MemoryDeltaAssertion, MemoryGuard, MemoryKey,
+    MemoryWriteEntry, WriteType, WriteTypeTag,
 };
-use lighthouse_sdk::{find_memory_pda, find_memory_pda_bump_iterate};
+use lighthouse_sdk::{find_memory_pda, find_memory_pda_bump_iterate, find_memory_pda_keyed};
+use solana_program::program_pack::Pack;
 use solana_program_test::tokio;
-use solana_sdk::instruction::Instruction;
+use solana_sdk::epoch_schedule::EpochSchedule;
+use solana_sdk::instruction::{AccountMeta, Instruction};
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::EncodableKeypair;
 use solana_sdk::transaction::Transaction;
-use solana_sdk::{bpf_loader, system_program};
+use solana_sdk::{bpf_loader, system_program, sysvar};
 use spl_associated_token_account::get_associated_token_address;
 use std::u8::MAX;
 
@@ -58,8 +66,8 @@ async fn write_account_data() {
             .write_offset(0u8.into())
             .system_program(system_program::id())
             .write_type(WriteType::AccountData {
-                offset: 8,
-                data_length: (account_data_length - 8) as u16,
+                offset: 8u8.into(),
+                data_length: (account_data_length - 8).into(),
             })
             .instruction()],
         Some(&user.encodable_pubkey()),
@@ -497,8 +505,8 @@ async fn token_transfer() {
         .write_offset(0u8.into())
         .memory_bump(memory_bump)
         .write_type(WriteType::AccountData {
-            offset: 0,
-            data_length: 72,
+            offset: 0u8.into(),
+            data_length: 72u8.into(),
         })
         .instruction()];
     ixs.extend(
@@ -859,3 +867,1062 @@ async fn account_info_program() {
     let actual_value = Pubkey::try_from_slice(&account_data[64..96]).unwrap();
     assert_eq!(actual_value, lighthouse_sdk::ID);
 }
+
+#[tokio::test]
+async fn write_multi() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+    let (memory, memory_bump) = find_memory_pda(user.encodable_pubkey(), 0);
+
+    let writes = vec![
+        MemoryWriteEntry {
+            source_account_index: 0,
+            write_offset: 0u8.into(),
+            write_type: WriteType::AccountInfoField(AccountInfoField::Key),
+        },
+        MemoryWriteEntry {
+            source_account_index: 1,
+            write_offset: 32u8.into(),
+            write_type: WriteType::AccountInfoField(AccountInfoField::Key),
+        },
+        MemoryWriteEntry {
+            source_account_index: 1,
+            write_offset: 64u8.into(),
+            write_type: WriteType::DataValue(DataValue::U64(u64::MAX)),
+        },
+    ];
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            MemoryWriteMultiBuilder::new()
+                .payer(user.encodable_pubkey())
+                .memory(memory)
+                .program_id(lighthouse_sdk::ID)
+                .memory_bump(memory_bump)
+                .writes(writes.into())
+                .add_remaining_account(AccountMeta::new_readonly(user.encodable_pubkey(), false))
+                .add_remaining_account(AccountMeta::new_readonly(system_program::id(), false))
+                .instruction(),
+            AssertAccountDataBuilder::new()
+                .target_account(memory)
+                .assertion(DataValueAssertion::Pubkey {
+                    value: user.encodable_pubkey(),
+                    operator: EquatableOperator::Equal,
+                })
+                .offset(0u8.into())
+                .instruction(),
+            AssertAccountDataBuilder::new()
+                .target_account(memory)
+                .assertion(DataValueAssertion::Pubkey {
+                    value: system_program::id(),
+                    operator: EquatableOperator::Equal,
+                })
+                .offset(32u8.into())
+                .instruction(),
+            AssertAccountDataBuilder::new()
+                .target_account(memory)
+                .assertion(DataValueAssertion::U64 {
+                    value: u64::MAX,
+                    operator: IntegerOperator::Equal,
+                })
+                .offset(64u8.into())
+                .instruction(),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    // Source account indexes past the provided accounts are rejected.
+    let tx = Transaction::new_signed_with_payer(
+        &[MemoryWriteMultiBuilder::new()
+            .payer(user.encodable_pubkey())
+            .memory(memory)
+            .program_id(lighthouse_sdk::ID)
+            .memory_bump(memory_bump)
+            .writes(
+                vec![MemoryWriteEntry {
+                    source_account_index: 1,
+                    write_offset: 0u8.into(),
+                    write_type: WriteType::AccountInfoField(AccountInfoField::Key),
+                }]
+                .into(),
+            )
+            .add_remaining_account(AccountMeta::new_readonly(user.encodable_pubkey(), false))
+            .instruction()],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(0, LighthouseError::NotEnoughAccounts),
+        None,
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn write_account_hash() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+    let test_account = create_test_account(context, &user, false).await.unwrap();
+    let (memory, memory_bump) = find_memory_pda(user.encodable_pubkey(), 0);
+
+    let write_hash = |offset: u8, algorithm: HashAlgorithm, scope: AccountHashScope| {
+        MemoryWriteBuilder::new()
+            .payer(user.encodable_pubkey())
+            .source_account(test_account.encodable_pubkey())
+            .program_id(lighthouse_sdk::ID)
+            .memory(memory)
+            .memory_id(0)
+            .write_offset(offset.into())
+            .memory_bump(memory_bump)
+            .write_type(WriteType::AccountHash { algorithm, scope })
+            .instruction()
+    };
+
+    let assert_hash = |offset: u8, algorithm: HashAlgorithm, scope: AccountHashScope, operator| {
+        AssertAccountDeltaBuilder::new()
+            .account_a(memory)
+            .account_b(test_account.encodable_pubkey())
+            .assertion(AccountDeltaAssertion::AccountInfo {
+                a_offset: offset.into(),
+                assertion: AccountInfoDeltaAssertion::Hash {
+                    algorithm,
+                    scope,
+                    operator,
+                },
+            })
+            .log_level(LogLevel::PlaintextMessage)
+            .instruction()
+    };
+
+    let data_range = AccountHashScope::DataRange {
+        offset: 8u8.into(),
+        length: 64u8.into(),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            write_hash(0, HashAlgorithm::Keccak256, AccountHashScope::Account),
+            write_hash(32, HashAlgorithm::Sha256, data_range.clone()),
+            assert_hash(
+                0,
+                HashAlgorithm::Keccak256,
+                AccountHashScope::Account,
+                EquatableOperator::Equal,
+            ),
+            assert_hash(
+                32,
+                HashAlgorithm::Sha256,
+                data_range.clone(),
+                EquatableOperator::Equal,
+            ),
+            assert_hash(
+                32,
+                HashAlgorithm::Keccak256,
+                data_range,
+                EquatableOperator::NotEqual,
+            ),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn write_large_account_data() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+
+    let large_account = Keypair::new().encodable_pubkey();
+    let large_account_data = (0..100_000u32)
+        .map(|i| (i % 251) as u8)
+        .collect::<Vec<u8>>();
+    set_account_from_refs(
+        context,
+        &large_account,
+        &large_account_data,
+        &system_program::id(),
+    )
+    .await;
+
+    let (memory, memory_bump) = find_memory_pda(user.encodable_pubkey(), 0);
+
+    let build_write = |write_offset: u64, write_type: WriteType| {
+        MemoryWriteBuilder::new()
+            .payer(user.encodable_pubkey())
+            .source_account(large_account)
+            .program_id(lighthouse_sdk::ID)
+            .memory(memory)
+            .memory_id(0)
+            .write_offset(write_offset.into())
+            .memory_bump(memory_bump)
+            .write_type(write_type)
+            .instruction()
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            build_write(
+                0,
+                WriteType::AccountDataRanges {
+                    ranges: vec![
+                        DataRange {
+                            offset: 70_000u32.into(),
+                            length: 32u8.into(),
+                        },
+                        DataRange {
+                            offset: 99_000u32.into(),
+                            length: 8u8.into(),
+                        },
+                    ]
+                    .into(),
+                },
+            ),
+            build_write(
+                40,
+                WriteType::AccountData {
+                    offset: 80_000u32.into(),
+                    data_length: 64u8.into(),
+                },
+            ),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    let memory_data = context.get_account(memory).await.unwrap().data;
+    assert_eq!(memory_data[0..32], large_account_data[70_000..70_032]);
+    assert_eq!(memory_data[32..40], large_account_data[99_000..99_008]);
+    assert_eq!(memory_data[40..104], large_account_data[80_000..80_064]);
+
+    // Memory can only grow by 10 KiB per instruction, a write past the grown memory fails.
+    let tx = Transaction::new_signed_with_payer(
+        &[build_write(
+            20_000,
+            WriteType::AccountData {
+                offset: 0u8.into(),
+                data_length: 64u8.into(),
+            },
+        )],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(0, LighthouseError::RangeOutOfBounds),
+        None,
+    )
+    .await
+    .unwrap();
+
+    // Larger memory accounts are grown by repeating a resize across several instructions.
+    let resize = build_write(
+        0,
+        WriteType::Resize {
+            space: 20_064u32.into(),
+        },
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            resize.clone(),
+            resize,
+            build_write(
+                20_000,
+                WriteType::AccountData {
+                    offset: 90_000u32.into(),
+                    data_length: 64u8.into(),
+                },
+            ),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    let memory_data = context.get_account(memory).await.unwrap().data;
+    assert_eq!(memory_data.len(), 20_064);
+    assert_eq!(memory_data[20_000..], large_account_data[90_000..90_064]);
+}
+
+#[tokio::test]
+async fn write_arithmetic() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+    let other_user = create_user(context).await.unwrap();
+    let (memory, memory_bump) = find_memory_pda(user.encodable_pubkey(), 0);
+
+    let user_lamports = context
+        .get_account(user.encodable_pubkey())
+        .await
+        .unwrap()
+        .lamports;
+    let other_user_lamports = context
+        .get_account(other_user.encodable_pubkey())
+        .await
+        .unwrap()
+        .lamports;
+
+    let accumulate = |source_account_index: u8, mode: ArithmeticMode| MemoryWriteEntry {
+        source_account_index,
+        write_offset: 0u8.into(),
+        write_type: WriteType::Arithmetic {
+            mode,
+            integer_type: IntegerType::U64,
+            source: ArithmeticSource::AccountInfoField(AccountInfoField::Lamports),
+        },
+    };
+
+    let build_write_multi = |writes: Vec<MemoryWriteEntry>| {
+        MemoryWriteMultiBuilder::new()
+            .payer(user.encodable_pubkey())
+            .memory(memory)
+            .program_id(lighthouse_sdk::ID)
+            .memory_bump(memory_bump)
+            .writes(writes.into())
+            .add_remaining_account(AccountMeta::new_readonly(user.encodable_pubkey(), false))
+            .add_remaining_account(AccountMeta::new_readonly(
+                other_user.encodable_pubkey(),
+                false,
+            ))
+            .instruction()
+    };
+
+    // The payer is charged the fee and the memory rent before its lamports are accumulated.
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            build_write_multi(vec![
+                accumulate(0, ArithmeticMode::CheckedAdd),
+                accumulate(1, ArithmeticMode::CheckedAdd),
+                MemoryWriteEntry {
+                    source_account_index: 0,
+                    write_offset: 8u8.into(),
+                    write_type: WriteType::Arithmetic {
+                        mode: ArithmeticMode::Max,
+                        integer_type: IntegerType::U64,
+                        source: ArithmeticSource::DataValue(DataValue::U64(42)),
+                    },
+                },
+            ]),
+            AssertAccountDataBuilder::new()
+                .target_account(memory)
+                .assertion(DataValueAssertion::U64 {
+                    value: user_lamports + other_user_lamports,
+                    operator: IntegerOperator::LessThan,
+                })
+                .offset(0u8.into())
+                .instruction(),
+            AssertAccountDataBuilder::new()
+                .target_account(memory)
+                .assertion(DataValueAssertion::U64 {
+                    value: user_lamports + other_user_lamports - 2_000_000,
+                    operator: IntegerOperator::GreaterThan,
+                })
+                .offset(0u8.into())
+                .instruction(),
+            AssertAccountDataBuilder::new()
+                .target_account(memory)
+                .assertion(DataValueAssertion::U64 {
+                    value: 42,
+                    operator: IntegerOperator::Equal,
+                })
+                .offset(8u8.into())
+                .instruction(),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    // Subtracting past zero overflows.
+    let tx = Transaction::new_signed_with_payer(
+        &[build_write_multi(vec![
+            accumulate(0, ArithmeticMode::CheckedSub),
+            accumulate(1, ArithmeticMode::CheckedSub),
+        ])],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(0, LighthouseError::ArithmeticOverflow),
+        None,
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn write_memory_header() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+    let test_account = create_test_account(context, &user, false).await.unwrap();
+    let (memory, memory_bump) = find_memory_pda(user.encodable_pubkey(), 0);
+
+    // The header occupies the first 130 bytes of the memory.
+    let data_offset = 130u64;
+    let write = |offset: u64, write_type: WriteType| MemoryWriteEntry {
+        source_account_index: 0,
+        write_offset: offset.into(),
+        write_type,
+    };
+
+    let guarded_assertion = |nonce: u64| {
+        AssertAccountDeltaBuilder::new()
+            .account_a(memory)
+            .account_b(test_account.encodable_pubkey())
+            .assertion(AccountDeltaAssertion::GuardedAccountInfo {
+                guard: MemoryGuard {
+                    current_slot: true,
+                    nonce: Some(nonce),
+                    type_tag: Some(WriteTypeTag::AccountInfoField),
+                },
+                a_offset: data_offset.into(),
+                assertion: AccountInfoDeltaAssertion::Lamports {
+                    value: 0,
+                    operator: IntegerOperator::Equal,
+                },
+            })
+            .log_level(LogLevel::PlaintextMessage)
+            .instruction()
+    };
+
+    let write_multi = |writes: Vec<MemoryWriteEntry>| {
+        MemoryWriteMultiBuilder::new()
+            .payer(user.encodable_pubkey())
+            .program_id(lighthouse_sdk::ID)
+            .memory(memory)
+            .memory_id(0)
+            .memory_bump(memory_bump)
+            .writes(writes.into())
+            .add_remaining_account(AccountMeta::new_readonly(
+                test_account.encodable_pubkey(),
+                false,
+            ))
+            .instruction()
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            write_multi(vec![
+                write(0, WriteType::MemoryHeader { nonce: 1 }),
+                write(
+                    data_offset,
+                    WriteType::AccountInfoField(AccountInfoField::Lamports),
+                ),
+            ]),
+            guarded_assertion(1),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    // The snapshot left behind by the previous transaction is rejected.
+    let tx = Transaction::new_signed_with_payer(
+        &[guarded_assertion(2)],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(0, LighthouseError::AssertionFailed),
+        None,
+    )
+    .await
+    .unwrap();
+
+    // Writing the header again clears the recorded writes, the snapshot must be taken again.
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            write_multi(vec![write(0, WriteType::MemoryHeader { nonce: 2 })]),
+            guarded_assertion(2),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(1, LighthouseError::AssertionFailed),
+        None,
+    )
+    .await
+    .unwrap();
+
+    // A write that overlaps the header is rejected.
+    let tx = Transaction::new_signed_with_payer(
+        &[write_multi(vec![write(
+            8,
+            WriteType::DataValue(DataValue::U64(0)),
+        )])],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(0, LighthouseError::RangeOutOfBounds),
+        None,
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn assert_memory_delta_and_close() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+    let test_account = create_test_account(context, &user, false).await.unwrap();
+    let (memory_a, memory_a_bump) = find_memory_pda(user.encodable_pubkey(), 0);
+    let (memory_b, memory_b_bump) = find_memory_pda(user.encodable_pubkey(), 1);
+
+    let write = |memory: Pubkey, memory_id: u8, memory_bump: u8, write_type: WriteType| {
+        MemoryWriteBuilder::new()
+            .payer(user.encodable_pubkey())
+            .source_account(test_account.encodable_pubkey())
+            .program_id(lighthouse_sdk::ID)
+            .memory(memory)
+            .memory_id(memory_id)
+            .write_offset(0u8.into())
+            .memory_bump(memory_bump)
+            .write_type(write_type)
+            .instruction()
+    };
+
+    let assert_and_close = |lamports_delta: i128| {
+        AssertMemoryDeltaAndCloseBuilder::new()
+            .program_id(lighthouse_sdk::ID)
+            .payer(user.encodable_pubkey())
+            .memories(
+                vec![
+                    MemoryAccount::Index {
+                        memory_id: 0,
+                        memory_bump: memory_a_bump,
+                    },
+                    MemoryAccount::Index {
+                        memory_id: 1,
+                        memory_bump: memory_b_bump,
+                    },
+                ]
+                .into(),
+            )
+            .assertions(
+                vec![
+                    MemoryDeltaAssertion {
+                        memory_index: 0,
+                        account_index: 0,
+                        assertion: AccountDeltaAssertion::AccountInfo {
+                            a_offset: 0u8.into(),
+                            assertion: AccountInfoDeltaAssertion::Lamports {
+                                value: lamports_delta,
+                                operator: IntegerOperator::Equal,
+                            },
+                        },
+                    },
+                    MemoryDeltaAssertion {
+                        memory_index: 1,
+                        account_index: 0,
+                        assertion: AccountDeltaAssertion::Data {
+                            a_offset: 0u8.into(),
+                            b_offset: 8u8.into(),
+                            assertion: DataValueDeltaAssertion::U64 {
+                                value: 0,
+                                operator: IntegerOperator::Equal,
+                            },
+                        },
+                    },
+                ]
+                .into(),
+            )
+            .add_remaining_account(AccountMeta::new(memory_a, false))
+            .add_remaining_account(AccountMeta::new(memory_b, false))
+            .add_remaining_account(AccountMeta::new_readonly(
+                test_account.encodable_pubkey(),
+                false,
+            ))
+            .log_level(LogLevel::PlaintextMessage)
+            .instruction()
+    };
+
+    let writes = [
+        write(
+            memory_a,
+            0,
+            memory_a_bump,
+            WriteType::AccountInfoField(AccountInfoField::Lamports),
+        ),
+        write(
+            memory_b,
+            1,
+            memory_b_bump,
+            WriteType::AccountData {
+                offset: 8u8.into(),
+                data_length: 8u8.into(),
+            },
+        ),
+    ];
+
+    // A failed assertion reverts the whole transaction, including the close.
+    let tx = Transaction::new_signed_with_payer(
+        &[writes[0].clone(), writes[1].clone(), assert_and_close(1)],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(context, tx, to_transaction_error_u8(2, 0x1900), None)
+        .await
+        .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[writes[0].clone(), writes[1].clone(), assert_and_close(0)],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    assert!(context.get_account(memory_a).await.is_none());
+    assert!(context.get_account(memory_b).await.is_none());
+}
+
+#[tokio::test]
+async fn write_keyed_memory() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+    let test_account = create_test_account(context, &user, false).await.unwrap();
+
+    let namespace = Keypair::new().encodable_pubkey();
+    let memory_keys = [
+        MemoryKey {
+            namespace: None,
+            id: u64::MAX,
+        },
+        MemoryKey {
+            namespace: Some(namespace),
+            id: u64::MAX,
+        },
+    ];
+
+    for memory_key in memory_keys {
+        let (memory, memory_bump) =
+            find_memory_pda_keyed(user.encodable_pubkey(), memory_key.namespace, memory_key.id);
+
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                MemoryWriteKeyedBuilder::new()
+                    .payer(user.encodable_pubkey())
+                    .source_account(test_account.encodable_pubkey())
+                    .program_id(lighthouse_sdk::ID)
+                    .memory(memory)
+                    .memory_key(memory_key.clone())
+                    .memory_bump(memory_bump)
+                    .write_offset(0u8.into())
+                    .write_type(WriteType::AccountInfoField(AccountInfoField::Lamports))
+                    .instruction(),
+                MemoryWriteMultiKeyedBuilder::new()
+                    .payer(user.encodable_pubkey())
+                    .program_id(lighthouse_sdk::ID)
+                    .memory(memory)
+                    .memory_key(memory_key.clone())
+                    .memory_bump(memory_bump)
+                    .writes(
+                        vec![MemoryWriteEntry {
+                            source_account_index: 0,
+                            write_offset: 8u8.into(),
+                            write_type: WriteType::DataValue(DataValue::U64(7)),
+                        }]
+                        .into(),
+                    )
+                    .add_remaining_account(AccountMeta::new_readonly(
+                        test_account.encodable_pubkey(),
+                        false,
+                    ))
+                    .instruction(),
+                AssertMemoryDeltaAndCloseBuilder::new()
+                    .program_id(lighthouse_sdk::ID)
+                    .payer(user.encodable_pubkey())
+                    .memories(
+                        vec![MemoryAccount::Key {
+                            memory_key: memory_key.clone(),
+                            memory_bump,
+                        }]
+                        .into(),
+                    )
+                    .assertions(
+                        vec![MemoryDeltaAssertion {
+                            memory_index: 0,
+                            account_index: 0,
+                            assertion: AccountDeltaAssertion::AccountInfo {
+                                a_offset: 0u8.into(),
+                                assertion: AccountInfoDeltaAssertion::Lamports {
+                                    value: 0,
+                                    operator: IntegerOperator::Equal,
+                                },
+                            },
+                        }]
+                        .into(),
+                    )
+                    .add_remaining_account(AccountMeta::new(memory, false))
+                    .add_remaining_account(AccountMeta::new_readonly(
+                        test_account.encodable_pubkey(),
+                        false,
+                    ))
+                    .instruction(),
+            ],
+            Some(&user.encodable_pubkey()),
+            &[&user],
+            context.get_blockhash().await,
+        );
+
+        process_transaction_assert_success(context, tx)
+            .await
+            .unwrap();
+
+        assert!(context.get_account(memory).await.is_none());
+    }
+
+    // Keyed memory is closed with the same key it was written with.
+    let memory_key = MemoryKey {
+        namespace: Some(namespace),
+        id: 1,
+    };
+    let (memory, memory_bump) =
+        find_memory_pda_keyed(user.encodable_pubkey(), memory_key.namespace, memory_key.id);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            MemoryWriteKeyedBuilder::new()
+                .payer(user.encodable_pubkey())
+                .source_account(test_account.encodable_pubkey())
+                .program_id(lighthouse_sdk::ID)
+                .memory(memory)
+                .memory_key(memory_key.clone())
+                .memory_bump(memory_bump)
+                .write_offset(0u8.into())
+                .write_type(WriteType::AccountInfoField(AccountInfoField::Lamports))
+                .instruction(),
+            MemoryCloseKeyedBuilder::new()
+                .payer(user.encodable_pubkey())
+                .program_id(lighthouse_sdk::ID)
+                .memory(memory)
+                .memory_key(memory_key)
+                .memory_bump(memory_bump)
+                .instruction(),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    assert!(context.get_account(memory).await.is_none());
+}
+
+#[tokio::test]
+async fn write_return_data() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+
+    let (tx, mint) = create_mint(
+        context,
+        &user,
+        CreateMintParameters {
+            token_program: spl_token::id(),
+            mint_authority: None,
+            freeze_authority: None,
+            mint_to: None,
+            decimals: 9,
+        },
+    )
+    .await
+    .unwrap();
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    let get_account_data_size_ix =
+        spl_token::instruction::get_account_data_size(&spl_token::id(), &mint.encodable_pubkey())
+            .unwrap();
+    let (memory, memory_bump) = find_memory_pda(user.encodable_pubkey(), 0);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            get_account_data_size_ix.clone(),
+            MemoryWriteBuilder::new()
+                .payer(user.encodable_pubkey())
+                .source_account(lighthouse_sdk::ID)
+                .program_id(lighthouse_sdk::ID)
+                .memory(memory)
+                .memory_id(0)
+                .memory_bump(memory_bump)
+                .write_offset(0u8.into())
+                .write_type(WriteType::ReturnData {
+                    program_id: spl_token::id(),
+                    offset: 0u8.into(),
+                    length: 8u8.into(),
+                })
+                .instruction(),
+            AssertAccountDataBuilder::new()
+                .target_account(memory)
+                .log_level(LogLevel::PlaintextMessage)
+                .assertion(DataValueAssertion::U64 {
+                    value: spl_token::state::Account::LEN as u64,
+                    operator: IntegerOperator::Equal,
+                })
+                .offset(0u8.into())
+                .instruction(),
+            MemoryCloseBuilder::new()
+                .payer(user.encodable_pubkey())
+                .program_id(lighthouse_sdk::ID)
+                .memory(memory)
+                .memory_id(0)
+                .memory_bump(memory_bump)
+                .instruction(),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    // Return data must have been set by the expected program and cover the range.
+    let write_types = [
+        WriteType::ReturnData {
+            program_id: Pubkey::new_unique(),
+            offset: 0u8.into(),
+            length: 8u8.into(),
+        },
+        WriteType::ReturnData {
+            program_id: spl_token::id(),
+            offset: 4u8.into(),
+            length: 8u8.into(),
+        },
+    ];
+    let expected_errors = [
+        to_transaction_error(1, LighthouseError::ReturnDataMismatch),
+        to_transaction_error(1, LighthouseError::RangeOutOfBounds),
+    ];
+
+    for (write_type, expected_error) in write_types.into_iter().zip(expected_errors) {
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                get_account_data_size_ix.clone(),
+                MemoryWriteBuilder::new()
+                    .payer(user.encodable_pubkey())
+                    .source_account(lighthouse_sdk::ID)
+                    .program_id(lighthouse_sdk::ID)
+                    .memory(memory)
+                    .memory_id(0)
+                    .memory_bump(memory_bump)
+                    .write_offset(0u8.into())
+                    .write_type(write_type)
+                    .instruction(),
+            ],
+            Some(&user.encodable_pubkey()),
+            &[&user],
+            context.get_blockhash().await,
+        );
+
+        process_transaction_assert_failure(context, tx, expected_error, None)
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+async fn write_sysvar_values() {
+    let context = &mut TestContext::new().await.unwrap();
+    let user = create_user(context).await.unwrap();
+
+    let rent = context.client().get_rent().await.unwrap();
+    let epoch_schedule = context
+        .client()
+        .get_sysvar::<EpochSchedule>()
+        .await
+        .unwrap();
+
+    let (memory, memory_bump) = find_memory_pda(user.encodable_pubkey(), 0);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            MemoryWriteBuilder::new()
+                .payer(user.encodable_pubkey())
+                .source_account(sysvar::instructions::id())
+                .program_id(lighthouse_sdk::ID)
+                .memory(memory)
+                .memory_id(0)
+                .memory_bump(memory_bump)
+                .write_offset(17u8.into())
+                .write_type(WriteType::InstructionIndex)
+                .instruction(),
+            MemoryWriteMultiBuilder::new()
+                .payer(user.encodable_pubkey())
+                .program_id(lighthouse_sdk::ID)
+                .memory(memory)
+                .memory_id(0)
+                .memory_bump(memory_bump)
+                .writes(
+                    vec![
+                        MemoryWriteEntry {
+                            source_account_index: 0,
+                            write_offset: 0u8.into(),
+                            write_type: WriteType::RentMinimumBalance {
+                                data_length: 165u8.into(),
+                            },
+                        },
+                        MemoryWriteEntry {
+                            source_account_index: 0,
+                            write_offset: 8u8.into(),
+                            write_type: WriteType::EpochSchedule(EpochScheduleField::SlotsPerEpoch),
+                        },
+                        MemoryWriteEntry {
+                            source_account_index: 0,
+                            write_offset: 16u8.into(),
+                            write_type: WriteType::EpochSchedule(EpochScheduleField::Warmup),
+                        },
+                        MemoryWriteEntry {
+                            source_account_index: 0,
+                            write_offset: 19u8.into(),
+                            write_type: WriteType::InstructionIndex,
+                        },
+                    ]
+                    .into(),
+                )
+                .add_remaining_account(AccountMeta::new_readonly(sysvar::instructions::id(), false))
+                .instruction(),
+            AssertAccountDataBuilder::new()
+                .target_account(memory)
+                .log_level(LogLevel::PlaintextMessage)
+                .assertion(DataValueAssertion::U64 {
+                    value: rent.minimum_balance(165),
+                    operator: IntegerOperator::Equal,
+                })
+                .offset(0u8.into())
+                .instruction(),
+            AssertAccountDataBuilder::new()
+                .target_account(memory)
+                .log_level(LogLevel::PlaintextMessage)
+                .assertion(DataValueAssertion::U64 {
+                    value: epoch_schedule.slots_per_epoch,
+                    operator: IntegerOperator::Equal,
+                })
+                .offset(8u8.into())
+                .instruction(),
+            AssertAccountDataBuilder::new()
+                .target_account(memory)
+                .log_level(LogLevel::PlaintextMessage)
+                .assertion(DataValueAssertion::Bool {
+                    value: epoch_schedule.warmup,
+                    operator: EquatableOperator::Equal,
+                })
+                .offset(16u8.into())
+                .instruction(),
+            AssertAccountDataBuilder::new()
+                .target_account(memory)
+                .log_level(LogLevel::PlaintextMessage)
+                .assertion(DataValueAssertion::U16 {
+                    value: 0,
+                    operator: IntegerOperator::Equal,
+                })
+                .offset(17u8.into())
+                .instruction(),
+            AssertAccountDataBuilder::new()
+                .target_account(memory)
+                .log_level(LogLevel::PlaintextMessage)
+                .assertion(DataValueAssertion::U16 {
+                    value: 1,
+                    operator: IntegerOperator::Equal,
+                })
+                .offset(19u8.into())
+                .instruction(),
+        ],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_success(context, tx)
+        .await
+        .unwrap();
+
+    // The instruction index is only read from the Instructions sysvar.
+    let tx = Transaction::new_signed_with_payer(
+        &[MemoryWriteBuilder::new()
+            .payer(user.encodable_pubkey())
+            .source_account(user.encodable_pubkey())
+            .program_id(lighthouse_sdk::ID)
+            .memory(memory)
+            .memory_id(0)
+            .memory_bump(memory_bump)
+            .write_offset(17u8.into())
+            .write_type(WriteType::InstructionIndex)
+            .instruction()],
+        Some(&user.encodable_pubkey()),
+        &[&user],
+        context.get_blockhash().await,
+    );
+
+    process_transaction_assert_failure(
+        context,
+        tx,
+        to_transaction_error(0, LighthouseError::AccountKeyMismatch),
+        None,
+    )
+    .await
+    .unwrap();
+}
@@ -264,8 +264,8 @@ fn build_safe_send_token_transaction(
             .write_offset(0u8.into())
             .memory_bump(memory_bump)
             .write_type(WriteType::AccountData {
-                offset: 0,
-                data_length: 72,
+                offset: 0u8.into(),
+                data_length: 72u8.into(),
             })
             .instruction(),
         spl_token::instruction::transfer(